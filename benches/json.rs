@@ -0,0 +1,49 @@
+/*! Benchmark parsing JSON with examples/json.tok
+
+Run with `cargo bench --bench json`. To compare the effect of a build feature like
+`inline_parselets`, run the benchmark once more with `--no-default-features` and the
+features of interest, which must include `cli`, e.g.
+`cargo bench --bench json --no-default-features --features cli,inline_parselets`.
+*/
+use std::time::{Duration, Instant};
+use tokay::{Compiler, Reader};
+
+const ROUNDS: u32 = 10;
+
+// Generate a JSON document with `count` records
+fn input(count: usize) -> String {
+    let records: Vec<String> = (0..count)
+        .map(|i| {
+            format!(
+                r#"{{"id": {i}, "name": "item{i}", "price": {i}.5, "tags": ["a", "b", null], "active": true, "parent": {{}}}}"#
+            )
+        })
+        .collect();
+
+    format!("[{}]", records.join(",\n"))
+}
+
+fn main() {
+    let program = Compiler::new()
+        .compile_from_str(include_str!("../examples/json.tok"))
+        .unwrap()
+        .unwrap();
+
+    let input = input(500);
+    let mut total = Duration::ZERO;
+
+    for _ in 0..ROUNDS {
+        let reader = Reader::new(None, Box::new(std::io::Cursor::new(input.clone())));
+
+        let start = Instant::now();
+        program.run_from_reader(reader).unwrap();
+        total += start.elapsed();
+    }
+
+    println!(
+        "json: {} bytes in {:?} per round ({} rounds)",
+        input.len(),
+        total / ROUNDS,
+        ROUNDS
+    );
+}
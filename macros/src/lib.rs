@@ -160,7 +160,7 @@ fn gen_assign_arguments(arguments: Vec<(String, String)>) -> Vec<proc_macro2::To
 
                         if value.is_none() {
                             if #required {
-                                return Err(format!("{} expected argument '{}'", __function, stringify!(#arg)).into()).into();
+                                return Err(tokay::Error::new(None, format!("{} expected argument '{}'", __function, stringify!(#arg))).with_kind(tokay::ErrorKind::Argument).into()).into();
                             }
                             else {
                                 #default
@@ -180,12 +180,12 @@ fn gen_assign_arguments(arguments: Vec<(String, String)>) -> Vec<proc_macro2::To
     if !args {
         ret.push(quote! {
             if args.len() > 0 {
-                return Err(
+                return Err(tokay::Error::new(None,
                     match #count {
                         0 => format!("{} doesn't accept any arguments ({} given)", __function, args.len()),
                         1 => format!("{} takes exactly one argument ({} given)", __function, #count + args.len()),
                         _ => format!("{} expected at most {} arguments ({} given)", __function, #count, #count + args.len()),
-                    }.into()
+                    }).with_kind(tokay::ErrorKind::Argument).into()
                 ).into()
             }
         });
@@ -195,11 +195,11 @@ fn gen_assign_arguments(arguments: Vec<(String, String)>) -> Vec<proc_macro2::To
         ret.push(quote! {
             if let Some(mut nargs) = nargs {
                 if let Some((name, _)) = nargs.pop() {
-                    return Err(
+                    return Err(tokay::Error::new(None,
                         match nargs.len() {
                             0 => format!("{} doesn't accept named argument '{}'", __function, name.to_string()),
                             n => format!("{} doesn't accept named arguments ({} given)", __function, n + 1),
-                        }.into()
+                        }).with_kind(tokay::ErrorKind::Argument).into()
                     ).into()
                 }
             }
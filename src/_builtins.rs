@@ -6,298 +6,492 @@
 use crate::builtin::Builtin;
 
 /*GENERATE cargo run -- src/_builtins.tok -- `find src -name "*.rs"` */
-pub static BUILTINS: [Builtin; 73] = [
+pub static BUILTINS: &[Builtin] = &[
+    Builtin {
+        name: "FILENAME",
+        signature: "FILENAME",
+        func: crate::builtin::tokay_token_filename,
+    },
     Builtin {
         name: "Float",
+        signature: "Float : @with_signs=true",
         func: crate::value::token::tokay_token_float,
     },
     Builtin {
         name: "Ident",
+        signature: "Ident",
         func: crate::value::token::tokay_token_ident,
     },
     Builtin {
         name: "Int",
+        signature: "Int : @base=void, with_signs=true",
         func: crate::value::token::tokay_token_int,
     },
     Builtin {
         name: "Match",
+        signature: "Match : @s nocase=false",
         func: crate::value::token::tokay_token_match,
     },
     Builtin {
         name: "Touch",
+        signature: "Touch : @s nocase=false",
         func: crate::value::token::tokay_token_touch,
     },
     Builtin {
         name: "Word",
+        signature: "Word : @min=1 max=void",
         func: crate::value::token::tokay_token_word,
     },
     Builtin {
         name: "ast",
+        signature: "ast : @emit, value=void, flatten=true, debug=false",
         func: crate::compiler::ast::tokay_function_ast,
     },
     Builtin {
         name: "ast2rust",
+        signature: "ast2rust : @ast, level=0",
         func: crate::compiler::ast::tokay_function_ast2rust,
     },
     Builtin {
         name: "ast_print",
+        signature: "ast_print : @ast",
         func: crate::compiler::ast::tokay_function_ast_print,
     },
     Builtin {
         name: "bool",
+        signature: "bool : @value",
         func: crate::value::value::Value::tokay_method_bool,
     },
     Builtin {
         name: "chr",
+        signature: "chr : @i",
         func: crate::builtin::tokay_function_chr,
     },
     Builtin {
         name: "debug",
+        signature: "debug : @level",
         func: crate::builtin::tokay_function_debug,
     },
     Builtin {
         name: "dict",
+        signature: "dict : @**nargs",
         func: crate::value::dict::Dict::tokay_method_dict,
     },
     Builtin {
         name: "dict_clone",
+        signature: "dict_clone : @dict",
         func: crate::value::dict::Dict::tokay_method_dict_clone,
     },
     Builtin {
         name: "dict_get_item",
+        signature: "dict_get_item : @dict, key, default=void, upsert=false",
         func: crate::value::dict::Dict::tokay_method_dict_get_item,
     },
+    Builtin {
+        name: "dict_has",
+        signature: "dict_has : @dict, key",
+        func: crate::value::dict::Dict::tokay_method_dict_has,
+    },
     Builtin {
         name: "dict_items",
+        signature: "dict_items : @dict, index=void, default=void",
         func: crate::value::dict::Dict::tokay_method_dict_items,
     },
     Builtin {
         name: "dict_iter",
+        signature: "dict_iter : @dict",
         func: crate::value::dict::Dict::tokay_method_dict_iter,
     },
     Builtin {
         name: "dict_keys",
+        signature: "dict_keys : @dict, index=void, default=void",
         func: crate::value::dict::Dict::tokay_method_dict_keys,
     },
     Builtin {
         name: "dict_len",
+        signature: "dict_len : @dict",
         func: crate::value::dict::Dict::tokay_method_dict_len,
     },
     Builtin {
         name: "dict_merge",
+        signature: "dict_merge : @dict, other",
         func: crate::value::dict::Dict::tokay_method_dict_merge,
     },
     Builtin {
         name: "dict_pop",
+        signature: "dict_pop : @dict, key=void, default=void",
         func: crate::value::dict::Dict::tokay_method_dict_pop,
     },
     Builtin {
         name: "dict_set_item",
+        signature: "dict_set_item : @dict, key, value=void",
         func: crate::value::dict::Dict::tokay_method_dict_set_item,
     },
     Builtin {
         name: "dict_values",
+        signature: "dict_values : @dict, index=void, default=void",
         func: crate::value::dict::Dict::tokay_method_dict_values,
     },
     Builtin {
         name: "eof",
+        signature: "eof : @",
         func: crate::builtin::tokay_function_eof,
     },
+    Builtin {
+        name: "eprint",
+        signature: "eprint : @*args",
+        func: crate::builtin::tokay_function_eprint,
+    },
     Builtin {
         name: "error",
+        signature: "error : @msg, collect=false, recover=false, raise=true",
         func: crate::error::tokay_function_error,
     },
+    Builtin {
+        name: "error_col",
+        signature: "error_col : @e",
+        func: crate::error::Error::tokay_method_error_col,
+    },
+    Builtin {
+        name: "error_filename",
+        signature: "error_filename : @e",
+        func: crate::error::Error::tokay_method_error_filename,
+    },
+    Builtin {
+        name: "error_kind",
+        signature: "error_kind : @e",
+        func: crate::error::Error::tokay_method_error_kind,
+    },
+    Builtin {
+        name: "error_msg",
+        signature: "error_msg : @e",
+        func: crate::error::Error::tokay_method_error_msg,
+    },
+    Builtin {
+        name: "error_offset",
+        signature: "error_offset : @e",
+        func: crate::error::Error::tokay_method_error_offset,
+    },
+    Builtin {
+        name: "error_row",
+        signature: "error_row : @e",
+        func: crate::error::Error::tokay_method_error_row,
+    },
+    #[cfg(feature = "io")]
+    Builtin {
+        name: "file_close",
+        signature: "file_close : @file",
+        func: crate::builtin::file::File::tokay_method_file_close,
+    },
+    #[cfg(feature = "io")]
+    Builtin {
+        name: "file_iter",
+        signature: "file_iter : @file",
+        func: crate::builtin::file::File::tokay_method_file_iter,
+    },
+    #[cfg(feature = "io")]
+    Builtin {
+        name: "file_lines",
+        signature: "file_lines : @file",
+        func: crate::builtin::file::File::tokay_method_file_lines,
+    },
+    #[cfg(feature = "io")]
+    Builtin {
+        name: "file_read",
+        signature: "file_read : @file",
+        func: crate::builtin::file::File::tokay_method_file_read,
+    },
+    #[cfg(feature = "io")]
+    Builtin {
+        name: "file_write",
+        signature: "file_write : @file, text",
+        func: crate::builtin::file::File::tokay_method_file_write,
+    },
     Builtin {
         name: "float",
+        signature: "float : @value",
         func: crate::value::value::Value::tokay_method_float,
     },
     Builtin {
         name: "float_ceil",
+        signature: "float_ceil : @float",
         func: crate::value::value::Value::tokay_method_float_ceil,
     },
     Builtin {
         name: "float_fract",
+        signature: "float_fract : @float",
         func: crate::value::value::Value::tokay_method_float_fract,
     },
     Builtin {
         name: "float_trunc",
+        signature: "float_trunc : @float",
         func: crate::value::value::Value::tokay_method_float_trunc,
     },
     Builtin {
         name: "int",
+        signature: "int : @value",
         func: crate::value::value::Value::tokay_method_int,
     },
     Builtin {
         name: "iter",
+        signature: "iter : @value",
         func: crate::value::iter::iter::Iter::tokay_method_iter,
     },
     Builtin {
         name: "iter_collect",
+        signature: "iter_collect : @iter",
         func: crate::value::iter::iter::Iter::tokay_method_iter_collect,
     },
     Builtin {
         name: "iter_enum",
+        signature: "iter_enum : @iter",
         func: crate::value::iter::enumiter::EnumIter::tokay_method_iter_enum,
     },
     Builtin {
         name: "iter_len",
+        signature: "iter_len : @iter",
         func: crate::value::iter::iter::Iter::tokay_method_iter_len,
     },
     Builtin {
         name: "iter_map",
+        signature: "iter_map : @iter, map",
         func: crate::value::iter::mapiter::MapIter::tokay_method_iter_map,
     },
     Builtin {
         name: "iter_max",
+        signature: "iter_max : @iter",
         func: crate::value::iter::iter::Iter::tokay_method_iter_max,
     },
     Builtin {
         name: "iter_min",
+        signature: "iter_min : @iter",
         func: crate::value::iter::iter::Iter::tokay_method_iter_min,
     },
     Builtin {
         name: "iter_next",
+        signature: "iter_next : @iter",
         func: crate::value::iter::iter::Iter::tokay_method_iter_next,
     },
     Builtin {
         name: "iter_rev",
+        signature: "iter_rev : @iter",
         func: crate::value::iter::iter::Iter::tokay_method_iter_rev,
     },
+    Builtin {
+        name: "json_decode",
+        signature: "json_decode : @json",
+        func: crate::builtin::json::tokay_function_json_decode,
+    },
+    Builtin {
+        name: "json_encode",
+        signature: "json_encode : @value, indent=void",
+        func: crate::builtin::json::tokay_function_json_encode,
+    },
     Builtin {
         name: "list",
+        signature: "list : @*args",
         func: crate::value::list::List::tokay_method_list,
     },
     Builtin {
         name: "list_add",
+        signature: "list_add : @list, append",
         func: crate::value::list::List::tokay_method_list_add,
     },
     Builtin {
         name: "list_clone",
+        signature: "list_clone : @list",
         func: crate::value::list::List::tokay_method_list_clone,
     },
+    Builtin {
+        name: "list_contains",
+        signature: "list_contains : @list, item",
+        func: crate::value::list::List::tokay_method_list_contains,
+    },
     Builtin {
         name: "list_extend",
+        signature: "list_extend : @list, extend, index=void",
         func: crate::value::list::List::tokay_method_list_extend,
     },
     Builtin {
         name: "list_flatten",
+        signature: "list_flatten : @list",
         func: crate::value::list::List::tokay_method_list_flatten,
     },
     Builtin {
         name: "list_get_item",
+        signature: "list_get_item : @list, index, default=void, upsert=false",
         func: crate::value::list::List::tokay_method_list_get_item,
     },
     Builtin {
         name: "list_iadd",
+        signature: "list_iadd : @list, append",
         func: crate::value::list::List::tokay_method_list_iadd,
     },
     Builtin {
         name: "list_index",
+        signature: "list_index : @list, item",
         func: crate::value::list::List::tokay_method_list_index,
     },
     Builtin {
         name: "list_len",
+        signature: "list_len : @list",
         func: crate::value::list::List::tokay_method_list_len,
     },
     Builtin {
         name: "list_pop",
+        signature: "list_pop : @list, index=void",
         func: crate::value::list::List::tokay_method_list_pop,
     },
     Builtin {
         name: "list_push",
+        signature: "list_push : @list, item, index=void",
         func: crate::value::list::List::tokay_method_list_push,
     },
     Builtin {
         name: "list_set_item",
+        signature: "list_set_item : @list, item, value=void",
         func: crate::value::list::List::tokay_method_list_set_item,
     },
     Builtin {
         name: "list_sort",
+        signature: "list_sort : @list",
         func: crate::value::list::List::tokay_method_list_sort,
     },
+    Builtin {
+        name: "next_input",
+        signature: "next_input : @",
+        func: crate::builtin::tokay_function_next_input,
+    },
     Builtin {
         name: "offset",
+        signature: "offset : @",
         func: crate::builtin::tokay_function_offset,
     },
+    #[cfg(feature = "io")]
+    Builtin {
+        name: "open",
+        signature: "open : @filename, mode=void",
+        func: crate::builtin::file::tokay_function_open,
+    },
     Builtin {
         name: "ord",
+        signature: "ord : @c",
         func: crate::builtin::tokay_function_ord,
     },
+    Builtin {
+        name: "parse",
+        signature: "parse : @parselet, input=0",
+        func: crate::builtin::tokay_function_parse,
+    },
     Builtin {
         name: "print",
+        signature: "print : @*args",
         func: crate::builtin::tokay_function_print,
     },
     Builtin {
         name: "range",
+        signature: "range : @start, stop=void, step=1",
         func: crate::builtin::range::tokay_function_range,
     },
     Builtin {
         name: "repr",
+        signature: "repr : @value",
         func: crate::builtin::tokay_function_repr,
     },
+    Builtin {
+        name: "slice",
+        signature: "slice : @start=void, stop=void, step=void",
+        func: crate::value::slice::Slice::tokay_method_slice,
+    },
     Builtin {
         name: "str",
+        signature: "str : @value",
         func: crate::value::str::Str::tokay_method_str,
     },
     Builtin {
         name: "str_add",
+        signature: "str_add : @s, append",
         func: crate::value::str::Str::tokay_method_str_add,
     },
     Builtin {
         name: "str_byteslen",
+        signature: "str_byteslen : @s",
         func: crate::value::str::Str::tokay_method_str_byteslen,
     },
+    Builtin {
+        name: "str_contains",
+        signature: "str_contains : @s, sub",
+        func: crate::value::str::Str::tokay_method_str_contains,
+    },
     Builtin {
         name: "str_endswith",
+        signature: "str_endswith : @s, postfix",
         func: crate::value::str::Str::tokay_method_str_endswith,
     },
     Builtin {
         name: "str_find",
+        signature: "str_find : @s, pat, start=0, end=void",
         func: crate::value::str::Str::tokay_method_str_find,
     },
     Builtin {
         name: "str_get_item",
+        signature: "str_get_item : @s, item, default=void",
         func: crate::value::str::Str::tokay_method_str_get_item,
     },
     Builtin {
         name: "str_join",
+        signature: "str_join : @s, list",
         func: crate::value::str::Str::tokay_method_str_join,
     },
     Builtin {
         name: "str_len",
+        signature: "str_len : @s",
         func: crate::value::str::Str::tokay_method_str_len,
     },
     Builtin {
         name: "str_lower",
+        signature: "str_lower : @s",
         func: crate::value::str::Str::tokay_method_str_lower,
     },
     Builtin {
         name: "str_mul",
+        signature: "str_mul : @s, count",
         func: crate::value::str::Str::tokay_method_str_mul,
     },
     Builtin {
         name: "str_replace",
+        signature: "str_replace : @s, from, to=void, n=void",
         func: crate::value::str::Str::tokay_method_str_replace,
     },
     Builtin {
         name: "str_split",
+        signature: "str_split : @s, sep=void, n=void",
         func: crate::value::str::Str::tokay_method_str_split,
     },
     Builtin {
         name: "str_startswith",
+        signature: "str_startswith : @s, prefix",
         func: crate::value::str::Str::tokay_method_str_startswith,
     },
     Builtin {
         name: "str_substr",
+        signature: "str_substr : @s, start=0, length=void",
         func: crate::value::str::Str::tokay_method_str_substr,
     },
     Builtin {
         name: "str_upper",
+        signature: "str_upper : @s",
         func: crate::value::str::Str::tokay_method_str_upper,
     },
     Builtin {
         name: "type",
+        signature: "type : @value",
         func: crate::builtin::tokay_function_type,
     },
+    Builtin {
+        name: "yaml_encode",
+        signature: "yaml_encode : @value",
+        func: crate::builtin::yaml::tokay_function_yaml_encode,
+    },
 ];
 /*ETARENEG*/
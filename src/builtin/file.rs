@@ -0,0 +1,281 @@
+//! File object and file I/O builtins
+#![cfg(feature = "io")]
+use crate::error::ErrorKind;
+use crate::value::{BoxedObject, Iter, Object, RefValue, RefValueIter};
+use crate::{Context, Error};
+use std::cell::RefCell;
+use std::fs;
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+use std::rc::Rc;
+use tokay_macros::{tokay_function, tokay_method};
+extern crate self as tokay;
+
+// Underlying handle of an opened file
+enum Handle {
+    Read(BufReader<fs::File>),
+    Write(BufWriter<fs::File>),
+}
+
+/** File object type
+
+A file is opened by `open(filename, mode)` for reading, writing or appending. Clones of a file
+share the same handle, so reading, writing and closing affects all of them. */
+#[derive(Clone)]
+pub struct File {
+    filename: String,
+    mode: &'static str,
+    handle: Rc<RefCell<Option<Handle>>>, // None when closed
+}
+
+impl Object for File {
+    fn name(&self) -> &'static str {
+        "file"
+    }
+
+    fn repr(&self) -> String {
+        format!("<{} {:?} ({})>", self.name(), self.filename, self.mode)
+    }
+}
+
+impl std::fmt::Debug for File {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.repr())
+    }
+}
+
+impl PartialEq for File {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.handle, &other.handle)
+    }
+}
+
+impl PartialOrd for File {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.id().partial_cmp(&other.id())
+    }
+}
+
+impl From<File> for RefValue {
+    fn from(value: File) -> Self {
+        RefValue::from(Box::new(value) as BoxedObject)
+    }
+}
+
+#[derive(Clone)]
+struct LinesIter {
+    file: File,
+    failed: bool,         // Reading failed, the iteration is finished
+    error: Option<Error>, // Error of the failed read, until it is taken
+}
+
+impl RefValueIter for LinesIter {
+    fn next(&mut self, _context: Option<&mut Context>) -> Option<RefValue> {
+        if self.failed {
+            return None;
+        }
+
+        let mut handle = self.file.handle.borrow_mut();
+
+        if let Some(Handle::Read(reader)) = handle.as_mut() {
+            let mut line = String::new();
+
+            match reader.read_line(&mut line) {
+                Ok(0) => {}
+                Ok(_) => {
+                    // Strip the line break
+                    if line.ends_with('\n') {
+                        line.pop();
+
+                        if line.ends_with('\r') {
+                            line.pop();
+                        }
+                    }
+
+                    return Some(RefValue::from(line));
+                }
+                Err(err) => {
+                    self.failed = true;
+                    self.error = Some(
+                        Error::from(format!(
+                            "file_lines() failed on {:?}: {}",
+                            self.file.filename, err
+                        ))
+                        .with_kind(ErrorKind::Io),
+                    );
+                }
+            }
+        }
+
+        None
+    }
+
+    fn take_error(&mut self) -> Option<Error> {
+        self.error.take()
+    }
+
+    fn repr(&self) -> String {
+        format!("<lines of {}>", self.file.repr())
+    }
+}
+
+#[allow(unused_doc_comments)]
+impl File {
+    // Run `func` with the handle of the file provided as `file` argument.
+    fn with_handle<T>(
+        function: &str,
+        file: &RefValue,
+        func: impl FnOnce(&File, &mut Handle) -> Result<T, String>,
+    ) -> Result<T, Error> {
+        let file = file.borrow();
+
+        let Some(file) = file.object::<File>() else {
+            return Err(Error::from(format!(
+                "{} only accepts 'file' as parameter, not '{}'",
+                function,
+                file.name()
+            )));
+        };
+
+        let mut handle = file.handle.borrow_mut();
+
+        let Some(handle) = handle.as_mut() else {
+            return Err(Error::from(format!(
+                "{} file {:?} is closed",
+                function, file.filename
+            )));
+        };
+
+        func(file, handle).map_err(|err| Error::from(format!("{} {}", function, err)))
+    }
+
+    /// Reads the remaining content of a `file` opened for reading into a str.
+    tokay_method!("file_read : @file", {
+        Self::with_handle(__function, &file, |file, handle| match handle {
+            Handle::Read(reader) => {
+                let mut content = String::new();
+
+                reader
+                    .read_to_string(&mut content)
+                    .map_err(|err| format!("failed on {:?}: {}", file.filename, err))?;
+
+                Ok(RefValue::from(content))
+            }
+            Handle::Write(_) => Err(format!(
+                "file {:?} is not opened for reading",
+                file.filename
+            )),
+        })
+    });
+
+    /** Returns an iterator over the lines of a `file` opened for reading.
+
+    The lines are provided without their line breaks. */
+    tokay_method!("file_lines : @file", {
+        let lines = Self::with_handle(__function, &file, |file, handle| match handle {
+            Handle::Read(_) => Ok(LinesIter {
+                file: file.clone(),
+                failed: false,
+                error: None,
+            }),
+            Handle::Write(_) => Err(format!(
+                "file {:?} is not opened for reading",
+                file.filename
+            )),
+        })?;
+
+        Ok(RefValue::from(Iter::new(Box::new(lines))))
+    });
+
+    /// Iterates over the lines of a `file`, same as `file_lines()`.
+    tokay_method!("file_iter : @file", {
+        Self::file_lines_internal(context, vec![file], None)
+    });
+
+    /// Writes `text` to a `file` opened for writing or appending.
+    tokay_method!("file_write : @file, text", {
+        let text = text.to_string();
+
+        Self::with_handle(__function, &file, |file, handle| match handle {
+            Handle::Write(writer) => {
+                writer
+                    .write_all(text.as_bytes())
+                    .map_err(|err| format!("failed on {:?}: {}", file.filename, err))?;
+
+                Ok(tokay::value!(void))
+            }
+            Handle::Read(_) => Err(format!(
+                "file {:?} is not opened for writing",
+                file.filename
+            )),
+        })
+    });
+
+    /// Closes a `file`, writing any pending output. Closing a closed file does nothing.
+    tokay_method!("file_close : @file", {
+        if let Some(file) = file.borrow().object::<File>()
+            && let Some(Handle::Write(mut writer)) = file.handle.borrow_mut().take()
+            && let Err(err) = writer.flush()
+        {
+            return Err(Error::from(format!(
+                "{} failed on {:?}: {}",
+                __function, file.filename, err
+            )));
+        }
+
+        Ok(tokay::value!(void))
+    });
+}
+
+// Opens the file `filename` in `mode` "r" for reading (default), "w" for writing or "a" for
+// appending. This requires the `io` feature, and must be permitted by `Thread::allow_io`.
+tokay_function!("open : @filename, mode=void", {
+    if !context
+        .as_ref()
+        .is_some_and(|context| context.thread.allow_io)
+    {
+        return Err(format!("{} file I/O is not permitted", __function).into());
+    }
+
+    let filename = filename.to_string();
+    let mode = if mode.is_void() {
+        "r".to_string()
+    } else {
+        mode.to_string()
+    };
+
+    let (mode, file) = match mode.as_str() {
+        "r" => (
+            "r",
+            fs::File::open(&filename).map(|file| Handle::Read(BufReader::new(file))),
+        ),
+        "w" => (
+            "w",
+            fs::File::create(&filename).map(|file| Handle::Write(BufWriter::new(file))),
+        ),
+        "a" => (
+            "a",
+            fs::OpenOptions::new()
+                .append(true)
+                .create(true)
+                .open(&filename)
+                .map(|file| Handle::Write(BufWriter::new(file))),
+        ),
+        mode => {
+            return Err(format!(
+                "{} invalid mode {:?}, expecting \"r\", \"w\" or \"a\"",
+                __function, mode
+            )
+            .into());
+        }
+    };
+
+    match file {
+        Ok(handle) => RefValue::from(File {
+            filename,
+            mode,
+            handle: Rc::new(RefCell::new(Some(handle))),
+        })
+        .into(),
+        Err(err) => Err(format!("{} cannot open {:?}: {}", __function, filename, err).into()),
+    }
+});
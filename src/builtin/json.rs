@@ -0,0 +1,471 @@
+//! JSON encoding and decoding of Tokay values
+use crate::value;
+use crate::value::{Dict, List, Object, RefValue, Str, Value};
+use num_bigint::BigInt;
+use tokay_macros::tokay_function;
+extern crate self as tokay;
+
+// Maximum nesting depth of arrays and objects, to not overflow the stack
+const MAX_DEPTH: usize = 128;
+
+/** Encode a value into a JSON string.
+
+Dicts become objects, lists become arrays, and str, int, float, bool and null are mapped to their
+JSON counterparts. Void is encoded as null. Integers are written with all their digits, regardless
+of their size. When `indent` is provided, the output is pretty-printed using `indent` spaces per
+level. Values nested deeper than 128 levels, e.g. a list containing itself, can't be encoded. */
+pub fn encode(value: &RefValue, indent: Option<usize>) -> Result<String, String> {
+    let mut json = String::new();
+    encode_value(&mut json, value, indent, 0)?;
+    Ok(json)
+}
+
+fn encode_str(json: &mut String, string: &str) {
+    json.push('"');
+
+    for ch in string.chars() {
+        match ch {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            '\x08' => json.push_str("\\b"),
+            '\x0c' => json.push_str("\\f"),
+            ch if (ch as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => json.push(ch),
+        }
+    }
+
+    json.push('"');
+}
+
+fn encode_value(
+    json: &mut String,
+    value: &RefValue,
+    indent: Option<usize>,
+    level: usize,
+) -> Result<(), String> {
+    if level > MAX_DEPTH {
+        return Err(format!(
+            "Cannot encode values nested deeper than {} levels as JSON",
+            MAX_DEPTH
+        ));
+    }
+
+    // Line break and indentation for the given level, if pretty-printing
+    let newline = |json: &mut String, level: usize| {
+        if let Some(indent) = indent {
+            json.push('\n');
+            json.push_str(&" ".repeat(indent * level));
+        }
+    };
+
+    let value = value.borrow();
+
+    match &*value {
+        Value::Void | Value::Null => json.push_str("null"),
+        Value::True => json.push_str("true"),
+        Value::False => json.push_str("false"),
+        Value::Int(int) => json.push_str(&int.to_string()),
+        Value::Float(float) => {
+            if !float.is_finite() {
+                return Err(format!("Cannot encode float '{}' as JSON", float));
+            }
+
+            json.push_str(&value.repr())
+        }
+        Value::Object(object) => {
+            if let Some(string) = object.as_any().downcast_ref::<Str>() {
+                encode_str(json, string.as_str());
+            } else if let Some(list) = object.as_any().downcast_ref::<List>() {
+                json.push('[');
+
+                for (i, item) in list.iter().enumerate() {
+                    if i > 0 {
+                        json.push(',');
+                    }
+
+                    newline(json, level + 1);
+                    encode_value(json, item, indent, level + 1)?;
+                }
+
+                if !list.is_empty() {
+                    newline(json, level);
+                }
+
+                json.push(']');
+            } else if let Some(dict) = object.as_any().downcast_ref::<Dict>() {
+                json.push('{');
+
+                for (i, (key, item)) in dict.iter().enumerate() {
+                    if i > 0 {
+                        json.push(',');
+                    }
+
+                    newline(json, level + 1);
+
+                    // Keys which are not a str are converted into a str
+                    encode_str(json, &key.to_string());
+                    json.push(':');
+
+                    if indent.is_some() {
+                        json.push(' ');
+                    }
+
+                    encode_value(json, item, indent, level + 1)?;
+                }
+
+                if !dict.is_empty() {
+                    newline(json, level);
+                }
+
+                json.push('}');
+            } else {
+                return Err(format!("Cannot encode '{}' as JSON", object.name()));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/** Decode a JSON string into a value.
+
+Objects become dicts, arrays become lists, and strings, numbers, booleans and null are
+mapped to str, int, float, bool and null. Integer numbers of any size become int. Arrays and
+objects nested deeper than 128 levels are rejected. */
+pub fn decode(json: &str) -> Result<RefValue, String> {
+    let mut decoder = Decoder {
+        json: json.chars().collect(),
+        pos: 0,
+        depth: 0,
+    };
+
+    let value = decoder.value()?;
+
+    decoder.whitespace();
+
+    if decoder.pos < decoder.json.len() {
+        return Err(decoder.error("end of input"));
+    }
+
+    Ok(value)
+}
+
+struct Decoder {
+    json: Vec<char>,
+    pos: usize,
+    depth: usize, // nesting depth of arrays and objects
+}
+
+impl Decoder {
+    fn position(&self) -> (usize, usize) {
+        let (mut row, mut col) = (1, 1);
+
+        for ch in &self.json[..self.pos] {
+            if *ch == '\n' {
+                row += 1;
+                col = 1;
+            } else {
+                col += 1;
+            }
+        }
+
+        (row, col)
+    }
+
+    fn error(&self, expected: &str) -> String {
+        let (row, col) = self.position();
+
+        match self.peek() {
+            Some(ch) => format!(
+                "Expecting {} at line {}, column {}, but got {:?}",
+                expected, row, col, ch
+            ),
+            None => format!(
+                "Expecting {} at line {}, column {}, but reached end of input",
+                expected, row, col
+            ),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.json.get(self.pos).copied()
+    }
+
+    fn whitespace(&mut self) {
+        while let Some(' ' | '\t' | '\n' | '\r') = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, ch: char) -> Result<(), String> {
+        if self.peek() == Some(ch) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error(&format!("{:?}", ch)))
+        }
+    }
+
+    fn keyword(&mut self, keyword: &str, value: RefValue) -> Result<RefValue, String> {
+        for ch in keyword.chars() {
+            if self.peek() != Some(ch) {
+                return Err(self.error(&format!("'{}'", keyword)));
+            }
+
+            self.pos += 1;
+        }
+
+        Ok(value)
+    }
+
+    fn value(&mut self) -> Result<RefValue, String> {
+        self.whitespace();
+
+        match self.peek() {
+            Some(ch @ ('{' | '[')) => {
+                if self.depth == MAX_DEPTH {
+                    let (row, col) = self.position();
+
+                    return Err(format!(
+                        "Maximum nesting depth of {} exceeded at line {}, column {}",
+                        MAX_DEPTH, row, col
+                    ));
+                }
+
+                self.depth += 1;
+                let value = if ch == '{' {
+                    self.object()
+                } else {
+                    self.array()
+                };
+                self.depth -= 1;
+
+                value
+            }
+            Some('"') => Ok(RefValue::from(self.string()?)),
+            Some('t') => self.keyword("true", value!(true)),
+            Some('f') => self.keyword("false", value!(false)),
+            Some('n') => self.keyword("null", value!(null)),
+            Some('-' | '0'..='9') => self.number(),
+            _ => Err(self.error("value")),
+        }
+    }
+
+    fn object(&mut self) -> Result<RefValue, String> {
+        let mut dict = Dict::new();
+
+        self.expect('{')?;
+        self.whitespace();
+
+        if self.peek() == Some('}') {
+            self.pos += 1;
+            return Ok(RefValue::from(dict));
+        }
+
+        loop {
+            self.whitespace();
+
+            if self.peek() != Some('"') {
+                return Err(self.error("string"));
+            }
+
+            let key = self.string()?;
+
+            self.whitespace();
+            self.expect(':')?;
+
+            dict.insert_str(&key, self.value()?);
+
+            self.whitespace();
+
+            match self.peek() {
+                Some(',') => self.pos += 1,
+                Some('}') => {
+                    self.pos += 1;
+                    return Ok(RefValue::from(dict));
+                }
+                _ => return Err(self.error("',' or '}'")),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<RefValue, String> {
+        let mut list = List::new();
+
+        self.expect('[')?;
+        self.whitespace();
+
+        if self.peek() == Some(']') {
+            self.pos += 1;
+            return Ok(RefValue::from(list));
+        }
+
+        loop {
+            list.push(self.value()?);
+
+            self.whitespace();
+
+            match self.peek() {
+                Some(',') => self.pos += 1,
+                Some(']') => {
+                    self.pos += 1;
+                    return Ok(RefValue::from(list));
+                }
+                _ => return Err(self.error("',' or ']'")),
+            }
+        }
+    }
+
+    fn hex4(&mut self) -> Result<u32, String> {
+        let mut code = 0;
+
+        for _ in 0..4 {
+            match self.peek().and_then(|ch| ch.to_digit(16)) {
+                Some(digit) => code = code * 16 + digit,
+                None => return Err(self.error("hexadecimal digit")),
+            }
+
+            self.pos += 1;
+        }
+
+        Ok(code)
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        let mut string = String::new();
+
+        self.expect('"')?;
+
+        loop {
+            match self.peek() {
+                Some('"') => {
+                    self.pos += 1;
+                    return Ok(string);
+                }
+                Some('\\') => {
+                    self.pos += 1;
+
+                    let ch = match self.peek() {
+                        Some('"') => '"',
+                        Some('\\') => '\\',
+                        Some('/') => '/',
+                        Some('b') => '\x08',
+                        Some('f') => '\x0c',
+                        Some('n') => '\n',
+                        Some('r') => '\r',
+                        Some('t') => '\t',
+                        Some('u') => {
+                            self.pos += 1;
+                            let mut code = self.hex4()?;
+
+                            // Combine UTF-16 surrogate pairs
+                            if (0xd800..0xdc00).contains(&code)
+                                && self.json[self.pos..].starts_with(&['\\', 'u'])
+                            {
+                                let pos = self.pos;
+                                self.pos += 2;
+                                let low = self.hex4()?;
+
+                                if (0xdc00..0xe000).contains(&low) {
+                                    code = 0x10000 + ((code - 0xd800) << 10) + (low - 0xdc00);
+                                } else {
+                                    self.pos = pos;
+                                }
+                            }
+
+                            string.push(char::from_u32(code).unwrap_or('\u{fffd}'));
+                            continue;
+                        }
+                        _ => return Err(self.error("escape sequence")),
+                    };
+
+                    string.push(ch);
+                    self.pos += 1;
+                }
+                Some(ch) if (ch as u32) >= 0x20 => {
+                    string.push(ch);
+                    self.pos += 1;
+                }
+                _ => return Err(self.error("'\"'")),
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<RefValue, String> {
+        let start = self.pos;
+        let mut is_float = false;
+
+        let digits = |decoder: &mut Self| {
+            let start = decoder.pos;
+
+            while let Some('0'..='9') = decoder.peek() {
+                decoder.pos += 1;
+            }
+
+            if decoder.pos == start {
+                Err(decoder.error("digit"))
+            } else {
+                Ok(())
+            }
+        };
+
+        if self.peek() == Some('-') {
+            self.pos += 1;
+        }
+
+        // A leading zero must not be followed by further digits
+        if self.peek() == Some('0') {
+            self.pos += 1;
+        } else {
+            digits(self)?;
+        }
+
+        if self.peek() == Some('.') {
+            self.pos += 1;
+            is_float = true;
+            digits(self)?;
+        }
+
+        if let Some('e' | 'E') = self.peek() {
+            self.pos += 1;
+            is_float = true;
+
+            if let Some('+' | '-') = self.peek() {
+                self.pos += 1;
+            }
+
+            digits(self)?;
+        }
+
+        let number: String = self.json[start..self.pos].iter().collect();
+
+        if is_float {
+            Ok(value!(number.parse::<f64>().unwrap()))
+        } else {
+            Ok(value!(number.parse::<BigInt>().unwrap()))
+        }
+    }
+}
+
+tokay_function!("json_encode : @value, indent=void", {
+    let indent = if indent.is_void() {
+        None
+    } else {
+        Some(indent.to_usize()?)
+    };
+
+    match encode(&value, indent) {
+        Ok(json) => value!(json).into(),
+        Err(err) => Err(format!("{} {}", __function, err).into()),
+    }
+});
+
+tokay_function!("json_decode : @json", {
+    match decode(&json.to_string()) {
+        Ok(value) => value.into(),
+        Err(err) => Err(format!("{} {}", __function, err).into()),
+    }
+});
@@ -2,28 +2,27 @@
 use crate::_builtins::BUILTINS;
 use crate::value;
 use crate::value::{Dict, Object, RefValue, Value};
-use crate::{Accept, Context, Reject};
+use crate::vm::{Capture, Thread};
+use crate::{Accept, Context, Reader, Reject};
 use std::io::{self, Write};
 extern crate self as tokay;
-use tokay_macros::tokay_function;
+use tokay_macros::{tokay_function, tokay_token};
+pub mod file;
+pub mod json;
 pub mod range;
+pub mod yaml;
 
 // Abstraction of a built-in function
 pub struct Builtin {
-    pub name: &'static str, // Function's external name
+    pub name: &'static str,      // Function's external name
+    pub signature: &'static str, // Function's signature, as specified in its definition
     pub func: fn(Option<&mut Context>, Vec<RefValue>, Option<Dict>) -> Result<Accept, Reject>, // Function
 }
 
 impl Builtin {
     /// Retrieve builtin by name
     pub fn get(ident: &str) -> Option<&'static Builtin> {
-        for builtin in &BUILTINS {
-            if builtin.name == ident {
-                return Some(builtin);
-            }
-        }
-
-        None
+        BUILTINS.iter().find(|builtin| builtin.name == ident)
     }
 
     /** Checks for a method on a value given by value type and method name.
@@ -35,7 +34,7 @@ impl Builtin {
     same function.
     */
     pub fn get_method(type_name: &str, method_name: &str) -> Result<&'static Builtin, String> {
-        for builtin in &BUILTINS {
+        for builtin in BUILTINS {
             // todo: This stupid stuff finds the method name without allocating a string.
             // I'm sure this could be done better in some way...
             if builtin.name.starts_with(type_name)
@@ -89,11 +88,16 @@ impl Object for BuiltinRef {
 
     fn call(
         &self,
-        context: Option<&mut Context>,
+        mut context: Option<&mut Context>,
         args: Vec<RefValue>,
         nargs: Option<Dict>,
     ) -> Result<Accept, Reject> {
-        (self.0.func)(context, args, nargs)
+        let ret = (self.0.func)(context.as_deref_mut(), args, nargs);
+
+        match context {
+            Some(context) => self.raise_read_error(context, ret),
+            None => ret,
+        }
     }
 
     fn call_direct(
@@ -103,7 +107,25 @@ impl Object for BuiltinRef {
         nargs: Option<Dict>,
     ) -> Result<Accept, Reject> {
         let args = context.drain(args);
-        (self.0.func)(Some(context), args, nargs)
+        let ret = (self.0.func)(Some(context), args, nargs);
+        self.raise_read_error(context, ret)
+    }
+}
+
+impl BuiltinRef {
+    // Errors reading the input are raised by the consuming builtin which read it
+    fn raise_read_error(
+        &self,
+        context: &mut Context,
+        ret: Result<Accept, Reject>,
+    ) -> Result<Accept, Reject> {
+        if self.is_consuming()
+            && let Some(error) = context.thread.reader.take_error()
+        {
+            return Err(Reject::Error(Box::new(error)));
+        }
+
+        ret
     }
 }
 
@@ -185,30 +207,59 @@ tokay_function!("ord : @c", {
     }
 });
 
-tokay_function!("print : @*args", {
+// Line printed by print() and eprint(), which is the current capture when no args are given
+fn print_line(context: &mut Option<&mut Context>, args: Vec<RefValue>) -> String {
+    let mut line = String::new();
+
     if args.len() == 0 && context.is_some() {
-        let context = context.unwrap();
+        let context = context.as_mut().unwrap();
 
         if let Some(mut capture) = context.get_capture(0) {
             let value = capture.extract(context.thread.reader);
-            print!("{}", value.to_string());
+            line.push_str(&value.to_string());
         }
     } else {
-        for i in 0..args.len() {
+        for (i, arg) in args.iter().enumerate() {
             if i > 0 {
-                print!(" ");
+                line.push(' ');
             }
 
-            print!("{}", args[i].to_string());
+            line.push_str(&arg.to_string());
         }
     }
 
-    print!("\n");
-    io::stdout().flush().unwrap();
+    line.push('\n');
+    line
+}
+
+tokay_function!("print : @*args", {
+    let mut context = context;
+    let line = print_line(&mut context, args);
+
+    // Write into a redirected output, if any
+    if let Some(output) = context.and_then(|context| context.thread.output.as_mut()) {
+        if let Err(err) = output
+            .write_all(line.as_bytes())
+            .and_then(|_| output.flush())
+        {
+            return Err(format!("{} failed: {}", __function, err).into());
+        }
+    } else {
+        print!("{}", line);
+        io::stdout().flush().unwrap();
+    }
 
     value!(void).into() // need to push a void with high severity
 });
 
+tokay_function!("eprint : @*args", {
+    let mut context = context;
+    let line = print_line(&mut context, args);
+
+    eprint!("{}", line);
+    value!(void).into()
+});
+
 tokay_function!("repr : @value", value!(value.repr()).into());
 tokay_function!("type : @value", value!(value.name()).into());
 
@@ -249,3 +300,76 @@ tokay_function!("offset : @", {
 tokay_function!("eof : @", {
     value!(context.unwrap().thread.reader.eof()).into()
 });
+
+// Filename of the current input, or void when the input is not a file
+tokay_token!("FILENAME", {
+    let filename = if let Some(filename) = &context.thread.reader.filename {
+        value!(filename.clone())
+    } else {
+        value!(void)
+    };
+
+    Ok(Accept::Push(Capture::Value(filename, None, 5)))
+});
+
+// Skips the rest of the current input, and continues with the next one
+tokay_function!("next_input : @", {
+    context.unwrap().thread.skip_input = true;
+    value!(void).into()
+});
+
+/* Parses `input` with `parselet` and returns its result, or void when it was rejected.
+
+`input` is either a str, or an index to the inputs of the program, where 0 is the current input
+and 1 the input following it. The position of any input is restored afterwards. */
+tokay_function!("parse : @parselet, input=0", {
+    let context = context.unwrap();
+    let thread = &mut *context.thread;
+
+    let mut string;
+    let reader: &mut Reader = if input.is("str") {
+        string = Reader::new(None, Box::new(io::Cursor::new(input.to_string())));
+        &mut string
+    } else {
+        match input.to_usize()? {
+            0 => &mut *thread.reader,
+            index => match thread.readers.get_mut(index - 1) {
+                Some(reader) => reader,
+                None => {
+                    return Err(format!("{} input {} doesn't exist", __function, index).into());
+                }
+            },
+        }
+    };
+
+    let start = reader.tell();
+
+    // Parse in a thread of its own, sharing the globals
+    let mut sub = Thread::new(thread.program, vec![reader]);
+    sub.debug = thread.debug;
+    sub.allow_io = thread.allow_io;
+    sub.output = thread.output.take();
+    sub.globals = std::mem::take(&mut thread.globals);
+
+    let ret = {
+        let main = thread.program.main();
+        let main = main.0.borrow();
+        let mut context = Context::new(&mut sub, &main, context.depth + 1, Vec::new());
+
+        match parselet.call(Some(&mut context), Vec::new(), None) {
+            Ok(Accept::Push(mut capture) | Accept::Return(mut capture)) => {
+                Ok(capture.extract(context.thread.reader))
+            }
+            Ok(_) | Err(Reject::Next | Reject::Skip) => Ok(value!(void)),
+            Err(reject) => Err(reject),
+        }
+    };
+
+    sub.reader.reset(start);
+
+    thread.output = sub.output;
+    thread.globals = sub.globals;
+    thread.errors.extend(sub.errors);
+
+    Ok(Accept::Push(Capture::Value(ret?, None, 10)))
+});
@@ -0,0 +1,129 @@
+//! YAML encoding of Tokay values
+use crate::value;
+use crate::value::{Dict, List, Object, RefValue, Str, Value};
+use tokay_macros::tokay_function;
+extern crate self as tokay;
+
+/** Encode a value into a YAML string.
+
+Dicts become block mappings, lists become block sequences, and str, int, float, bool and null
+are mapped to their YAML counterparts. Void is encoded as null. */
+pub fn encode(value: &RefValue) -> Result<String, String> {
+    let mut yaml = String::new();
+    encode_value(&mut yaml, value, 0)?;
+    Ok(yaml)
+}
+
+fn encode_str(yaml: &mut String, string: &str) {
+    let mut chars = string.chars();
+
+    // Strings which can't be confused with anything else are written plain
+    let is_plain = chars
+        .next()
+        .is_some_and(|ch| ch.is_alphabetic() || ch == '_')
+        && chars.all(|ch| ch.is_alphanumeric() || matches!(ch, '_' | '-' | '.' | '/'))
+        && !matches!(
+            string.to_lowercase().as_str(),
+            "true" | "false" | "null" | "yes" | "no" | "on" | "off" | "y" | "n"
+        );
+
+    if is_plain {
+        yaml.push_str(string);
+        return;
+    }
+
+    yaml.push('"');
+
+    for ch in string.chars() {
+        match ch {
+            '"' => yaml.push_str("\\\""),
+            '\\' => yaml.push_str("\\\\"),
+            '\n' => yaml.push_str("\\n"),
+            '\r' => yaml.push_str("\\r"),
+            '\t' => yaml.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => yaml.push_str(&format!("\\x{:02x}", ch as u32)),
+            ch => yaml.push(ch),
+        }
+    }
+
+    yaml.push('"');
+}
+
+/// Check for a non-empty list or dict, which is written as a block.
+fn is_block(value: &RefValue) -> bool {
+    let value = value.borrow();
+
+    value.object::<List>().is_some_and(|list| !list.is_empty())
+        || value.object::<Dict>().is_some_and(|dict| !dict.is_empty())
+}
+
+fn encode_value(yaml: &mut String, value: &RefValue, level: usize) -> Result<(), String> {
+    let newline = |yaml: &mut String, level: usize| {
+        yaml.push('\n');
+        yaml.push_str(&"  ".repeat(level));
+    };
+
+    let value = value.borrow();
+
+    match &*value {
+        Value::Void | Value::Null => yaml.push_str("null"),
+        Value::True => yaml.push_str("true"),
+        Value::False => yaml.push_str("false"),
+        Value::Int(int) => yaml.push_str(&int.to_string()),
+        Value::Float(float) if float.is_nan() => yaml.push_str(".nan"),
+        Value::Float(float) if float.is_infinite() => {
+            yaml.push_str(if *float > 0.0 { ".inf" } else { "-.inf" })
+        }
+        Value::Float(_) => yaml.push_str(&value.repr()),
+        Value::Object(object) => {
+            if let Some(string) = object.as_any().downcast_ref::<Str>() {
+                encode_str(yaml, string.as_str());
+            } else if let Some(list) = object.as_any().downcast_ref::<List>() {
+                if list.is_empty() {
+                    yaml.push_str("[]");
+                }
+
+                for (i, item) in list.iter().enumerate() {
+                    if i > 0 {
+                        newline(yaml, level);
+                    }
+
+                    yaml.push_str("- ");
+                    encode_value(yaml, item, level + 1)?;
+                }
+            } else if let Some(dict) = object.as_any().downcast_ref::<Dict>() {
+                if dict.is_empty() {
+                    yaml.push_str("{}");
+                }
+
+                for (i, (key, item)) in dict.iter().enumerate() {
+                    if i > 0 {
+                        newline(yaml, level);
+                    }
+
+                    encode_str(yaml, &key.to_string());
+                    yaml.push(':');
+
+                    if is_block(item) {
+                        newline(yaml, level + 1);
+                    } else {
+                        yaml.push(' ');
+                    }
+
+                    encode_value(yaml, item, level + 1)?;
+                }
+            } else {
+                return Err(format!("Cannot encode '{}' as YAML", object.name()));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+tokay_function!("yaml_encode : @value", {
+    match encode(&value) {
+        Ok(yaml) => value!(yaml).into(),
+        Err(err) => Err(format!("{} {}", __function, err).into()),
+    }
+});
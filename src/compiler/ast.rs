@@ -1,5 +1,6 @@
 //! Compiler's internal Abstract Syntax Tree traversal
 use indexmap::IndexMap;
+use num::{One, Zero};
 use tokay_macros::tokay_function;
 extern crate self as tokay;
 use super::*;
@@ -7,7 +8,7 @@ use crate::builtin::Builtin;
 use crate::reader::Offset;
 use crate::utils;
 use crate::value;
-use crate::value::{Dict, List, Object, RefValue, Str, Token};
+use crate::value::{Dict, List, Object, RefValue, Regex, Str, Token};
 use crate::vm::*;
 use charclass::CharClass;
 
@@ -17,8 +18,34 @@ pub static RESERVED_TOKENS: &[&'static str] = &[
 ];
 
 pub static RESERVED_KEYWORDS: &[&'static str] = &[
-    "accept", "begin", "break", "continue", "else", "end", "exit", "false", "for", "if", "in",
-    "loop", "next", "null", "push", "reject", "repeat", "reset", "return", "self", "true", "void",
+    "accept",
+    "begin",
+    "beginfile",
+    "break",
+    "catch",
+    "continue",
+    "else",
+    "end",
+    "endfile",
+    "exit",
+    "false",
+    "for",
+    "if",
+    "in",
+    "loop",
+    "next",
+    "nomemo",
+    "null",
+    "push",
+    "reject",
+    "repeat",
+    "reset",
+    "return",
+    "self",
+    "true",
+    "try",
+    "use",
+    "void",
 ];
 
 /// AST traversal entry
@@ -80,9 +107,9 @@ fn traverse_node_value(scope: &Scope, node: &Dict, name: Option<String>) -> ImlV
         "value_null" => ImlValue::Value(scope.compiler.statics.borrow()[1].clone()),
         "value_true" => ImlValue::Value(scope.compiler.statics.borrow()[2].clone()),
         "value_false" => ImlValue::Value(scope.compiler.statics.borrow()[3].clone()),
-        "value_integer" => match node["value"].to_i64() {
-            Ok(0) => ImlValue::Value(scope.compiler.statics.borrow()[4].clone()),
-            Ok(1) => ImlValue::Value(scope.compiler.statics.borrow()[5].clone()),
+        "value_integer" => match node["value"].to_bigint() {
+            Ok(int) if int.is_zero() => ImlValue::Value(scope.compiler.statics.borrow()[4].clone()),
+            Ok(int) if int.is_one() => ImlValue::Value(scope.compiler.statics.borrow()[5].clone()),
             _ => scope.compiler.register_static(node["value"].clone()),
         },
         "value_float" => scope.compiler.register_static(node["value"].clone()),
@@ -90,7 +117,10 @@ fn traverse_node_value(scope: &Scope, node: &Dict, name: Option<String>) -> ImlV
 
         // Tokens
         "value_token_void" => ImlValue::VoidToken,
-        "value_token_match" | "value_token_touch" => {
+        "value_token_match"
+        | "value_token_touch"
+        | "value_token_match_nocase"
+        | "value_token_touch_nocase" => {
             let mut value = node["value"].to_string();
 
             if value.len() == 0 {
@@ -101,14 +131,22 @@ fn traverse_node_value(scope: &Scope, node: &Dict, name: Option<String>) -> ImlV
                 value = "#INVALID".to_string();
             }
 
-            scope
-                .compiler
-                .register_static(if emit == "value_token_match" {
-                    RefValue::from(Token::Match(value))
-                } else {
-                    RefValue::from(Token::Touch(value))
-                })
+            scope.compiler.register_static(RefValue::from(match emit {
+                "value_token_match" => Token::Match(value),
+                "value_token_touch" => Token::Touch(value),
+                "value_token_match_nocase" => Token::MatchNoCase(value),
+                _ => Token::TouchNoCase(value),
+            }))
         }
+        "value_token_regex" => match Regex::new(&node["value"].to_string()) {
+            Ok(regex) => scope
+                .compiler
+                .register_static(RefValue::from(Token::Regex(regex))),
+            Err(msg) => {
+                scope.push_error(traverse_node_offset(node), msg);
+                ImlValue::VoidToken
+            }
+        },
         "value_token_any" => scope
             .compiler
             .register_static(RefValue::from(Token::Char(CharClass::new().negate()))),
@@ -173,6 +211,7 @@ fn traverse_node_value(scope: &Scope, node: &Dict, name: Option<String>) -> ImlV
             // Construct generics and signature
             let mut generics = IndexMap::new();
             let mut signature = IndexMap::new();
+            let mut memoize = true;
 
             // Traverse the AST
             let mut sigs = List::from(node["children"].clone());
@@ -185,6 +224,12 @@ fn traverse_node_value(scope: &Scope, node: &Dict, name: Option<String>) -> ImlV
                 let emit = node["emit"].borrow();
                 let emit = emit.object::<Str>().unwrap().as_str();
 
+                // Disable memoization of the parselet's results
+                if emit == "nomemo" {
+                    memoize = false;
+                    continue;
+                }
+
                 let children = List::from(node["children"].clone());
                 let name = children[0].borrow().object::<Dict>().unwrap()["value"].to_string();
 
@@ -196,11 +241,21 @@ fn traverse_node_value(scope: &Scope, node: &Dict, name: Option<String>) -> ImlV
 
                         // Evaluate default parameter
                         let default = if children.len() == 2 {
-                            let default = traverse_node_static(
-                                scope,
-                                Some(name.clone()),
-                                children[1].borrow().object::<Dict>().unwrap(),
-                            );
+                            let default = children[1].borrow();
+                            let default = default.object::<Dict>().unwrap();
+
+                            // A default can refer to a generic defined before
+                            let default = match default["emit"].to_string().as_str() {
+                                "identifier"
+                                    if generics.contains_key(&default["value"].to_string()) =>
+                                {
+                                    ImlValue::Generic {
+                                        offset: traverse_node_offset(default),
+                                        name: default["value"].to_string(),
+                                    }
+                                }
+                                _ => traverse_node_static(scope, Some(name.clone()), default),
+                            };
 
                             if utils::identifier_is_consumable(&name) && !default.is_consuming() {
                                 scope.push_error(
@@ -255,6 +310,13 @@ fn traverse_node_value(scope: &Scope, node: &Dict, name: Option<String>) -> ImlV
                                         )
                                     }
                             );
+                        } else if scope.compiler.restrict
+                            && RESERVED_KEYWORDS.contains(&name.as_str())
+                        {
+                            scope.push_error(
+                                traverse_node_offset(node),
+                                format!("Expected identifier, found reserved word '{}'", name),
+                            );
                         }
 
                         assert!(children.len() <= 2);
@@ -297,6 +359,8 @@ fn traverse_node_value(scope: &Scope, node: &Dict, name: Option<String>) -> ImlV
                 false,
             ));
 
+            new_parselet.borrow().model.borrow_mut().memoize = memoize;
+
             // Push new parselet scope
             let scope = &scope.shadow(ScopeLevel::Parselet(new_parselet.clone()));
 
@@ -628,6 +692,21 @@ fn traverse_node_lvalue(scope: &Scope, node: &Dict, store: bool, hold: bool) ->
                 }
             }
 
+            // attribute ------------------------------------------------------
+            "attribute" => {
+                ops.push(traverse(scope, &item["children"]));
+
+                if store {
+                    if hold {
+                        ops.push(Op::StoreAttrHold.into());
+                    } else {
+                        ops.push(Op::StoreAttr.into());
+                    }
+                } else {
+                    ops.push(Op::LoadAttr.into())
+                }
+            }
+
             // item -----------------------------------------------------------
             "item" => {
                 ops.push(traverse(scope, &item["children"]));
@@ -724,8 +803,27 @@ fn traverse_node_rvalue(scope: &Scope, node: &Dict, mode: Rvalue) -> ImlOp {
             let children = List::from(&node["children"]);
 
             let mut ops = vec![traverse_offset(node)];
+            let mut start = 0;
+
+            // Access to a constant of an imported module, e.g. `module.Name`
+            if let Some(name) = traverse_node_namespace(scope, &children) {
+                let offset = traverse_node_offset(node);
+
+                if children.len() == 2 {
+                    return match mode {
+                        Rvalue::Load => ImlOp::load_by_name(scope, offset, name),
+                        Rvalue::CallOrLoad => ImlOp::call_by_name(scope, offset, name, None),
+                        Rvalue::Call(args, nargs) => {
+                            ImlOp::call_by_name(scope, offset, name, Some((args, nargs)))
+                        }
+                    };
+                }
+
+                ops.push(ImlOp::call_by_name(scope, offset, name, None));
+                start = 2;
+            }
 
-            for i in 0..children.len() {
+            for i in start..children.len() {
                 ops.push(traverse_node_rvalue(
                     scope,
                     children[i].borrow().object::<Dict>().unwrap(),
@@ -773,6 +871,39 @@ fn traverse_node_rvalue(scope: &Scope, node: &Dict, mode: Rvalue) -> ImlOp {
     }
 }
 
+/** Resolve the first two children of an rvalue into the name of a namespaced constant.
+
+An rvalue like `module.Name` refers to the constant `Name` imported from `module`,
+as long as `module` isn't shadowed by any other name in the current scope. */
+fn traverse_node_namespace(scope: &Scope, children: &List) -> Option<String> {
+    if children.len() < 2 {
+        return None;
+    }
+
+    let (first, second) = (children[0].borrow(), children[1].borrow());
+    let (first, second) = (first.object::<Dict>()?, second.object::<Dict>()?);
+
+    if first["emit"].to_string() != "identifier" || second["emit"].to_string() != "attribute" {
+        return None;
+    }
+
+    let namespace = first["value"].to_string();
+
+    if scope.resolve_name(None, &namespace).is_some() {
+        return None;
+    }
+
+    let attribute = second["children"].borrow();
+    let attribute = attribute.object::<Dict>()?;
+
+    if attribute["emit"].to_string() != "value_string" {
+        return None;
+    }
+
+    let name = format!("{}.{}", namespace, attribute["value"].to_string());
+    scope.resolve_name(None, &name).map(|_| name)
+}
+
 fn traverse_node(scope: &Scope, node: &Dict) -> ImlOp {
     let emit = node["emit"].borrow();
     let emit = emit.object::<Str>().unwrap().as_str();
@@ -824,6 +955,12 @@ fn traverse_node(scope: &Scope, node: &Dict) -> ImlOp {
                     "div" => ImlOp::from(Op::BinaryOp(BinaryOp::InlineDiv)),
                     "divi" => ImlOp::from(Op::BinaryOp(BinaryOp::InlineIntDiv)),
                     "mod" => ImlOp::from(Op::BinaryOp(BinaryOp::InlineMod)),
+                    "pow" => ImlOp::from(Op::BinaryOp(BinaryOp::InlinePow)),
+                    "shl" => ImlOp::from(Op::BinaryOp(BinaryOp::InlineShl)),
+                    "shr" => ImlOp::from(Op::BinaryOp(BinaryOp::InlineShr)),
+                    "bitand" => ImlOp::from(Op::BinaryOp(BinaryOp::InlineBitAnd)),
+                    "bitor" => ImlOp::from(Op::BinaryOp(BinaryOp::InlineBitOr)),
+                    "bitxor" => ImlOp::from(Op::BinaryOp(BinaryOp::InlineBitXor)),
                     _ => unreachable!(),
                 });
 
@@ -853,34 +990,33 @@ fn traverse_node(scope: &Scope, node: &Dict) -> ImlOp {
         }
 
         // begin ----------------------------------------------------------
-        "begin" | "end" => {
+        "begin" | "end" | "beginfile" | "endfile" => {
             let body = traverse(scope, &node["children"]);
 
-            if let ScopeLevel::Parselet(parselet) = &scope.level {
+            if emit.ends_with("file") && !scope.is_global() {
+                scope.push_error(
+                    traverse_node_offset(node),
+                    format!("'{}' may only be used in the main scope", emit),
+                );
+            } else if let ScopeLevel::Parselet(parselet) = &scope.level {
                 let parselet = parselet.borrow();
                 let mut model = parselet.model.borrow_mut();
 
-                if emit == "begin" {
-                    match &mut model.begin {
-                        ImlOp::Nop => model.begin = body,
-                        ImlOp::Alt { alts } => alts.push(body),
-                        _ => {
-                            let alt = ImlOp::Alt {
-                                alts: vec![std::mem::replace(&mut model.begin, ImlOp::Nop), body],
-                            };
-                            model.begin = alt;
-                        }
-                    }
-                } else {
-                    match &mut model.end {
-                        ImlOp::Nop => model.end = body,
-                        ImlOp::Alt { alts } => alts.push(body),
-                        _ => {
-                            let alt = ImlOp::Alt {
-                                alts: vec![std::mem::replace(&mut model.end, ImlOp::Nop), body],
-                            };
-                            model.end = alt;
-                        }
+                let part = match emit {
+                    "begin" => &mut model.begin,
+                    "end" => &mut model.end,
+                    "beginfile" => &mut model.begin_file,
+                    _ => &mut model.end_file,
+                };
+
+                match part {
+                    ImlOp::Nop => *part = body,
+                    ImlOp::Alt { alts } => alts.push(body),
+                    _ => {
+                        let alt = ImlOp::Alt {
+                            alts: vec![std::mem::replace(part, ImlOp::Nop), body],
+                        };
+                        *part = alt;
                     }
                 }
             } else {
@@ -893,6 +1029,57 @@ fn traverse_node(scope: &Scope, node: &Dict) -> ImlOp {
             ImlOp::Nop
         }
 
+        // use ------------------------------------------------------------
+        "use" => {
+            let children = List::from(&node["children"]);
+            let offset = traverse_node_offset(node);
+
+            let module = children[0].borrow().object::<Dict>().unwrap()["value"].to_string();
+            let alias = children
+                .get(1)
+                .map(|alias| alias.borrow().object::<Dict>().unwrap()["value"].to_string());
+
+            match scope.compiler.import(&module) {
+                Ok((name, constants)) => {
+                    let namespace = alias.unwrap_or(name);
+
+                    if !namespace.starts_with(|ch: char| ch.is_alphabetic() || ch == '_')
+                        || !namespace
+                            .chars()
+                            .all(|ch| ch.is_alphanumeric() || ch == '_')
+                    {
+                        scope.push_error(
+                            offset,
+                            format!(
+                                "Module name '{}' is not a valid identifier, use 'as' to specify a namespace",
+                                namespace
+                            ),
+                        );
+                    } else {
+                        for (name, value) in constants {
+                            scope.define_constant(&format!("{}.{}", namespace, name), value);
+                        }
+
+                        scope.resolve_usages();
+                    }
+                }
+                Err(errors) => {
+                    for mut error in errors {
+                        error.message = format!("In module '{}': {}", module, error.message);
+
+                        // Errors located in the module's source keep their location
+                        if let Some(offset) = offset {
+                            error.patch_offset(offset);
+                        }
+
+                        scope.errors.borrow_mut().push(error);
+                    }
+                }
+            }
+
+            ImlOp::Nop
+        }
+
         // block ----------------------------------------------------------
         "block" | "body" | "main" => {
             if let Some(ast) = node.get_str("children") {
@@ -1086,6 +1273,8 @@ fn traverse_node(scope: &Scope, node: &Dict) -> ImlOp {
                     "cmp_gteq" => Op::BinaryOp(BinaryOp::GtEq),
                     "cmp_lt" => Op::BinaryOp(BinaryOp::Lt),
                     "cmp_gt" => Op::BinaryOp(BinaryOp::Gt),
+                    "cmp_in" => Op::BinaryOp(BinaryOp::In),
+                    "cmp_notin" => Op::BinaryOp(BinaryOp::NotIn),
                     _ => unimplemented!("{}", emit),
                 }));
 
@@ -1321,6 +1510,7 @@ fn traverse_node(scope: &Scope, node: &Dict) -> ImlOp {
                         "iinc" => UnaryOp::Inc,
                         "neg" => UnaryOp::Neg,
                         "not" => UnaryOp::Not,
+                        "bitnot" => UnaryOp::BitNot,
                         _ => unimplemented!("{}", emit),
                     };
 
@@ -1383,6 +1573,16 @@ fn traverse_node(scope: &Scope, node: &Dict) -> ImlOp {
                                 else_: Box::new(ImlOp::from(Op::Push)),
                             }
                         }
+                        // A bitwise-or with any consuming operand is an alternation, e.g. `Int | Float`
+                        "bitor" if left.is_consuming() || right.is_consuming() => {
+                            let mut alts = match left {
+                                ImlOp::Alt { alts } => alts,
+                                left => vec![left],
+                            };
+
+                            alts.push(right);
+                            return ImlOp::Alt { alts };
+                        }
                         _ => {
                             let op = match parts[2] {
                                 "add" => BinaryOp::Add,
@@ -1391,6 +1591,12 @@ fn traverse_node(scope: &Scope, node: &Dict) -> ImlOp {
                                 "div" => BinaryOp::Div,
                                 "divi" => BinaryOp::IntDiv,
                                 "mod" => BinaryOp::Mod,
+                                "pow" => BinaryOp::Pow,
+                                "shl" => BinaryOp::Shl,
+                                "shr" => BinaryOp::Shr,
+                                "bitand" => BinaryOp::BitAnd,
+                                "bitor" => BinaryOp::BitOr,
+                                "bitxor" => BinaryOp::BitXor,
                                 _ => {
                                     unimplemented!("{}", emit);
                                 }
@@ -1491,7 +1697,9 @@ fn traverse_node(scope: &Scope, node: &Dict) -> ImlOp {
                                 }
                             }
                             // fixme: This is an ugly hack to keep severity for modified versions
-                            Some(Token::Touch(_)) => assume_severity = Some(0),
+                            Some(Token::Touch(_) | Token::TouchNoCase(_)) => {
+                                assume_severity = Some(0)
+                            }
                             _ => {}
                         }
                     }
@@ -1621,6 +1829,51 @@ fn traverse_node(scope: &Scope, node: &Dict) -> ImlOp {
                     }
                 }
 
+                "try" => {
+                    let children = List::from(&node["children"]);
+
+                    let body = &children[0].borrow();
+                    let body = traverse_node_rvalue(
+                        scope,
+                        body.object::<Dict>().unwrap(),
+                        Rvalue::CallOrLoad,
+                    );
+
+                    let mut catch = Vec::new();
+                    let mut children = children.iter().skip(1).peekable();
+
+                    // The error is either stored into a variable, or dropped
+                    if let Some(lvalue) = children.next_if(|child| {
+                        child.borrow().object::<Dict>().unwrap()["emit"].to_string() == "lvalue"
+                    }) {
+                        let lvalue = lvalue.borrow();
+                        catch.push(traverse_node_lvalue(
+                            scope,
+                            lvalue.object::<Dict>().unwrap(),
+                            true,
+                            false,
+                        ));
+                    } else {
+                        catch.push(ImlOp::from(Op::Drop));
+                    }
+
+                    if let Some(catch_part) = children.next() {
+                        let catch_part = catch_part.borrow();
+                        catch.push(traverse_node_rvalue(
+                            scope,
+                            catch_part.object::<Dict>().unwrap(),
+                            Rvalue::CallOrLoad,
+                        ));
+                    } else {
+                        catch.push(ImlOp::from(Op::Push));
+                    }
+
+                    ImlOp::Try {
+                        body: Box::new(body),
+                        catch: Box::new(ImlOp::from(catch)),
+                    }
+                }
+
                 "loop" => {
                     let children = List::from(&node["children"]);
 
@@ -1674,6 +1927,41 @@ fn traverse_node(scope: &Scope, node: &Dict) -> ImlOp {
             ImlOp::from(ops)
         }
 
+        // slice ----------------------------------------------------------
+        "slice" => {
+            let children = if let Some(children) = node.get_str("children") {
+                List::from(children)
+            } else {
+                List::new()
+            };
+
+            let mut ops = Vec::new();
+
+            // Push start, stop and step, where any of them not specified is void
+            for part in ["slice_start", "slice_stop", "slice_step"] {
+                if let Some(child) = children.iter().find(|child| {
+                    child.borrow().object::<Dict>().unwrap()["emit"].to_string() == part
+                }) {
+                    ops.push(traverse(
+                        scope,
+                        &child.borrow().object::<Dict>().unwrap()["children"],
+                    ));
+                } else {
+                    ops.push(ImlOp::load(scope, None, ImlValue::from(value!(void))));
+                }
+            }
+
+            let slice = ImlValue::from(RefValue::from(Builtin::get("slice").unwrap()));
+            ops.push(ImlOp::call(
+                scope,
+                traverse_node_offset(node),
+                slice,
+                Some((3, false)),
+            ));
+
+            ImlOp::from(ops)
+        }
+
         // sequence, dict, list  -----------------------------------------
         "sequence" | "dict" | "list" => {
             let children = if let Some(children) = node.get_str("children") {
@@ -1715,8 +2003,10 @@ pub fn print(ast: &RefValue) {
     fn print(value: &RefValue, indent: usize) {
         let value = value.borrow();
 
-        if let Some(d) = value.object::<Dict>() {
-            let emit = d["emit"].to_string();
+        if let Some(d) = value.object::<Dict>()
+            && let Some(emit) = d.get_str("emit")
+        {
+            let emit = emit.to_string();
 
             let row = d
                 .get_str("row")
@@ -1768,6 +2058,22 @@ pub fn print(ast: &RefValue) {
         }
     }
 
+    let value = ast.borrow();
+
+    // Anything else than AST nodes is printed as is
+    if value.object::<List>().is_none()
+        && value
+            .object::<Dict>()
+            .is_none_or(|d| d.get_str("emit").is_none())
+    {
+        if !value.is_void() {
+            println!("{}", value.repr());
+        }
+
+        return;
+    }
+
+    drop(value);
     print(ast, 0);
 }
 
@@ -1821,17 +2127,16 @@ tokay_function!("ast : @emit, value=void, flatten=true, debug=false", {
     }
 
     let start = context.frame.reader_start;
-    let reader_start = context.thread.reader.start();
 
     // Store positions of reader start
-    ret.insert_str("offset", value!(start.offset + reader_start.offset));
+    ret.insert_str("offset", value!(start.offset));
     ret.insert_str("row", value!(start.row as usize));
     ret.insert_str("col", value!(start.col as usize));
 
     // Store positions of reader stop
     let current = context.thread.reader.tell();
 
-    ret.insert_str("stop_offset", value!(current.offset + reader_start.offset));
+    ret.insert_str("stop_offset", value!(current.offset));
     ret.insert_str("stop_row", value!(current.row as usize));
     ret.insert_str("stop_col", value!(current.col as usize));
 
@@ -1,7 +1,7 @@
 //! Tokay compiler
 
 use super::*;
-use crate::error::Error;
+use crate::error::{Error, ErrorKind};
 use crate::reader::*;
 use crate::value;
 use crate::value::RefValue;
@@ -9,6 +9,9 @@ use crate::vm::*;
 use indexmap::{IndexMap, IndexSet, indexset};
 use log;
 use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
 
 /** Tokay compiler instance
 
@@ -20,8 +23,12 @@ won't be removed and can be accessed on later calls.
 pub struct Compiler {
     parser: Option<parser::Parser>, // Internal Tokay parser
     pub debug: u8,                  // Compiler debug mode
+    pub allow_io: bool, // Permit file I/O builtins like open() in compiled programs, defaults to false
     pub(super) restrict: bool, // Restrict assignment of reserved identifiers (required by prelude bootstrap)
     pub(super) statics: RefCell<IndexSet<RefValue>>, // Static values collected during compilation
+    pub path: Vec<PathBuf>,    // Module search path
+    modules: RefCell<HashMap<PathBuf, Option<IndexMap<String, ImlValue>>>>, // Imported modules cache
+    sources: RefCell<Vec<PathBuf>>, // Directories of the sources currently compiled
 
     // TODO: As workaround to emulate old behavior of the Compiler struct
     main: ImlParseletModel,                // keep global parselet
@@ -50,8 +57,12 @@ impl Compiler {
         let mut compiler = Self {
             parser: None,
             debug: 0,
+            allow_io: false,
             restrict: false,
             statics: RefCell::new(statics),
+            path: Vec::new(),
+            modules: RefCell::new(HashMap::new()),
+            sources: RefCell::new(Vec::new()),
             // TODO: workaround...
             main: ImlParseletModel::new(None),
             constants: IndexMap::new(),
@@ -61,6 +72,11 @@ impl Compiler {
         compiler.load_prelude();
         compiler.restrict = true;
 
+        // Set module search path from environment
+        if let Some(path) = std::env::var_os("TOKAY_PATH") {
+            compiler.path = std::env::split_paths(&path).collect();
+        }
+
         // Set compiler debug level afterwards
         compiler.debug = if let Ok(level) = std::env::var("TOKAY_DEBUG") {
             level.parse::<u8>().unwrap_or_default()
@@ -115,8 +131,7 @@ impl Compiler {
             // println!("usages = {:?}", global_scope.usages);
 
             for usage in global_scope.usages.borrow_mut().drain(..) {
-                global_scope
-                    .push_error(usage.offset(), format!("Use of undefined name '{}'", usage));
+                global_scope.push_undefined(&usage);
             }
 
             // Break on error
@@ -135,6 +150,8 @@ impl Compiler {
         self.main.body = ImlOp::Nop;
         self.main.begin = ImlOp::Nop;
         self.main.end = ImlOp::Nop;
+        self.main.begin_file = ImlOp::Nop;
+        self.main.end_file = ImlOp::Nop;
 
         /*
         if self.debug > 1 {
@@ -149,7 +166,9 @@ impl Compiler {
         let program = ImlProgram::new(main_parselet);
 
         match program.compile() {
-            Ok(program) => {
+            Ok(mut program) => {
+                program.allow_io = self.allow_io;
+
                 if self.debug > 1 {
                     println!("--- Finalized program ---");
                     program.dump();
@@ -161,20 +180,48 @@ impl Compiler {
         }
     }
 
-    /** Compile a Tokay program from a Reader source into the compiler. */
-    pub fn compile(&mut self, reader: Reader) -> Result<Option<Program>, Vec<Error>> {
-        log::trace!("compile");
-
+    /** Parse a Tokay program from a Reader source into its abstract syntax tree. */
+    pub fn parse(&mut self, reader: Reader) -> Result<RefValue, Vec<Error>> {
         // Create the Tokay parser when not already done
         if self.parser.is_none() {
             self.parser = Some(Parser::new());
         }
 
-        let parser = self.parser.as_ref().unwrap();
-        let ast = match parser.parse(reader) {
+        self.parser.as_ref().unwrap().parse(reader)
+    }
+
+    /** Compile a Tokay program from a Reader source into the compiler. */
+    pub fn compile(&mut self, mut reader: Reader) -> Result<Option<Program>, Vec<Error>> {
+        log::trace!("compile");
+
+        // Remember the source's directory to resolve modules relative to it
+        let dir = reader
+            .filename
+            .as_ref()
+            .filter(|filename| *filename != "-")
+            .and_then(|filename| Path::new(filename).parent().map(|dir| dir.to_path_buf()));
+
+        // Keep the source code, to locate errors in it
+        let source = Rc::new(Source {
+            filename: reader.filename.clone(),
+            code: reader.read_to_string().map_err(|error| vec![error])?,
+        });
+
+        let locate = |mut errors: Vec<Error>| {
+            for error in errors.iter_mut() {
+                error.locate(&source);
+            }
+
+            errors
+        };
+
+        let ast = match self.parse(Reader::new(
+            source.filename.clone(),
+            Box::new(std::io::Cursor::new(source.code.clone())),
+        )) {
             Ok(ast) => ast,
-            Err(error) => {
-                return Err(vec![error]);
+            Err(errors) => {
+                return Err(locate(errors));
             }
         };
 
@@ -183,7 +230,17 @@ impl Compiler {
             //println!("###\n{:#?}\n###", ast);
         }
 
-        self.compile_from_ast(&ast, None)
+        // Parselets of the program refer to its source
+        self.main.source = Some(source.clone());
+
+        if let Some(dir) = dir {
+            self.sources.borrow_mut().push(dir);
+            let ret = self.compile_from_ast(&ast, None);
+            self.sources.borrow_mut().pop();
+            ret.map_err(locate)
+        } else {
+            self.compile_from_ast(&ast, None).map_err(locate)
+        }
     }
 
     /// Shortcut to compile a Tokay program from a &str into the compiler.
@@ -194,6 +251,199 @@ impl Compiler {
         ))
     }
 
+    /** Import a module by its name or filename.
+
+    A module is a Tokay source file which may only define constants. These constants are
+    returned together with the module's default namespace, which is the module's file stem.
+
+    Modules are searched relative to the currently compiled source, then in the module search
+    path, and finally in the current working directory. Every module is compiled only once,
+    further imports are served from a cache.
+    */
+    pub(super) fn import(
+        &self,
+        module: &str,
+    ) -> Result<(String, IndexMap<String, ImlValue>), Vec<Error>> {
+        log::trace!("import module = {:?}", module);
+
+        let filename = if module.ends_with(".tok") {
+            PathBuf::from(module)
+        } else {
+            PathBuf::from(format!("{}.tok", module))
+        };
+
+        let path = if filename.is_absolute() {
+            Some(filename.clone())
+        } else {
+            self.sources
+                .borrow()
+                .last()
+                .into_iter()
+                .chain(self.path.iter())
+                .chain([PathBuf::from(".")].iter())
+                .map(|dir| dir.join(&filename))
+                .find(|path| path.is_file())
+        };
+
+        let path = match path.and_then(|path| path.canonicalize().ok()) {
+            Some(path) => path,
+            None => {
+                return Err(vec![
+                    Error::new(None, format!("Module '{}' not found", module))
+                        .with_kind(ErrorKind::Io),
+                ]);
+            }
+        };
+
+        let name = path
+            .file_stem()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        match self.modules.borrow().get(&path) {
+            Some(Some(constants)) => return Ok((name, constants.clone())),
+            Some(None) => {
+                return Err(vec![
+                    format!("Circular import of module '{}'", module).into(),
+                ]);
+            }
+            None => {}
+        }
+
+        // Mark module as being imported, to detect circular imports
+        self.modules.borrow_mut().insert(path.clone(), None);
+
+        let ret = self.compile_module(&path, &name);
+
+        match &ret {
+            Ok(constants) => {
+                self.modules
+                    .borrow_mut()
+                    .insert(path.clone(), Some(constants.clone()));
+            }
+            Err(_) => {
+                self.modules.borrow_mut().remove(&path);
+            }
+        }
+
+        ret.map(|constants| (name, constants))
+    }
+
+    /// Compile a module from path into its own global scope, and return its constants.
+    fn compile_module(
+        &self,
+        path: &Path,
+        name: &str,
+    ) -> Result<IndexMap<String, ImlValue>, Vec<Error>> {
+        let code = match std::fs::read_to_string(path) {
+            Ok(code) => code,
+            Err(error) => {
+                return Err(vec![
+                    Error::new(
+                        None,
+                        format!("Can't open module '{}': {}", path.display(), error),
+                    )
+                    .with_kind(ErrorKind::Io),
+                ]);
+            }
+        };
+
+        // Parselets of the module refer to its source, to locate errors in it
+        let source = Rc::new(Source {
+            filename: Some(path.to_string_lossy().to_string()),
+            code,
+        });
+
+        let locate = |mut errors: Vec<Error>| {
+            for error in errors.iter_mut() {
+                error.locate(&source);
+            }
+
+            errors
+        };
+
+        let ast = Parser::new().parse(Reader::new(
+            source.filename.clone(),
+            Box::new(std::io::Cursor::new(source.code.clone())),
+        ));
+
+        let ast = match ast {
+            Ok(ast) => ast,
+            Err(errors) => return Err(locate(errors)),
+        };
+
+        let module_parselet = ImlRefParselet::new(ImlParselet::new(
+            None,
+            None,
+            None,
+            None,
+            Some(name.to_string()),
+            5,
+            false,
+        ));
+
+        module_parselet.borrow().model.borrow_mut().source = Some(source.clone());
+
+        if let Some(dir) = path.parent() {
+            self.sources.borrow_mut().push(dir.to_path_buf());
+        }
+
+        let scope = Scope::new(self, ScopeLevel::Parselet(module_parselet.clone()), None);
+
+        scope.constants.borrow_mut().extend(self.constants.clone());
+
+        ast::traverse(&scope, &ast);
+        scope.resolve_usages();
+
+        if path.parent().is_some() {
+            self.sources.borrow_mut().pop();
+        }
+
+        for usage in scope.usages.borrow_mut().drain(..) {
+            scope.push_undefined(&usage);
+        }
+
+        // Modules are not allowed to run any code on their own
+        {
+            let parselet = module_parselet.borrow();
+            let model = parselet.model.borrow();
+
+            let is_empty = |op: &ImlOp| match op {
+                ImlOp::Nop => true,
+                ImlOp::Alt { alts } => alts.is_empty(),
+                _ => false,
+            };
+
+            if !is_empty(&model.begin)
+                || !is_empty(&model.end)
+                || !is_empty(&model.begin_file)
+                || !is_empty(&model.end_file)
+                || !is_empty(&model.body)
+                || !model.variables.is_empty()
+            {
+                scope.push_error(
+                    None,
+                    format!(
+                        "Module '{}' may only define constants, but contains code or variables",
+                        name
+                    ),
+                );
+            }
+        }
+
+        if !scope.errors.borrow().is_empty() {
+            return Err(locate(scope.errors.borrow_mut().drain(..).collect()));
+        }
+
+        // Only export constants which are defined by the module itself
+        Ok(scope
+            .constants
+            .take()
+            .into_iter()
+            .filter(|(name, value)| self.constants.get(name) != Some(value))
+            .collect())
+    }
+
     /** Register a static value within a compiler instance.
 
     This avoids that the compiler produces multiple results pointing to effectively the same values
@@ -46,6 +46,12 @@ pub(in crate::compiler) enum ImlOp {
         condition: Box<ImlOp>, // Abort condition
         body: Box<ImlOp>,      // Iterating body
     },
+
+    // Try construct
+    Try {
+        body: Box<ImlOp>,  // Code path which errors are caught of
+        catch: Box<ImlOp>, // Code path executed on error, receiving the error on the stack
+    },
 }
 
 impl ImlOp {
@@ -302,6 +308,19 @@ impl ImlOp {
                     ops.push(Op::Break);
                 }
             }
+            ImlOp::Try { body, catch } => {
+                let mut catch_ops = Vec::new();
+                catch.compile(program, current, &mut catch_ops);
+
+                let backpatch = ops.len();
+                ops.push(Op::Nop); // Backpatch operation placeholder
+
+                let jump = body.compile(program, current, ops) + 2;
+                ops[backpatch] = Op::Try(jump);
+
+                ops.push(Op::Catch(catch_ops.len() + 1));
+                ops.extend(catch_ops);
+            }
         }
 
         ops.len() - start
@@ -333,8 +352,16 @@ impl ImlOp {
 
                     None
                 }
-                ImlOp::If { then, else_, .. } => {
-                    for item in [&then, &else_] {
+                ImlOp::If {
+                    then: first,
+                    else_: second,
+                    ..
+                }
+                | ImlOp::Try {
+                    body: first,
+                    catch: second,
+                } => {
+                    for item in [&first, &second] {
                         if let Some(res) = walk(item) {
                             return Some(res);
                         }
@@ -350,6 +377,94 @@ impl ImlOp {
         walk(self).unwrap_or(false)
     }
 
+    /// Returns the number of ImlOps in this ImlOp, including itself
+    pub fn size(&self) -> usize {
+        1 + match self {
+            ImlOp::Alt { alts: items } | ImlOp::Seq { seq: items, .. } => {
+                items.iter().map(|item| item.size()).sum()
+            }
+            ImlOp::If { then, else_, .. } => then.size() + else_.size(),
+            ImlOp::Loop {
+                initial,
+                condition,
+                body,
+                ..
+            } => initial.size() + condition.size() + body.size(),
+            ImlOp::Try { body, catch } => body.size() + catch.size(),
+            _ => 0,
+        }
+    }
+
+    /** Returns a copy of the ImlOp to be inlined into the code of another parselet,
+    or None when this isn't possible.
+
+    Only calls of consuming values without arguments, alternations, sequences, `reset` and
+    `reject` can be inlined. Generics are resolved from the inlined `parselet`. As inlined code
+    runs within the frames of its caller, `reject` closes the frames opened inside of the inlined
+    code up to the given `depth` before rejecting.
+    */
+    pub fn inline(&self, parselet: &ImlRefParselet, depth: usize) -> Option<ImlOp> {
+        match self {
+            ImlOp::Nop | ImlOp::Op(Op::Nop | Op::Offset(_) | Op::Next | Op::ResetReader) => {
+                Some(self.clone())
+            }
+            ImlOp::Op(Op::Reject) => {
+                let mut seq = vec![ImlOp::Op(Op::Close); depth];
+                seq.push(ImlOp::Op(Op::Next));
+                Some(ImlOp::seq(seq, false))
+            }
+            ImlOp::Call {
+                target,
+                args: None | Some((0, false)),
+                ..
+            } => {
+                let value = match target {
+                    ImlValue::Generic { name, .. } => parselet.resolve(name),
+                    target => target.clone(),
+                };
+
+                // Values used before their definition are shared
+                let value = match value {
+                    ImlValue::Shared(value) => value.borrow().clone(),
+                    value => value,
+                };
+
+                match value {
+                    ImlValue::Value(_) | ImlValue::Parselet(_) | ImlValue::VoidToken
+                        if value.is_consuming() =>
+                    {
+                        Some(self.clone())
+                    }
+                    _ => None,
+                }
+            }
+            ImlOp::Alt { alts } => {
+                // Alternations with more than one alternative run in their own frame
+                let depth = if alts.len() > 1 { depth + 1 } else { depth };
+
+                Some(ImlOp::Alt {
+                    alts: alts
+                        .iter()
+                        .map(|alt| alt.inline(parselet, depth))
+                        .collect::<Option<_>>()?,
+                })
+            }
+            ImlOp::Seq { seq, collect } => {
+                // Collecting sequences run in their own frame
+                let depth = if *collect { depth + 1 } else { depth };
+
+                Some(ImlOp::Seq {
+                    seq: seq
+                        .iter()
+                        .map(|item| item.inline(parselet, depth))
+                        .collect::<Option<_>>()?,
+                    collect: *collect,
+                })
+            }
+            _ => None,
+        }
+    }
+
     /** Returns a value to operate with or evaluate during compile-time.
 
     The function will only return Ok(Value) when the static_expression_evaluation-feature
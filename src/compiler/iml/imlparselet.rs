@@ -1,6 +1,6 @@
 //! Intermediate representation of a parselet
 use super::*;
-use crate::reader::Offset;
+use crate::reader::{Offset, Source};
 use crate::value::Parselet;
 use indexmap::IndexMap;
 use log;
@@ -22,13 +22,17 @@ during compilation.
 #[derive(Debug, Clone)]
 pub(in crate::compiler) struct ImlParseletModel {
     pub is_consuming: bool, // Flag if parselet is consuming
+    pub memoize: bool,      // Flag if results of a consuming parselet are memoized
     pub locals: usize,      // Total number of local variables present (including arguments)
     pub signature: IndexMap<String, Option<ImlValue>>, // Arguments signature with default values
     pub variables: IndexMap<String, usize>, // Named local variables
     pub temporaries: Vec<usize>, // Unnamed temporary variables
     pub begin: ImlOp,       // Begin intermediate operations
     pub end: ImlOp,         // End intermediate operations
+    pub begin_file: ImlOp,  // Begin intermediate operations per input file (main only)
+    pub end_file: ImlOp,    // End intermediate operations per input file (main only)
     pub body: ImlOp,        // Body intermediate Operations
+    pub source: Option<Rc<Source>>, // Source the parselet is defined in
 }
 
 impl ImlParseletModel {
@@ -44,13 +48,17 @@ impl ImlParseletModel {
 
         Self {
             is_consuming: false,
+            memoize: true,
             locals: signature.len(),
             signature,
             variables,
             temporaries: Vec::new(),
             begin: ImlOp::Nop,
             end: ImlOp::Nop,
+            begin_file: ImlOp::Nop,
+            end_file: ImlOp::Nop,
             body: ImlOp::Nop,
+            source: None,
         }
     }
 
@@ -126,8 +134,17 @@ impl ImlParselet {
         severity: u8,
         is_generated: bool,
     ) -> Self {
+        let mut model = model.unwrap_or(ImlParseletModel::new(None));
+
+        // Parselets are defined in the source of their origin
+        if model.source.is_none()
+            && let Some(origin) = &origin
+        {
+            model.source = origin.borrow().model.borrow().source.clone();
+        }
+
         Self {
-            model: Rc::new(RefCell::new(model.unwrap_or(ImlParseletModel::new(None)))),
+            model: Rc::new(RefCell::new(model)),
             generics: generics.unwrap_or(IndexMap::new()),
             origin,
             offset,
@@ -318,10 +335,11 @@ impl ImlRefParselet {
 
         log::debug!("compiling {}", parselet);
 
-        Parselet::new(
+        let mut compiled = Parselet::new(
             Some(format!("{}", parselet)),
             None,
             parselet.severity,
+            model.memoize,
             model
                 .signature
                 .iter()
@@ -341,7 +359,20 @@ impl ImlRefParselet {
             model.begin.compile_to_vec(program, (self, index)),
             model.end.compile_to_vec(program, (self, index)),
             model.body.compile_to_vec(program, (self, index)),
-        )
+        );
+
+        compiled.begin_file = model.begin_file.compile_to_vec(program, (self, index));
+        compiled.end_file = model.end_file.compile_to_vec(program, (self, index));
+        compiled.source = model.source.clone();
+
+        // Keep variable names for debugging
+        compiled.variables = vec![String::new(); model.locals];
+
+        for (name, addr) in &model.variables {
+            compiled.variables[*addr] = name.clone();
+        }
+
+        compiled
     }
 }
 
@@ -1,21 +1,26 @@
 //! ImlProgram glues ImlParselet, ImlOp and ImlValue together to produce a VM program.
 
 use super::*;
-use crate::Error;
+use crate::error::{Error, ErrorKind};
 use crate::reader::Offset;
 use crate::value::ParseletRef;
-use crate::vm::Program;
+use crate::vm::{Op, Program};
 use crate::{Object, RefValue};
 use indexmap::{IndexMap, IndexSet, indexmap, indexset};
 use log;
 use std::cell::RefCell;
 use std::collections::HashMap;
 
+// Maximum size of a parselet's body to be inlined
+const INLINE_MAX_SIZE: usize = 8;
+
 #[derive(Debug)]
 pub(in crate::compiler) struct ImlProgram {
     errors: Vec<Error>, // errors collected during compilation
     statics: IndexSet<Result<RefValue, usize>>,
     parselets: IndexMap<ImlRefParselet, usize>,
+    inlining: Vec<ImlRefParselet>, // parselets currently being inlined
+    leftrec: IndexMap<ImlRefParselet, bool>, // cache for left-recursive parselets
 }
 
 impl ImlProgram {
@@ -24,12 +29,15 @@ impl ImlProgram {
             errors: Vec::new(),
             statics: indexset![Err(0)],
             parselets: indexmap![main => 0],
+            inlining: Vec::new(),
+            leftrec: IndexMap::new(),
         }
     }
 
     /// Push an Error to the programs's error log, with given offset and msg.
     pub fn push_error(&mut self, offset: Option<Offset>, msg: String) {
-        self.errors.push(Error::new(offset, msg))
+        self.errors
+            .push(Error::new(offset, msg).with_kind(ErrorKind::Syntax))
     }
 
     /** Registers an ImlValue in the ImlProgram's statics map and returns its index.
@@ -58,21 +66,204 @@ impl ImlProgram {
                 }
             }
             ImlValue::Variable { offset, name, .. } => {
-                self.errors.push(Error::new(
-                    offset.clone(),
-                    format!("Variable '{}' used in static context", name),
-                ));
+                self.errors.push(
+                    Error::new(
+                        *offset,
+                        format!("Variable '{}' used in static context", name),
+                    )
+                    .with_kind(ErrorKind::Syntax),
+                );
                 Err(())
             }
             ImlValue::Generic { offset, .. } | ImlValue::Instance(ImlInstance { offset, .. }) => {
-                self.errors
-                    .push(Error::new(offset.clone(), format!("Unresolved {}", value)));
+                self.errors.push(
+                    Error::new(*offset, format!("Unresolved {}", value)).with_kind(ErrorKind::Name),
+                );
                 Err(())
             }
             _ => unreachable!(),
         }
     }
 
+    /** Inlines the code of a parselet registered at `index` into the code of its `caller`.
+
+    This is done for small, non left-recursive parselets without arguments, variables, begin or
+    end, to avoid the overhead of a parselet call. The inlined code runs in its own frame, which
+    results in the same capture a call would push. Returns false when the parselet can't be inlined.
+
+    Inlined code isn't memoized: When a caller backtracks and runs it again at the same offset, it
+    is executed again. Parselets called by the inlined code are still memoized as usual.
+    */
+    pub fn inline(
+        &mut self,
+        parselet: &ImlRefParselet,
+        index: usize,
+        caller: &ImlRefParselet,
+        ops: &mut Vec<Op>,
+    ) -> bool {
+        // Recursive parselets can't be inlined
+        if self.inlining.contains(parselet) {
+            return false;
+        }
+
+        let (body, severity) = {
+            let inlined = parselet.borrow();
+            let model = inlined.model.borrow();
+
+            // Only small parselets of the same source are inlined, to keep the code size low and
+            // the source offsets within the caller's source
+            if !model.is_consuming
+                || model.source != caller.borrow().model.borrow().source
+                || model.locals > 0
+                || !matches!(model.begin, ImlOp::Nop)
+                || !matches!(model.end, ImlOp::Nop)
+                || model.body.size() > INLINE_MAX_SIZE
+            {
+                return false;
+            }
+
+            let body = match model.body.inline(parselet, 0) {
+                Some(body) => body,
+                None => return false,
+            };
+
+            // Check for collections, which would use the severity of the caller
+            fn collects(op: &ImlOp) -> bool {
+                match op {
+                    ImlOp::Alt { alts: items }
+                    | ImlOp::Seq {
+                        seq: items,
+                        collect: false,
+                    } => items.iter().any(collects),
+                    ImlOp::Seq { collect: true, .. } => true,
+                    _ => false,
+                }
+            }
+
+            if inlined.severity != caller.borrow().severity && collects(&body) {
+                return false;
+            }
+
+            (body, inlined.severity)
+        };
+
+        // Left-recursive parselets rely on being called
+        if self.is_leftrec(parselet) {
+            return false;
+        }
+
+        log::debug!("inlining {} into {}", parselet, caller);
+
+        self.inlining.push(parselet.clone());
+        ops.push(Op::Frame(0));
+        body.compile(self, (parselet, index), ops);
+        ops.push(Op::Inlined(severity));
+        self.inlining.pop();
+
+        true
+    }
+
+    /** Checks whether a parselet may call itself before consuming any input.
+
+    This is a conservative check: Any call to another parselet is considered to be nullable,
+    only consuming values which aren't nullable stop the search within a sequence.
+    */
+    fn is_leftrec(&mut self, parselet: &ImlRefParselet) -> bool {
+        if let Some(leftrec) = self.leftrec.get(parselet) {
+            return *leftrec;
+        }
+
+        // Walk value, returns true when it is possibly nullable
+        fn walk_value(
+            value: &ImlValue,
+            current: &ImlRefParselet,
+            target: &ImlRefParselet,
+            visited: &mut IndexSet<ImlRefParselet>,
+        ) -> Result<bool, ()> {
+            match value {
+                ImlValue::Shared(value) => walk_value(&value.borrow(), current, target, visited),
+                ImlValue::SelfToken if current == target => Err(()),
+                ImlValue::Parselet(parselet) => {
+                    if let Ok(derived) = parselet.derive(current) {
+                        if derived == *target {
+                            return Err(());
+                        }
+
+                        if visited.insert(derived.clone()) {
+                            let body = derived.borrow().model.borrow().body.clone();
+                            walk_op(&body, &derived, target, visited)?;
+                        }
+                    }
+
+                    Ok(true)
+                }
+                ImlValue::Value(value) => Ok(!value.is_consuming() || value.is_nullable()),
+                ImlValue::Generic { name, .. } => {
+                    walk_value(&current.resolve(name), current, target, visited)
+                }
+                _ => Ok(true),
+            }
+        }
+
+        // Walk op, returns true when it is possibly nullable
+        fn walk_op(
+            op: &ImlOp,
+            current: &ImlRefParselet,
+            target: &ImlRefParselet,
+            visited: &mut IndexSet<ImlRefParselet>,
+        ) -> Result<bool, ()> {
+            match op {
+                ImlOp::Call { target: value, .. } => walk_value(value, current, target, visited),
+                ImlOp::Alt { alts } => {
+                    let mut nullable = false;
+
+                    for alt in alts {
+                        nullable |= walk_op(alt, current, target, visited)?;
+                    }
+
+                    Ok(nullable)
+                }
+                ImlOp::Seq { seq, .. } => {
+                    for item in seq {
+                        if !walk_op(item, current, target, visited)? {
+                            return Ok(false);
+                        }
+                    }
+
+                    Ok(true)
+                }
+                ImlOp::If { then, else_, .. } => {
+                    walk_op(then, current, target, visited)?;
+                    walk_op(else_, current, target, visited)?;
+                    Ok(true)
+                }
+                ImlOp::Loop {
+                    initial,
+                    condition,
+                    body,
+                    ..
+                } => {
+                    walk_op(initial, current, target, visited)?;
+                    walk_op(condition, current, target, visited)?;
+                    walk_op(body, current, target, visited)?;
+                    Ok(true)
+                }
+                ImlOp::Try { body, catch } => {
+                    walk_op(body, current, target, visited)?;
+                    walk_op(catch, current, target, visited)?;
+                    Ok(true)
+                }
+                _ => Ok(true),
+            }
+        }
+
+        let body = parselet.borrow().model.borrow().body.clone();
+        let leftrec = walk_op(&body, parselet, parselet, &mut IndexSet::new()).is_err();
+
+        self.leftrec.insert(parselet.clone(), leftrec);
+        leftrec
+    }
+
     /** Turns the ImlProgram and its intermediate values into a final VM program ready for execution.
 
     The finalization is done according to a grammar's point of view, as this is one of Tokays core features.
@@ -177,8 +368,8 @@ impl ImlProgram {
                     // Try to derive the parselet with current constants
                     let derived = parselet.derive(current).unwrap();
 
-                    // The derived parselet's original must be in the configs!
-                    let parselet = configs.get_key_value(&derived).unwrap().0.clone();
+                    // Only consuming parselets are in the configs, others are just called.
+                    let parselet = configs.get_key_value(&derived)?.0.clone();
 
                     finalize_parselet(&parselet, visited, configs)
                 }
@@ -251,7 +442,11 @@ impl ImlProgram {
                         None
                     }
                 }
-                ImlOp::If { then, else_, .. } => {
+                ImlOp::If { then, else_, .. }
+                | ImlOp::Try {
+                    body: then,
+                    catch: else_,
+                } => {
                     let then = finalize_op(then, current, visited, configs);
 
                     if let Some(else_) = finalize_op(else_, current, visited, configs) {
@@ -326,7 +521,13 @@ impl ImlProgram {
                 // If not already visited, add and recurse.
                 visited.insert(current.clone());
 
-                for part in [&model.begin, &model.body, &model.end] {
+                for part in [
+                    &model.begin,
+                    &model.begin_file,
+                    &model.body,
+                    &model.end_file,
+                    &model.end,
+                ] {
                     finalize_op(part, current, visited, configs);
                 }
 
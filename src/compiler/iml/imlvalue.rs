@@ -173,7 +173,7 @@ impl ImlValue {
 
                 if let ImlValue::Parselet(parselet) = &target {
                     let parselet = parselet.borrow();
-                    let mut generics = IndexMap::new();
+                    let mut generics: IndexMap<String, Option<ImlValue>> = IndexMap::new();
 
                     // Map args and nargs to generics of this parselet
                     for (name, default) in parselet.generics.iter() {
@@ -186,9 +186,16 @@ impl ImlValue {
                         else if let Some(narg) = instance.nargs.shift_remove(name) {
                             (narg.0, Some(narg.1.resolve(scope)))
                         }
-                        // Otherwise, use default
+                        // Otherwise, use default, which may refer to a generic before
                         else {
-                            (instance.offset.clone(), default.clone())
+                            match default {
+                                Some(ImlValue::Generic { name, .. })
+                                    if generics.contains_key(name) =>
+                                {
+                                    (instance.offset, generics[name].clone())
+                                }
+                                default => (instance.offset, default.clone()),
+                            }
                         };
 
                         // Check integrity of constant names
@@ -405,7 +412,21 @@ impl ImlValue {
             // Register new static
             let idx = match self {
                 ImlValue::Parselet(parselet) => match parselet.derive(current.0) {
-                    Ok(parselet) => program.register(&ImlValue::Parselet(parselet)).unwrap(),
+                    Ok(parselet) => {
+                        let idx = program
+                            .register(&ImlValue::Parselet(parselet.clone()))
+                            .unwrap();
+
+                        // Calls without arguments may be inlined
+                        if cfg!(feature = "inline_parselets")
+                            && matches!(call, Some(None | Some((0, false))))
+                            && program.inline(&parselet, idx, current.0, ops)
+                        {
+                            return;
+                        }
+
+                        idx
+                    }
                     Err(msg) => {
                         program.push_error(offset.clone(), msg);
                         return;
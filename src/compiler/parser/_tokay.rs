@@ -25,86 +25,84 @@ crate::value!([
                     (crate::value!([
                         (crate::value!([
                             "emit" => "identifier",
-                            "value" => "_"
+                            "value" => "Unexpected"
                         ])),
                         (crate::value!([
                             "emit" => "value_parselet",
                             "children" =>
                                 (crate::value!([
-                                    "emit" => "body",
-                                    "children" =>
-                                        (crate::value!([
+                                    (crate::value!([
+                                        "emit" => "gen",
+                                        "children" =>
                                             (crate::value!([
-                                                "emit" => "op_mod_pos",
-                                                "children" =>
-                                                    (crate::value!([
-                                                        "emit" => "value_token_ccl",
-                                                        "children" =>
-                                                            (crate::value!([
-                                                                "emit" => "ccl",
-                                                                "children" =>
-                                                                    (crate::value!([
-                                                                        (crate::value!([
-                                                                            "emit" => "char",
-                                                                            "value" => "\t"
-                                                                        ])),
-                                                                        (crate::value!([
-                                                                            "emit" => "char",
-                                                                            "value" => " "
-                                                                        ]))
-                                                                    ]))
-                                                            ]))
-                                                    ]))
-                                            ])),
+                                                "emit" => "identifier",
+                                                "value" => "P"
+                                            ]))
+                                    ])),
+                                    (crate::value!([
+                                        "emit" => "body",
+                                        "children" =>
                                             (crate::value!([
                                                 "emit" => "sequence",
                                                 "children" =>
                                                     (crate::value!([
                                                         (crate::value!([
-                                                            "emit" => "value_token_touch",
-                                                            "value" => "#"
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "op_mod_kle",
+                                                            "emit" => "value_instance",
                                                             "children" =>
                                                                 (crate::value!([
-                                                                    "emit" => "value_token_ccl",
-                                                                    "children" =>
-                                                                        (crate::value!([
-                                                                            "emit" => "ccl_neg",
-                                                                            "children" =>
-                                                                                (crate::value!([
-                                                                                    "emit" => "char",
-                                                                                    "value" => "\n"
-                                                                                ]))
-                                                                        ]))
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Not"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "identifier",
+                                                                                "value" => "P"
+                                                                            ]))
+                                                                    ]))
                                                                 ]))
-                                                        ]))
-                                                    ]))
-                                            ])),
-                                            (crate::value!([
-                                                "emit" => "sequence",
-                                                "children" =>
-                                                    (crate::value!([
-                                                        (crate::value!([
-                                                            "emit" => "value_token_touch",
-                                                            "value" => "\\"
                                                         ])),
                                                         (crate::value!([
-                                                            "emit" => "op_mod_opt",
+                                                            "emit" => "value_instance",
                                                             "children" =>
                                                                 (crate::value!([
-                                                                    "emit" => "value_token_touch",
-                                                                    "value" => "\r"
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Not"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "identifier",
+                                                                                "value" => "EOF"
+                                                                            ]))
+                                                                    ]))
                                                                 ]))
                                                         ])),
                                                         (crate::value!([
-                                                            "emit" => "value_token_touch",
-                                                            "value" => "\n"
+                                                            "emit" => "value_instance",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Expect"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "identifier",
+                                                                                "value" => "P"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
                                                         ]))
                                                     ]))
                                             ]))
-                                        ]))
+                                    ]))
                                 ]))
                         ]))
                     ]))
@@ -115,138 +113,210 @@ crate::value!([
                     (crate::value!([
                         (crate::value!([
                             "emit" => "identifier",
-                            "value" => "___"
+                            "value" => "Recover"
                         ])),
                         (crate::value!([
-                            "emit" => "op_mod_kle",
+                            "emit" => "value_parselet",
                             "children" =>
                                 (crate::value!([
-                                    "emit" => "sequence",
-                                    "children" =>
-                                        (crate::value!([
-                                            (crate::value!([
-                                                "emit" => "identifier",
-                                                "value" => "T_EOL"
-                                            ])),
+                                    (crate::value!([
+                                        "emit" => "sig",
+                                        "children" =>
                                             (crate::value!([
                                                 "emit" => "identifier",
-                                                "value" => "_"
+                                                "value" => "e"
                                             ]))
-                                        ]))
-                                ]))
-                        ]))
-                    ]))
-            ])),
-            (crate::value!([
-                "emit" => "constant",
-                "children" =>
-                    (crate::value!([
-                        (crate::value!([
-                            "emit" => "identifier",
-                            "value" => "T_EOL"
-                        ])),
-                        (crate::value!([
-                            "emit" => "value_parselet",
-                            "children" =>
-                                (crate::value!([
-                                    "emit" => "body",
-                                    "children" =>
-                                        (crate::value!([
-                                            (crate::value!([
-                                                "emit" => "sequence",
-                                                "children" =>
-                                                    (crate::value!([
-                                                        (crate::value!([
-                                                            "emit" => "value_token_touch",
-                                                            "value" => "\n"
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "_"
-                                                        ]))
-                                                    ]))
-                                            ])),
-                                            (crate::value!([
-                                                "emit" => "sequence",
-                                                "children" =>
-                                                    (crate::value!([
-                                                        (crate::value!([
-                                                            "emit" => "value_token_touch",
-                                                            "value" => "\r"
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "op_mod_opt",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    "emit" => "value_token_touch",
-                                                                    "value" => "\n"
-                                                                ]))
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "_"
-                                                        ]))
-                                                    ]))
-                                            ])),
+                                    ])),
+                                    (crate::value!([
+                                        "emit" => "body",
+                                        "children" =>
                                             (crate::value!([
-                                                "emit" => "sequence",
-                                                "children" =>
-                                                    (crate::value!([
-                                                        (crate::value!([
-                                                            "emit" => "value_token_touch",
-                                                            "value" => ";"
-                                                        ])),
+                                                (crate::value!([
+                                                    "emit" => "op_if",
+                                                    "children" =>
                                                         (crate::value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "_"
-                                                        ]))
-                                                    ]))
-                                            ])),
-                                            (crate::value!([
-                                                "emit" => "op_accept",
-                                                "children" =>
-                                                    (crate::value!([
-                                                        "emit" => "value_instance",
-                                                        "children" =>
                                                             (crate::value!([
-                                                                (crate::value!([
-                                                                    "emit" => "identifier",
-                                                                    "value" => "Peek"
-                                                                ])),
-                                                                (crate::value!([
-                                                                    "emit" => "instarg",
-                                                                    "children" =>
+                                                                "emit" => "comparison",
+                                                                "children" =>
+                                                                    (crate::value!([
                                                                         (crate::value!([
-                                                                            "emit" => "value_token_touch",
-                                                                            "value" => "}"
+                                                                            "emit" => "rvalue",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    (crate::value!([
+                                                                                        "emit" => "call",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                "emit" => "identifier",
+                                                                                                "value" => "offset"
+                                                                                            ]))
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "item",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                "emit" => "value_string",
+                                                                                                "value" => "offset"
+                                                                                            ]))
+                                                                                    ]))
+                                                                                ]))
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "cmp_eq",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "rvalue",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            (crate::value!([
+                                                                                                "emit" => "identifier",
+                                                                                                "value" => "e"
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "attribute",
+                                                                                                "children" =>
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "value_string",
+                                                                                                        "value" => "offset"
+                                                                                                    ]))
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ]))
                                                                         ]))
-                                                                ]))
-                                                            ]))
-                                                    ]))
-                                            ])),
-                                            (crate::value!([
-                                                "emit" => "op_accept",
-                                                "children" =>
-                                                    (crate::value!([
-                                                        "emit" => "value_instance",
-                                                        "children" =>
+                                                                    ]))
+                                                            ])),
                                                             (crate::value!([
+                                                                "emit" => "value_token_any"
+                                                            ]))
+                                                        ]))
+                                                ])),
+                                                (crate::value!([
+                                                    "emit" => "op_loop",
+                                                    "children" =>
+                                                        (crate::value!([
+                                                            "emit" => "block",
+                                                            "children" =>
                                                                 (crate::value!([
-                                                                    "emit" => "identifier",
-                                                                    "value" => "Peek"
-                                                                ])),
-                                                                (crate::value!([
-                                                                    "emit" => "instarg",
-                                                                    "children" =>
-                                                                        (crate::value!([
-                                                                            "emit" => "identifier",
-                                                                            "value" => "EOF"
-                                                                        ]))
+                                                                    (crate::value!([
+                                                                        "emit" => "op_if",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                (crate::value!([
+                                                                                    "emit" => "comparison",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            (crate::value!([
+                                                                                                "emit" => "rvalue",
+                                                                                                "children" =>
+                                                                                                    (crate::value!([
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "call",
+                                                                                                            "children" =>
+                                                                                                                (crate::value!([
+                                                                                                                    "emit" => "identifier",
+                                                                                                                    "value" => "offset"
+                                                                                                                ]))
+                                                                                                        ])),
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "item",
+                                                                                                            "children" =>
+                                                                                                                (crate::value!([
+                                                                                                                    "emit" => "value_string",
+                                                                                                                    "value" => "offset"
+                                                                                                                ]))
+                                                                                                        ]))
+                                                                                                    ]))
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "cmp_gteq",
+                                                                                                "children" =>
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "rvalue",
+                                                                                                        "children" =>
+                                                                                                            (crate::value!([
+                                                                                                                (crate::value!([
+                                                                                                                    "emit" => "identifier",
+                                                                                                                    "value" => "e"
+                                                                                                                ])),
+                                                                                                                (crate::value!([
+                                                                                                                    "emit" => "attribute",
+                                                                                                                    "children" =>
+                                                                                                                        (crate::value!([
+                                                                                                                            "emit" => "value_string",
+                                                                                                                            "value" => "offset"
+                                                                                                                        ]))
+                                                                                                                ]))
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ])),
+                                                                                (crate::value!([
+                                                                                    "emit" => "op_break"
+                                                                                ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "value_token_any"
+                                                                    ]))
                                                                 ]))
-                                                            ]))
-                                                    ]))
+                                                        ]))
+                                                ])),
+                                                (crate::value!([
+                                                    "emit" => "op_loop",
+                                                    "children" =>
+                                                        (crate::value!([
+                                                            "emit" => "block",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "sequence",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                (crate::value!([
+                                                                                    "emit" => "value_instance",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            (crate::value!([
+                                                                                                "emit" => "identifier",
+                                                                                                "value" => "Peek"
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "instarg",
+                                                                                                "children" =>
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "T_EOL"
+                                                                                                    ]))
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ])),
+                                                                                (crate::value!([
+                                                                                    "emit" => "op_break"
+                                                                                ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "sequence",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                (crate::value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "EOF"
+                                                                                ])),
+                                                                                (crate::value!([
+                                                                                    "emit" => "op_break"
+                                                                                ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "value_token_any"
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                ]))
                                             ]))
-                                        ]))
+                                    ]))
                                 ]))
                         ]))
                     ]))
@@ -257,17 +327,85 @@ crate::value!([
                     (crate::value!([
                         (crate::value!([
                             "emit" => "identifier",
-                            "value" => "T_OctDigit"
+                            "value" => "_"
                         ])),
                         (crate::value!([
-                            "emit" => "value_token_ccl",
+                            "emit" => "value_parselet",
                             "children" =>
                                 (crate::value!([
-                                    "emit" => "ccl",
+                                    "emit" => "body",
                                     "children" =>
                                         (crate::value!([
-                                            "emit" => "range",
-                                            "value" => "07"
+                                            (crate::value!([
+                                                "emit" => "op_mod_pos",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        "emit" => "value_token_ccl",
+                                                        "children" =>
+                                                            (crate::value!([
+                                                                "emit" => "ccl",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "char",
+                                                                            "value" => "\t"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "char",
+                                                                            "value" => " "
+                                                                        ]))
+                                                                    ]))
+                                                            ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "value_token_touch",
+                                                            "value" => "#"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "op_mod_kle",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    "emit" => "value_token_ccl",
+                                                                    "children" =>
+                                                                        (crate::value!([
+                                                                            "emit" => "ccl_neg",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "char",
+                                                                                    "value" => "\n"
+                                                                                ]))
+                                                                        ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "value_token_touch",
+                                                            "value" => "\\"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "op_mod_opt",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    "emit" => "value_token_touch",
+                                                                    "value" => "\r"
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_token_touch",
+                                                            "value" => "\n"
+                                                        ]))
+                                                    ]))
+                                            ]))
                                         ]))
                                 ]))
                         ]))
@@ -279,26 +417,22 @@ crate::value!([
                     (crate::value!([
                         (crate::value!([
                             "emit" => "identifier",
-                            "value" => "T_HexDigit"
+                            "value" => "___"
                         ])),
                         (crate::value!([
-                            "emit" => "value_token_ccl",
+                            "emit" => "op_mod_kle",
                             "children" =>
                                 (crate::value!([
-                                    "emit" => "ccl",
+                                    "emit" => "sequence",
                                     "children" =>
                                         (crate::value!([
                                             (crate::value!([
-                                                "emit" => "range",
-                                                "value" => "09"
-                                            ])),
-                                            (crate::value!([
-                                                "emit" => "range",
-                                                "value" => "AF"
+                                                "emit" => "identifier",
+                                                "value" => "T_EOL"
                                             ])),
                                             (crate::value!([
-                                                "emit" => "range",
-                                                "value" => "af"
+                                                "emit" => "identifier",
+                                                "value" => "_"
                                             ]))
                                         ]))
                                 ]))
@@ -311,7 +445,7 @@ crate::value!([
                     (crate::value!([
                         (crate::value!([
                             "emit" => "identifier",
-                            "value" => "T_EscapeSequence"
+                            "value" => "T_EOL"
                         ])),
                         (crate::value!([
                             "emit" => "value_parselet",
@@ -326,11 +460,11 @@ crate::value!([
                                                     (crate::value!([
                                                         (crate::value!([
                                                             "emit" => "value_token_touch",
-                                                            "value" => "a"
+                                                            "value" => "\n"
                                                         ])),
                                                         (crate::value!([
-                                                            "emit" => "value_string",
-                                                            "value" => ""
+                                                            "emit" => "identifier",
+                                                            "value" => "_"
                                                         ]))
                                                     ]))
                                             ])),
@@ -340,25 +474,19 @@ crate::value!([
                                                     (crate::value!([
                                                         (crate::value!([
                                                             "emit" => "value_token_touch",
-                                                            "value" => "b"
+                                                            "value" => "\r"
                                                         ])),
                                                         (crate::value!([
-                                                            "emit" => "value_string",
-                                                            "value" => ""
-                                                        ]))
-                                                    ]))
-                                            ])),
-                                            (crate::value!([
-                                                "emit" => "sequence",
-                                                "children" =>
-                                                    (crate::value!([
-                                                        (crate::value!([
-                                                            "emit" => "value_token_touch",
-                                                            "value" => "f"
+                                                            "emit" => "op_mod_opt",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    "emit" => "value_token_touch",
+                                                                    "value" => "\n"
+                                                                ]))
                                                         ])),
                                                         (crate::value!([
-                                                            "emit" => "value_string",
-                                                            "value" => ""
+                                                            "emit" => "identifier",
+                                                            "value" => "_"
                                                         ]))
                                                     ]))
                                             ])),
@@ -368,23 +496,197 @@ crate::value!([
                                                     (crate::value!([
                                                         (crate::value!([
                                                             "emit" => "value_token_touch",
-                                                            "value" => "n"
+                                                            "value" => ";"
                                                         ])),
                                                         (crate::value!([
-                                                            "emit" => "value_string",
-                                                            "value" => "\n"
+                                                            "emit" => "identifier",
+                                                            "value" => "_"
                                                         ]))
                                                     ]))
                                             ])),
                                             (crate::value!([
-                                                "emit" => "sequence",
+                                                "emit" => "op_accept",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        "emit" => "value_instance",
+                                                        "children" =>
+                                                            (crate::value!([
+                                                                (crate::value!([
+                                                                    "emit" => "identifier",
+                                                                    "value" => "Peek"
+                                                                ])),
+                                                                (crate::value!([
+                                                                    "emit" => "instarg",
+                                                                    "children" =>
+                                                                        (crate::value!([
+                                                                            "emit" => "value_token_touch",
+                                                                            "value" => "}"
+                                                                        ]))
+                                                                ]))
+                                                            ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "op_accept",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        "emit" => "value_instance",
+                                                        "children" =>
+                                                            (crate::value!([
+                                                                (crate::value!([
+                                                                    "emit" => "identifier",
+                                                                    "value" => "Peek"
+                                                                ])),
+                                                                (crate::value!([
+                                                                    "emit" => "instarg",
+                                                                    "children" =>
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "EOF"
+                                                                        ]))
+                                                                ]))
+                                                            ]))
+                                                    ]))
+                                            ]))
+                                        ]))
+                                ]))
+                        ]))
+                    ]))
+            ])),
+            (crate::value!([
+                "emit" => "constant",
+                "children" =>
+                    (crate::value!([
+                        (crate::value!([
+                            "emit" => "identifier",
+                            "value" => "T_OctDigit"
+                        ])),
+                        (crate::value!([
+                            "emit" => "value_token_ccl",
+                            "children" =>
+                                (crate::value!([
+                                    "emit" => "ccl",
+                                    "children" =>
+                                        (crate::value!([
+                                            "emit" => "range",
+                                            "value" => "07"
+                                        ]))
+                                ]))
+                        ]))
+                    ]))
+            ])),
+            (crate::value!([
+                "emit" => "constant",
+                "children" =>
+                    (crate::value!([
+                        (crate::value!([
+                            "emit" => "identifier",
+                            "value" => "T_HexDigit"
+                        ])),
+                        (crate::value!([
+                            "emit" => "value_token_ccl",
+                            "children" =>
+                                (crate::value!([
+                                    "emit" => "ccl",
+                                    "children" =>
+                                        (crate::value!([
+                                            (crate::value!([
+                                                "emit" => "range",
+                                                "value" => "09"
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "range",
+                                                "value" => "AF"
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "range",
+                                                "value" => "af"
+                                            ]))
+                                        ]))
+                                ]))
+                        ]))
+                    ]))
+            ])),
+            (crate::value!([
+                "emit" => "constant",
+                "children" =>
+                    (crate::value!([
+                        (crate::value!([
+                            "emit" => "identifier",
+                            "value" => "T_EscapeSequence"
+                        ])),
+                        (crate::value!([
+                            "emit" => "value_parselet",
+                            "children" =>
+                                (crate::value!([
+                                    "emit" => "body",
+                                    "children" =>
+                                        (crate::value!([
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "value_token_touch",
+                                                            "value" => "a"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_string",
+                                                            "value" => ""
+                                                        ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "value_token_touch",
+                                                            "value" => "b"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_string",
+                                                            "value" => ""
+                                                        ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "value_token_touch",
+                                                            "value" => "f"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_string",
+                                                            "value" => ""
+                                                        ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "sequence",
                                                 "children" =>
                                                     (crate::value!([
                                                         (crate::value!([
-                                                            "emit" => "value_token_touch",
-                                                            "value" => "r"
-                                                        ])),
-                                                        (crate::value!([
+                                                            "emit" => "value_token_touch",
+                                                            "value" => "n"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_string",
+                                                            "value" => "\n"
+                                                        ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "value_token_touch",
+                                                            "value" => "r"
+                                                        ])),
+                                                        (crate::value!([
                                                             "emit" => "value_string",
                                                             "value" => "\r"
                                                         ]))
@@ -1414,51 +1716,7 @@ crate::value!([
                     (crate::value!([
                         (crate::value!([
                             "emit" => "identifier",
-                            "value" => "T_Integer"
-                        ])),
-                        (crate::value!([
-                            "emit" => "value_parselet",
-                            "children" =>
-                                (crate::value!([
-                                    "emit" => "body",
-                                    "children" =>
-                                        (crate::value!([
-                                            "emit" => "call",
-                                            "children" =>
-                                                (crate::value!([
-                                                    (crate::value!([
-                                                        "emit" => "identifier",
-                                                        "value" => "ast"
-                                                    ])),
-                                                    (crate::value!([
-                                                        "emit" => "callarg",
-                                                        "children" =>
-                                                            (crate::value!([
-                                                                "emit" => "value_string",
-                                                                "value" => "value_integer"
-                                                            ]))
-                                                    ])),
-                                                    (crate::value!([
-                                                        "emit" => "callarg",
-                                                        "children" =>
-                                                            (crate::value!([
-                                                                "emit" => "identifier",
-                                                                "value" => "Int"
-                                                            ]))
-                                                    ]))
-                                                ]))
-                                        ]))
-                                ]))
-                        ]))
-                    ]))
-            ])),
-            (crate::value!([
-                "emit" => "constant",
-                "children" =>
-                    (crate::value!([
-                        (crate::value!([
-                            "emit" => "identifier",
-                            "value" => "T_Float"
+                            "value" => "T_RegexBody"
                         ])),
                         (crate::value!([
                             "emit" => "value_parselet",
@@ -1467,42 +1725,146 @@ crate::value!([
                                     "emit" => "body",
                                     "children" =>
                                         (crate::value!([
-                                            "emit" => "call",
+                                            "emit" => "sequence",
                                             "children" =>
                                                 (crate::value!([
                                                     (crate::value!([
-                                                        "emit" => "identifier",
-                                                        "value" => "ast"
-                                                    ])),
-                                                    (crate::value!([
-                                                        "emit" => "callarg",
-                                                        "children" =>
-                                                            (crate::value!([
-                                                                "emit" => "value_string",
-                                                                "value" => "value_float"
-                                                            ]))
-                                                    ])),
-                                                    (crate::value!([
-                                                        "emit" => "callarg",
+                                                        "emit" => "op_mod_pos",
                                                         "children" =>
                                                             (crate::value!([
-                                                                "emit" => "identifier",
-                                                                "value" => "Float"
-                                                            ]))
-                                                    ]))
-                                                ]))
-                                        ]))
-                                ]))
-                        ]))
-                    ]))
-            ])),
-            (crate::value!([
-                "emit" => "constant",
-                "children" =>
-                    (crate::value!([
-                        (crate::value!([
-                            "emit" => "identifier",
-                            "value" => "CclChar"
+                                                                "emit" => "block",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "sequence",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    (crate::value!([
+                                                                                        "emit" => "value_token_touch",
+                                                                                        "value" => "\\"
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "value_token_any"
+                                                                                    ]))
+                                                                                ]))
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "sequence",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    (crate::value!([
+                                                                                        "emit" => "value_token_touch",
+                                                                                        "value" => "["
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "op_mod_kle",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                "emit" => "block",
+                                                                                                "children" =>
+                                                                                                    (crate::value!([
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "sequence",
+                                                                                                            "children" =>
+                                                                                                                (crate::value!([
+                                                                                                                    (crate::value!([
+                                                                                                                        "emit" => "value_token_touch",
+                                                                                                                        "value" => "\\"
+                                                                                                                    ])),
+                                                                                                                    (crate::value!([
+                                                                                                                        "emit" => "value_token_any"
+                                                                                                                    ]))
+                                                                                                                ]))
+                                                                                                        ])),
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "value_token_ccl",
+                                                                                                            "children" =>
+                                                                                                                (crate::value!([
+                                                                                                                    "emit" => "ccl_neg",
+                                                                                                                    "children" =>
+                                                                                                                        (crate::value!([
+                                                                                                                            (crate::value!([
+                                                                                                                                "emit" => "char",
+                                                                                                                                "value" => "\\"
+                                                                                                                            ])),
+                                                                                                                            (crate::value!([
+                                                                                                                                "emit" => "char",
+                                                                                                                                "value" => "]"
+                                                                                                                            ])),
+                                                                                                                            (crate::value!([
+                                                                                                                                "emit" => "char",
+                                                                                                                                "value" => "\n"
+                                                                                                                            ]))
+                                                                                                                        ]))
+                                                                                                                ]))
+                                                                                                        ]))
+                                                                                                    ]))
+                                                                                            ]))
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "value_token_touch",
+                                                                                        "value" => "]"
+                                                                                    ]))
+                                                                                ]))
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "value_token_ccl",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "ccl_neg",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            (crate::value!([
+                                                                                                "emit" => "char",
+                                                                                                "value" => "\t"
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "char",
+                                                                                                "value" => "\n"
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "char",
+                                                                                                "value" => " "
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "char",
+                                                                                                "value" => "/"
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "char",
+                                                                                                "value" => "["
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "char",
+                                                                                                "value" => "\\"
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ]))
+                                                                        ]))
+                                                                    ]))
+                                                            ]))
+                                                    ])),
+                                                    (crate::value!([
+                                                        "emit" => "capture_index",
+                                                        "children" =>
+                                                            (crate::value!([
+                                                                "emit" => "value_integer",
+                                                                "value" => 0
+                                                            ]))
+                                                    ]))
+                                                ]))
+                                        ]))
+                                ]))
+                        ]))
+                    ]))
+            ])),
+            (crate::value!([
+                "emit" => "constant",
+                "children" =>
+                    (crate::value!([
+                        (crate::value!([
+                            "emit" => "identifier",
+                            "value" => "T_Regex"
                         ])),
                         (crate::value!([
                             "emit" => "value_parselet",
@@ -1511,39 +1873,143 @@ crate::value!([
                                     "emit" => "body",
                                     "children" =>
                                         (crate::value!([
-                                            (crate::value!([
-                                                "emit" => "sequence",
-                                                "children" =>
+                                            "emit" => "sequence",
+                                            "children" =>
+                                                (crate::value!([
                                                     (crate::value!([
-                                                        (crate::value!([
-                                                            "emit" => "value_token_touch",
-                                                            "value" => "\\"
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "T_EscapeSequence"
-                                                        ]))
-                                                    ]))
-                                            ])),
-                                            (crate::value!([
-                                                "emit" => "value_token_ccl",
-                                                "children" =>
+                                                        "emit" => "value_token_touch",
+                                                        "value" => "/"
+                                                    ])),
                                                     (crate::value!([
-                                                        "emit" => "ccl_neg",
+                                                        "emit" => "value_instance",
                                                         "children" =>
                                                             (crate::value!([
-                                                                "emit" => "char",
-                                                                "value" => ">"
+                                                                (crate::value!([
+                                                                    "emit" => "identifier",
+                                                                    "value" => "Not"
+                                                                ])),
+                                                                (crate::value!([
+                                                                    "emit" => "instarg",
+                                                                    "children" =>
+                                                                        (crate::value!([
+                                                                            "emit" => "value_token_ccl",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "ccl",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            "emit" => "char",
+                                                                                            "value" => "="
+                                                                                        ]))
+                                                                                ]))
+                                                                        ]))
+                                                                ]))
+                                                            ]))
+                                                    ])),
+                                                    (crate::value!([
+                                                        "emit" => "identifier",
+                                                        "value" => "T_RegexBody"
+                                                    ])),
+                                                    (crate::value!([
+                                                        "emit" => "value_token_touch",
+                                                        "value" => "/"
+                                                    ])),
+                                                    (crate::value!([
+                                                        "emit" => "value_instance",
+                                                        "children" =>
+                                                            (crate::value!([
+                                                                (crate::value!([
+                                                                    "emit" => "identifier",
+                                                                    "value" => "Not"
+                                                                ])),
+                                                                (crate::value!([
+                                                                    "emit" => "instarg",
+                                                                    "children" =>
+                                                                        (crate::value!([
+                                                                            "emit" => "value_token_ccl",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "ccl",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            (crate::value!([
+                                                                                                "emit" => "range",
+                                                                                                "value" => "09"
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "range",
+                                                                                                "value" => "AZ"
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "char",
+                                                                                                "value" => "_"
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "range",
+                                                                                                "value" => "az"
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "char",
+                                                                                                "value" => "("
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "char",
+                                                                                                "value" => "["
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "char",
+                                                                                                "value" => "\""
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "char",
+                                                                                                "value" => "'"
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "char",
+                                                                                                "value" => "$"
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ]))
+                                                                        ]))
+                                                                ]))
+                                                            ]))
+                                                    ])),
+                                                    (crate::value!([
+                                                        "emit" => "capture_index",
+                                                        "children" =>
+                                                            (crate::value!([
+                                                                "emit" => "value_integer",
+                                                                "value" => 3
                                                             ]))
                                                     ]))
-                                            ])),
+                                                ]))
+                                        ]))
+                                ]))
+                        ]))
+                    ]))
+            ])),
+            (crate::value!([
+                "emit" => "constant",
+                "children" =>
+                    (crate::value!([
+                        (crate::value!([
+                            "emit" => "identifier",
+                            "value" => "T_Module"
+                        ])),
+                        (crate::value!([
+                            "emit" => "value_parselet",
+                            "children" =>
+                                (crate::value!([
+                                    "emit" => "body",
+                                    "children" =>
+                                        (crate::value!([
                                             (crate::value!([
                                                 "emit" => "sequence",
                                                 "children" =>
                                                     (crate::value!([
                                                         (crate::value!([
                                                             "emit" => "identifier",
-                                                            "value" => "EOF"
+                                                            "value" => "T_String"
                                                         ])),
                                                         (crate::value!([
                                                             "emit" => "call",
@@ -1551,19 +2017,23 @@ crate::value!([
                                                                 (crate::value!([
                                                                     (crate::value!([
                                                                         "emit" => "identifier",
-                                                                        "value" => "error"
+                                                                        "value" => "ast"
                                                                     ])),
                                                                     (crate::value!([
                                                                         "emit" => "callarg",
                                                                         "children" =>
                                                                             (crate::value!([
                                                                                 "emit" => "value_string",
-                                                                                "value" => "Unclosed character-class, expecting ']'"
+                                                                                "value" => "value_string"
                                                                             ]))
                                                                     ]))
                                                                 ]))
                                                         ]))
                                                     ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "identifier",
+                                                "value" => "T_Alias"
                                             ]))
                                         ]))
                                 ]))
@@ -1576,7 +2046,7 @@ crate::value!([
                     (crate::value!([
                         (crate::value!([
                             "emit" => "identifier",
-                            "value" => "CclRange"
+                            "value" => "T_Integer"
                         ])),
                         (crate::value!([
                             "emit" => "value_parselet",
@@ -1585,24 +2055,186 @@ crate::value!([
                                     "emit" => "body",
                                     "children" =>
                                         (crate::value!([
-                                            (crate::value!([
-                                                "emit" => "sequence",
-                                                "children" =>
+                                            "emit" => "call",
+                                            "children" =>
+                                                (crate::value!([
                                                     (crate::value!([
-                                                        (crate::value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "CclChar"
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "value_token_touch",
-                                                            "value" => "-"
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "CclChar"
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "call",
+                                                        "emit" => "identifier",
+                                                        "value" => "ast"
+                                                    ])),
+                                                    (crate::value!([
+                                                        "emit" => "callarg",
+                                                        "children" =>
+                                                            (crate::value!([
+                                                                "emit" => "value_string",
+                                                                "value" => "value_integer"
+                                                            ]))
+                                                    ])),
+                                                    (crate::value!([
+                                                        "emit" => "callarg",
+                                                        "children" =>
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "Int"
+                                                            ]))
+                                                    ]))
+                                                ]))
+                                        ]))
+                                ]))
+                        ]))
+                    ]))
+            ])),
+            (crate::value!([
+                "emit" => "constant",
+                "children" =>
+                    (crate::value!([
+                        (crate::value!([
+                            "emit" => "identifier",
+                            "value" => "T_Float"
+                        ])),
+                        (crate::value!([
+                            "emit" => "value_parselet",
+                            "children" =>
+                                (crate::value!([
+                                    "emit" => "body",
+                                    "children" =>
+                                        (crate::value!([
+                                            "emit" => "call",
+                                            "children" =>
+                                                (crate::value!([
+                                                    (crate::value!([
+                                                        "emit" => "identifier",
+                                                        "value" => "ast"
+                                                    ])),
+                                                    (crate::value!([
+                                                        "emit" => "callarg",
+                                                        "children" =>
+                                                            (crate::value!([
+                                                                "emit" => "value_string",
+                                                                "value" => "value_float"
+                                                            ]))
+                                                    ])),
+                                                    (crate::value!([
+                                                        "emit" => "callarg",
+                                                        "children" =>
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "Float"
+                                                            ]))
+                                                    ]))
+                                                ]))
+                                        ]))
+                                ]))
+                        ]))
+                    ]))
+            ])),
+            (crate::value!([
+                "emit" => "constant",
+                "children" =>
+                    (crate::value!([
+                        (crate::value!([
+                            "emit" => "identifier",
+                            "value" => "CclChar"
+                        ])),
+                        (crate::value!([
+                            "emit" => "value_parselet",
+                            "children" =>
+                                (crate::value!([
+                                    "emit" => "body",
+                                    "children" =>
+                                        (crate::value!([
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "value_token_touch",
+                                                            "value" => "\\"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "T_EscapeSequence"
+                                                        ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "value_token_ccl",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        "emit" => "ccl_neg",
+                                                        "children" =>
+                                                            (crate::value!([
+                                                                "emit" => "char",
+                                                                "value" => ">"
+                                                            ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "EOF"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "call",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "error"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "callarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_string",
+                                                                                "value" => "Unclosed character-class, expecting ']'"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ]))
+                                        ]))
+                                ]))
+                        ]))
+                    ]))
+            ])),
+            (crate::value!([
+                "emit" => "constant",
+                "children" =>
+                    (crate::value!([
+                        (crate::value!([
+                            "emit" => "identifier",
+                            "value" => "CclRange"
+                        ])),
+                        (crate::value!([
+                            "emit" => "value_parselet",
+                            "children" =>
+                                (crate::value!([
+                                    "emit" => "body",
+                                    "children" =>
+                                        (crate::value!([
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "CclChar"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_token_touch",
+                                                            "value" => "-"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "CclChar"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "call",
                                                             "children" =>
                                                                 (crate::value!([
                                                                     (crate::value!([
@@ -1774,7 +2406,7 @@ crate::value!([
                     (crate::value!([
                         (crate::value!([
                             "emit" => "identifier",
-                            "value" => "Subscript"
+                            "value" => "Slice"
                         ])),
                         (crate::value!([
                             "emit" => "value_parselet",
@@ -1787,54 +2419,264 @@ crate::value!([
                                             "children" =>
                                                 (crate::value!([
                                                     (crate::value!([
-                                                        "emit" => "identifier",
-                                                        "value" => "_"
+                                                        "emit" => "op_mod_opt",
+                                                        "children" =>
+                                                            (crate::value!([
+                                                                "emit" => "sequence",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "Expression"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "call",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    (crate::value!([
+                                                                                        "emit" => "identifier",
+                                                                                        "value" => "ast"
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "callarg",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                "emit" => "value_string",
+                                                                                                "value" => "slice_start"
+                                                                                            ]))
+                                                                                    ]))
+                                                                                ]))
+                                                                        ]))
+                                                                    ]))
+                                                            ]))
                                                     ])),
                                                     (crate::value!([
                                                         "emit" => "value_token_touch",
-                                                        "value" => "["
+                                                        "value" => ":"
                                                     ])),
                                                     (crate::value!([
                                                         "emit" => "identifier",
                                                         "value" => "_"
                                                     ])),
                                                     (crate::value!([
-                                                        "emit" => "identifier",
-                                                        "value" => "Expression"
-                                                    ])),
-                                                    (crate::value!([
-                                                        "emit" => "value_token_touch",
-                                                        "value" => "]"
-                                                    ])),
-                                                    (crate::value!([
-                                                        "emit" => "call",
+                                                        "emit" => "op_mod_opt",
                                                         "children" =>
                                                             (crate::value!([
-                                                                (crate::value!([
-                                                                    "emit" => "identifier",
-                                                                    "value" => "ast"
-                                                                ])),
-                                                                (crate::value!([
-                                                                    "emit" => "callarg",
-                                                                    "children" =>
+                                                                "emit" => "sequence",
+                                                                "children" =>
+                                                                    (crate::value!([
                                                                         (crate::value!([
-                                                                            "emit" => "value_string",
-                                                                            "value" => "item"
+                                                                            "emit" => "identifier",
+                                                                            "value" => "Expression"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "call",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    (crate::value!([
+                                                                                        "emit" => "identifier",
+                                                                                        "value" => "ast"
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "callarg",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                "emit" => "value_string",
+                                                                                                "value" => "slice_stop"
+                                                                                            ]))
+                                                                                    ]))
+                                                                                ]))
                                                                         ]))
-                                                                ]))
+                                                                    ]))
                                                             ]))
-                                                    ]))
-                                                ]))
-                                        ]))
-                                ]))
-                        ]))
-                    ]))
-            ])),
-            (crate::value!([
-                "emit" => "constant",
-                "children" =>
-                    (crate::value!([
-                        (crate::value!([
+                                                    ])),
+                                                    (crate::value!([
+                                                        "emit" => "op_mod_opt",
+                                                        "children" =>
+                                                            (crate::value!([
+                                                                "emit" => "sequence",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "value_token_touch",
+                                                                            "value" => ":"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "_"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "op_mod_opt",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "sequence",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            (crate::value!([
+                                                                                                "emit" => "identifier",
+                                                                                                "value" => "Expression"
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "call",
+                                                                                                "children" =>
+                                                                                                    (crate::value!([
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "identifier",
+                                                                                                            "value" => "ast"
+                                                                                                        ])),
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "callarg",
+                                                                                                            "children" =>
+                                                                                                                (crate::value!([
+                                                                                                                    "emit" => "value_string",
+                                                                                                                    "value" => "slice_step"
+                                                                                                                ]))
+                                                                                                        ]))
+                                                                                                    ]))
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ]))
+                                                                        ]))
+                                                                    ]))
+                                                            ]))
+                                                    ])),
+                                                    (crate::value!([
+                                                        "emit" => "call",
+                                                        "children" =>
+                                                            (crate::value!([
+                                                                (crate::value!([
+                                                                    "emit" => "identifier",
+                                                                    "value" => "ast"
+                                                                ])),
+                                                                (crate::value!([
+                                                                    "emit" => "callarg",
+                                                                    "children" =>
+                                                                        (crate::value!([
+                                                                            "emit" => "value_string",
+                                                                            "value" => "slice"
+                                                                        ]))
+                                                                ]))
+                                                            ]))
+                                                    ]))
+                                                ]))
+                                        ]))
+                                ]))
+                        ]))
+                    ]))
+            ])),
+            (crate::value!([
+                "emit" => "constant",
+                "children" =>
+                    (crate::value!([
+                        (crate::value!([
+                            "emit" => "identifier",
+                            "value" => "Subscript"
+                        ])),
+                        (crate::value!([
+                            "emit" => "value_parselet",
+                            "children" =>
+                                (crate::value!([
+                                    "emit" => "body",
+                                    "children" =>
+                                        (crate::value!([
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "_"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_token_touch",
+                                                            "value" => "["
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "_"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "Slice"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_token_touch",
+                                                            "value" => "]"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "call",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "ast"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "callarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_string",
+                                                                                "value" => "item"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "_"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_token_touch",
+                                                            "value" => "["
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "_"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "Expression"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_token_touch",
+                                                            "value" => "]"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "call",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "ast"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "callarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_string",
+                                                                                "value" => "item"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ]))
+                                        ]))
+                                ]))
+                        ]))
+                    ]))
+            ])),
+            (crate::value!([
+                "emit" => "constant",
+                "children" =>
+                    (crate::value!([
+                        (crate::value!([
                             "emit" => "identifier",
                             "value" => "Attribute"
                         ])),
@@ -2110,8 +2952,18 @@ crate::value!([
                                                         "emit" => "op_mod_kle",
                                                         "children" =>
                                                             (crate::value!([
-                                                                "emit" => "identifier",
-                                                                "value" => "Subscript"
+                                                                "emit" => "op_binary_bitor",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "Subscript"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "Attribute"
+                                                                        ]))
+                                                                    ]))
                                                             ]))
                                                     ])),
                                                     (crate::value!([
@@ -2344,6 +3196,56 @@ crate::value!([
                                                         "emit" => "identifier",
                                                         "value" => "_"
                                                     ])),
+                                                    (crate::value!([
+                                                        "emit" => "op_mod_opt",
+                                                        "children" =>
+                                                            (crate::value!([
+                                                                "emit" => "sequence",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "value_instance",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    (crate::value!([
+                                                                                        "emit" => "identifier",
+                                                                                        "value" => "Keyword"
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "instarg",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                "emit" => "value_token_touch",
+                                                                                                "value" => "nomemo"
+                                                                                            ]))
+                                                                                    ]))
+                                                                                ]))
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "_"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "call",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    (crate::value!([
+                                                                                        "emit" => "identifier",
+                                                                                        "value" => "ast"
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "callarg",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                "emit" => "value_string",
+                                                                                                "value" => "nomemo"
+                                                                                            ]))
+                                                                                    ]))
+                                                                                ]))
+                                                                        ]))
+                                                                    ]))
+                                                            ]))
+                                                    ])),
                                                     (crate::value!([
                                                         "emit" => "op_mod_opt",
                                                         "children" =>
@@ -2757,7 +3659,7 @@ crate::value!([
                             "value" => "StaticParseletInstance"
                         ])),
                         (crate::value!([
-                            "emit" => "block",
+                            "emit" => "op_binary_bitor",
                             "children" =>
                                 (crate::value!([
                                     (crate::value!([
@@ -3170,32 +4072,87 @@ crate::value!([
                                                     ]))
                                             ])),
                                             (crate::value!([
-                                                "emit" => "identifier",
-                                                "value" => "InlineAssignment"
-                                            ]))
-                                        ]))
-                                ]))
-                        ]))
-                    ]))
-            ])),
-            (crate::value!([
-                "emit" => "constant",
-                "children" =>
-                    (crate::value!([
-                        (crate::value!([
-                            "emit" => "identifier",
-                            "value" => "InlineSequence"
-                        ])),
-                        (crate::value!([
-                            "emit" => "value_parselet",
-                            "children" =>
-                                (crate::value!([
-                                    "emit" => "body",
-                                    "children" =>
-                                        (crate::value!([
-                                            "emit" => "sequence",
-                                            "children" =>
-                                                (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "InlineAssignment"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "op_if",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "comparison",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                (crate::value!([
+                                                                                    "emit" => "rvalue",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            (crate::value!([
+                                                                                                "emit" => "capture_index",
+                                                                                                "children" =>
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "value_integer",
+                                                                                                        "value" => 1
+                                                                                                    ]))
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "item",
+                                                                                                "children" =>
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "value_string",
+                                                                                                        "value" => "emit"
+                                                                                                    ]))
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ])),
+                                                                                (crate::value!([
+                                                                                    "emit" => "cmp_eq",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            "emit" => "value_string",
+                                                                                            "value" => "op_binary_bitor"
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "op_next"
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "identifier",
+                                                "value" => "BitXor"
+                                            ]))
+                                        ]))
+                                ]))
+                        ]))
+                    ]))
+            ])),
+            (crate::value!([
+                "emit" => "constant",
+                "children" =>
+                    (crate::value!([
+                        (crate::value!([
+                            "emit" => "identifier",
+                            "value" => "InlineSequence"
+                        ])),
+                        (crate::value!([
+                            "emit" => "value_parselet",
+                            "children" =>
+                                (crate::value!([
+                                    "emit" => "body",
+                                    "children" =>
+                                        (crate::value!([
+                                            "emit" => "sequence",
+                                            "children" =>
+                                                (crate::value!([
                                                     (crate::value!([
                                                         "emit" => "op_mod_pos",
                                                         "children" =>
@@ -3203,6 +4160,10 @@ crate::value!([
                                                                 "emit" => "sequence",
                                                                 "children" =>
                                                                     (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "_"
+                                                                        ])),
                                                                         (crate::value!([
                                                                             "emit" => "identifier",
                                                                             "value" => "InlineSequenceItem"
@@ -3385,50 +4346,72 @@ crate::value!([
                                                     (crate::value!([
                                                         (crate::value!([
                                                             "emit" => "identifier",
-                                                            "value" => "InlineSequence"
+                                                            "value" => "InlineSequences"
                                                         ])),
                                                         (crate::value!([
-                                                            "emit" => "op_mod_pos",
+                                                            "emit" => "identifier",
+                                                            "value" => "___"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_token_touch",
+                                                            "value" => "|"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_instance",
                                                             "children" =>
                                                                 (crate::value!([
-                                                                    "emit" => "sequence",
-                                                                    "children" =>
-                                                                        (crate::value!([
-                                                                            (crate::value!([
-                                                                                "emit" => "identifier",
-                                                                                "value" => "___"
-                                                                            ])),
-                                                                            (crate::value!([
-                                                                                "emit" => "value_token_touch",
-                                                                                "value" => "|"
-                                                                            ])),
-                                                                            (crate::value!([
-                                                                                "emit" => "identifier",
-                                                                                "value" => "_"
-                                                                            ])),
-                                                                            (crate::value!([
-                                                                                "emit" => "identifier",
-                                                                                "value" => "___"
-                                                                            ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Not"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
                                                                             (crate::value!([
-                                                                                "emit" => "value_instance",
+                                                                                "emit" => "value_token_ccl",
                                                                                 "children" =>
                                                                                     (crate::value!([
-                                                                                        (crate::value!([
-                                                                                            "emit" => "identifier",
-                                                                                            "value" => "Expect"
-                                                                                        ])),
-                                                                                        (crate::value!([
-                                                                                            "emit" => "instarg",
-                                                                                            "children" =>
+                                                                                        "emit" => "ccl",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
                                                                                                 (crate::value!([
-                                                                                                    "emit" => "identifier",
-                                                                                                    "value" => "InlineSequence"
+                                                                                                    "emit" => "char",
+                                                                                                    "value" => "|"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "char",
+                                                                                                    "value" => "="
                                                                                                 ]))
-                                                                                        ]))
+                                                                                            ]))
                                                                                     ]))
                                                                             ]))
-                                                                        ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "_"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "___"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_instance",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Expect"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "identifier",
+                                                                                "value" => "InlineSequence"
+                                                                            ]))
+                                                                    ]))
                                                                 ]))
                                                         ])),
                                                         (crate::value!([
@@ -3444,7 +4427,7 @@ crate::value!([
                                                                         "children" =>
                                                                             (crate::value!([
                                                                                 "emit" => "value_string",
-                                                                                "value" => "block"
+                                                                                "value" => "op_binary_bitor"
                                                                             ]))
                                                                     ]))
                                                                 ]))
@@ -3913,32 +4896,48 @@ crate::value!([
                                                             "value" => "'"
                                                         ])),
                                                         (crate::value!([
-                                                            "emit" => "call",
+                                                            "emit" => "value_token_touch",
+                                                            "value" => "i"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_instance",
                                                             "children" =>
                                                                 (crate::value!([
                                                                     (crate::value!([
                                                                         "emit" => "identifier",
-                                                                        "value" => "ast"
+                                                                        "value" => "Not"
                                                                     ])),
                                                                     (crate::value!([
-                                                                        "emit" => "callarg",
+                                                                        "emit" => "instarg",
                                                                         "children" =>
                                                                             (crate::value!([
-                                                                                "emit" => "value_string",
-                                                                                "value" => "value_token_match"
+                                                                                "emit" => "value_token_ccl",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        "emit" => "ccl",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "range",
+                                                                                                    "value" => "09"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "range",
+                                                                                                    "value" => "AZ"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "char",
+                                                                                                    "value" => "_"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "range",
+                                                                                                    "value" => "az"
+                                                                                                ]))
+                                                                                            ]))
+                                                                                    ]))
                                                                             ]))
                                                                     ]))
                                                                 ]))
-                                                        ]))
-                                                    ]))
-                                            ])),
-                                            (crate::value!([
-                                                "emit" => "sequence",
-                                                "children" =>
-                                                    (crate::value!([
-                                                        (crate::value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "T_Touch"
                                                         ])),
                                                         (crate::value!([
                                                             "emit" => "call",
@@ -3953,7 +4952,7 @@ crate::value!([
                                                                         "children" =>
                                                                             (crate::value!([
                                                                                 "emit" => "value_string",
-                                                                                "value" => "value_token_touch"
+                                                                                "value" => "value_token_match_nocase"
                                                                             ]))
                                                                     ]))
                                                                 ]))
@@ -3964,35 +4963,17 @@ crate::value!([
                                                 "emit" => "sequence",
                                                 "children" =>
                                                     (crate::value!([
-                                                        (crate::value!([
-                                                            "emit" => "value_instance",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "Keyword"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "instarg",
-                                                                        "children" =>
-                                                                            (crate::value!([
-                                                                                "emit" => "value_token_touch",
-                                                                                "value" => "Chars"
-                                                                            ]))
-                                                                    ]))
-                                                                ]))
-                                                        ])),
                                                         (crate::value!([
                                                             "emit" => "value_token_touch",
-                                                            "value" => "<"
+                                                            "value" => "'"
                                                         ])),
                                                         (crate::value!([
                                                             "emit" => "identifier",
-                                                            "value" => "Ccl"
+                                                            "value" => "T_Touch"
                                                         ])),
                                                         (crate::value!([
                                                             "emit" => "value_token_touch",
-                                                            "value" => ">"
+                                                            "value" => "'"
                                                         ])),
                                                         (crate::value!([
                                                             "emit" => "call",
@@ -4007,7 +4988,7 @@ crate::value!([
                                                                         "children" =>
                                                                             (crate::value!([
                                                                                 "emit" => "value_string",
-                                                                                "value" => "value_token_ccls"
+                                                                                "value" => "value_token_match"
                                                                             ]))
                                                                     ]))
                                                                 ]))
@@ -4018,20 +4999,50 @@ crate::value!([
                                                 "emit" => "sequence",
                                                 "children" =>
                                                     (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "T_Touch"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_token_touch",
+                                                            "value" => "i"
+                                                        ])),
                                                         (crate::value!([
                                                             "emit" => "value_instance",
                                                             "children" =>
                                                                 (crate::value!([
                                                                     (crate::value!([
                                                                         "emit" => "identifier",
-                                                                        "value" => "Keyword"
+                                                                        "value" => "Not"
                                                                     ])),
                                                                     (crate::value!([
                                                                         "emit" => "instarg",
                                                                         "children" =>
                                                                             (crate::value!([
-                                                                                "emit" => "value_token_touch",
-                                                                                "value" => "Chars"
+                                                                                "emit" => "value_token_ccl",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        "emit" => "ccl",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "range",
+                                                                                                    "value" => "09"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "range",
+                                                                                                    "value" => "AZ"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "char",
+                                                                                                    "value" => "_"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "range",
+                                                                                                    "value" => "az"
+                                                                                                ]))
+                                                                                            ]))
+                                                                                    ]))
                                                                             ]))
                                                                     ]))
                                                                 ]))
@@ -4049,7 +5060,7 @@ crate::value!([
                                                                         "children" =>
                                                                             (crate::value!([
                                                                                 "emit" => "value_string",
-                                                                                "value" => "value_token_anys"
+                                                                                "value" => "value_token_touch_nocase"
                                                                             ]))
                                                                     ]))
                                                                 ]))
@@ -4061,15 +5072,167 @@ crate::value!([
                                                 "children" =>
                                                     (crate::value!([
                                                         (crate::value!([
-                                                            "emit" => "value_instance",
+                                                            "emit" => "identifier",
+                                                            "value" => "T_Touch"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "call",
                                                             "children" =>
                                                                 (crate::value!([
                                                                     (crate::value!([
                                                                         "emit" => "identifier",
-                                                                        "value" => "Keyword"
+                                                                        "value" => "ast"
                                                                     ])),
                                                                     (crate::value!([
-                                                                        "emit" => "instarg",
+                                                                        "emit" => "callarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_string",
+                                                                                "value" => "value_token_touch"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "T_Regex"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "call",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "ast"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "callarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_string",
+                                                                                "value" => "value_token_regex"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "value_instance",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Keyword"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_token_touch",
+                                                                                "value" => "Chars"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_token_touch",
+                                                            "value" => "<"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "Ccl"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_token_touch",
+                                                            "value" => ">"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "call",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "ast"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "callarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_string",
+                                                                                "value" => "value_token_ccls"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "value_instance",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Keyword"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_token_touch",
+                                                                                "value" => "Chars"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "call",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "ast"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "callarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_string",
+                                                                                "value" => "value_token_anys"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "value_instance",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Keyword"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "instarg",
                                                                         "children" =>
                                                                             (crate::value!([
                                                                                 "emit" => "value_token_touch",
@@ -4271,7 +5434,7 @@ crate::value!([
                                                             "value" => "___"
                                                         ])),
                                                         (crate::value!([
-                                                            "emit" => "block",
+                                                            "emit" => "op_binary_bitor",
                                                             "children" =>
                                                                 (crate::value!([
                                                                     (crate::value!([
@@ -4333,7 +5496,7 @@ crate::value!([
                                                             "value" => "___"
                                                         ])),
                                                         (crate::value!([
-                                                            "emit" => "block",
+                                                            "emit" => "op_binary_bitor",
                                                             "children" =>
                                                                 (crate::value!([
                                                                     (crate::value!([
@@ -5163,65 +6326,7 @@ crate::value!([
                                                                         "children" =>
                                                                             (crate::value!([
                                                                                 "emit" => "value_token_touch",
-                                                                                "value" => "loop"
-                                                                            ]))
-                                                                    ]))
-                                                                ]))
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "_"
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "Expression"
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "___"
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "Block"
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "call",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "ast"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "callarg",
-                                                                        "children" =>
-                                                                            (crate::value!([
-                                                                                "emit" => "value_string",
-                                                                                "value" => "op_loop"
-                                                                            ]))
-                                                                    ]))
-                                                                ]))
-                                                        ]))
-                                                    ]))
-                                            ])),
-                                            (crate::value!([
-                                                "emit" => "sequence",
-                                                "children" =>
-                                                    (crate::value!([
-                                                        (crate::value!([
-                                                            "emit" => "value_instance",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "Keyword"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "instarg",
-                                                                        "children" =>
-                                                                            (crate::value!([
-                                                                                "emit" => "value_token_touch",
-                                                                                "value" => "loop"
+                                                                                "value" => "try"
                                                                             ]))
                                                                     ]))
                                                                 ]))
@@ -5247,57 +6352,277 @@ crate::value!([
                                                                         "children" =>
                                                                             (crate::value!([
                                                                                 "emit" => "identifier",
-                                                                                "value" => "Block"
+                                                                                "value" => "Statement"
                                                                             ]))
                                                                     ]))
                                                                 ]))
                                                         ])),
                                                         (crate::value!([
-                                                            "emit" => "call",
+                                                            "emit" => "op_mod_opt",
                                                             "children" =>
                                                                 (crate::value!([
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "ast"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "callarg",
-                                                                        "children" =>
+                                                                    "emit" => "sequence",
+                                                                    "children" =>
+                                                                        (crate::value!([
                                                                             (crate::value!([
-                                                                                "emit" => "value_string",
-                                                                                "value" => "op_loop"
-                                                                            ]))
-                                                                    ]))
-                                                                ]))
-                                                        ]))
-                                                    ]))
-                                            ])),
-                                            (crate::value!([
-                                                "emit" => "identifier",
-                                                "value" => "Load"
-                                            ]))
-                                        ]))
-                                ]))
-                        ]))
-                    ]))
-            ])),
-            (crate::value!([
-                "emit" => "constant",
-                "children" =>
-                    (crate::value!([
-                        (crate::value!([
-                            "emit" => "identifier",
-                            "value" => "Rvalue"
-                        ])),
-                        (crate::value!([
-                            "emit" => "value_parselet",
-                            "children" =>
-                                (crate::value!([
-                                    "emit" => "body",
-                                    "children" =>
-                                        (crate::value!([
-                                            (crate::value!([
-                                                "emit" => "sequence",
+                                                                                "emit" => "identifier",
+                                                                                "value" => "___"
+                                                                            ])),
+                                                                            (crate::value!([
+                                                                                "emit" => "value_instance",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        (crate::value!([
+                                                                                            "emit" => "identifier",
+                                                                                            "value" => "Keyword"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "instarg",
+                                                                                            "children" =>
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "value_token_touch",
+                                                                                                    "value" => "catch"
+                                                                                                ]))
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ])),
+                                                                            (crate::value!([
+                                                                                "emit" => "identifier",
+                                                                                "value" => "_"
+                                                                            ])),
+                                                                            (crate::value!([
+                                                                                "emit" => "op_mod_opt",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        "emit" => "sequence",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "Lvalue"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "_"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "value_instance",
+                                                                                                    "children" =>
+                                                                                                        (crate::value!([
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "identifier",
+                                                                                                                "value" => "Peek"
+                                                                                                            ])),
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "instarg",
+                                                                                                                "children" =>
+                                                                                                                    (crate::value!([
+                                                                                                                        "emit" => "value_token_touch",
+                                                                                                                        "value" => "{"
+                                                                                                                    ]))
+                                                                                                            ]))
+                                                                                                        ]))
+                                                                                                ]))
+                                                                                            ]))
+                                                                                    ]))
+                                                                            ])),
+                                                                            (crate::value!([
+                                                                                "emit" => "identifier",
+                                                                                "value" => "___"
+                                                                            ])),
+                                                                            (crate::value!([
+                                                                                "emit" => "value_instance",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        (crate::value!([
+                                                                                            "emit" => "identifier",
+                                                                                            "value" => "Expect"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "instarg",
+                                                                                            "children" =>
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "Statement"
+                                                                                                ]))
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                        ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "call",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "ast"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "callarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_string",
+                                                                                "value" => "op_try"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "value_instance",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Keyword"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_token_touch",
+                                                                                "value" => "loop"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "_"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "Expression"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "___"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "Block"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "call",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "ast"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "callarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_string",
+                                                                                "value" => "op_loop"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "value_instance",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Keyword"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_token_touch",
+                                                                                "value" => "loop"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "_"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "___"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_instance",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Expect"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "identifier",
+                                                                                "value" => "Block"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "call",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "ast"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "callarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_string",
+                                                                                "value" => "op_loop"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "identifier",
+                                                "value" => "Load"
+                                            ]))
+                                        ]))
+                                ]))
+                        ]))
+                    ]))
+            ])),
+            (crate::value!([
+                "emit" => "constant",
+                "children" =>
+                    (crate::value!([
+                        (crate::value!([
+                            "emit" => "identifier",
+                            "value" => "Rvalue"
+                        ])),
+                        (crate::value!([
+                            "emit" => "value_parselet",
+                            "children" =>
+                                (crate::value!([
+                                    "emit" => "body",
+                                    "children" =>
+                                        (crate::value!([
+                                            (crate::value!([
+                                                "emit" => "sequence",
                                                 "children" =>
                                                     (crate::value!([
                                                         (crate::value!([
@@ -5374,7 +6699,7 @@ crate::value!([
                                                             "emit" => "op_mod_kle",
                                                             "children" =>
                                                                 (crate::value!([
-                                                                    "emit" => "block",
+                                                                    "emit" => "op_binary_bitor",
                                                                     "children" =>
                                                                         (crate::value!([
                                                                             (crate::value!([
@@ -5528,7 +6853,7 @@ crate::value!([
                                                     (crate::value!([
                                                         (crate::value!([
                                                             "emit" => "value_token_touch",
-                                                            "value" => "*"
+                                                            "value" => "~"
                                                         ])),
                                                         (crate::value!([
                                                             "emit" => "identifier",
@@ -5551,7 +6876,7 @@ crate::value!([
                                                                         "children" =>
                                                                             (crate::value!([
                                                                                 "emit" => "value_string",
-                                                                                "value" => "op_deref"
+                                                                                "value" => "op_unary_bitnot"
                                                                             ]))
                                                                     ]))
                                                                 ]))
@@ -5563,9 +6888,199 @@ crate::value!([
                                                 "children" =>
                                                     (crate::value!([
                                                         (crate::value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "Rvalue"
-                                                        ])),
+                                                            "emit" => "value_token_touch",
+                                                            "value" => "*"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "_"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "Unary"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "call",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "ast"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "callarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_string",
+                                                                                "value" => "op_deref"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "identifier",
+                                                "value" => "Power"
+                                            ]))
+                                        ]))
+                                ]))
+                        ]))
+                    ]))
+            ])),
+            (crate::value!([
+                "emit" => "constant",
+                "children" =>
+                    (crate::value!([
+                        (crate::value!([
+                            "emit" => "identifier",
+                            "value" => "Power"
+                        ])),
+                        (crate::value!([
+                            "emit" => "value_parselet",
+                            "children" =>
+                                (crate::value!([
+                                    "emit" => "body",
+                                    "children" =>
+                                        (crate::value!([
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "Rvalue"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "_"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_token_touch",
+                                                            "value" => "**"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_instance",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Not"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_token_ccl",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        "emit" => "ccl",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                "emit" => "char",
+                                                                                                "value" => "="
+                                                                                            ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "_"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_instance",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Expect"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "identifier",
+                                                                                "value" => "Unary"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "call",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "ast"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "callarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_string",
+                                                                                "value" => "op_binary_pow"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "Rvalue"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_instance",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Peek"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "sequence",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        (crate::value!([
+                                                                                            "emit" => "identifier",
+                                                                                            "value" => "__"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "identifier",
+                                                                                            "value" => "T_Regex"
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "capture_index",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    "emit" => "value_integer",
+                                                                    "value" => 1
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "Rvalue"
+                                                        ])),
                                                         (crate::value!([
                                                             "emit" => "identifier",
                                                             "value" => "_"
@@ -5622,8 +7137,14 @@ crate::value!([
                                                                                         "emit" => "ccl",
                                                                                         "children" =>
                                                                                             (crate::value!([
-                                                                                                "emit" => "char",
-                                                                                                "value" => "="
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "char",
+                                                                                                    "value" => "*"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "char",
+                                                                                                    "value" => "="
+                                                                                                ]))
                                                                                             ]))
                                                                                     ]))
                                                                             ]))
@@ -6123,7 +7644,7 @@ crate::value!([
                     (crate::value!([
                         (crate::value!([
                             "emit" => "identifier",
-                            "value" => "Comparison"
+                            "value" => "Shift"
                         ])),
                         (crate::value!([
                             "emit" => "value_parselet",
@@ -6138,316 +7659,248 @@ crate::value!([
                                                     (crate::value!([
                                                         (crate::value!([
                                                             "emit" => "identifier",
-                                                            "value" => "AddSub"
+                                                            "value" => "Shift"
                                                         ])),
                                                         (crate::value!([
-                                                            "emit" => "op_mod_pos",
+                                                            "emit" => "value_token_touch",
+                                                            "value" => "<<"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_instance",
                                                             "children" =>
                                                                 (crate::value!([
-                                                                    "emit" => "block",
-                                                                    "children" =>
-                                                                        (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Not"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
                                                                             (crate::value!([
-                                                                                "emit" => "sequence",
+                                                                                "emit" => "value_token_ccl",
                                                                                 "children" =>
                                                                                     (crate::value!([
-                                                                                        (crate::value!([
-                                                                                            "emit" => "value_token_touch",
-                                                                                            "value" => "=="
-                                                                                        ])),
-                                                                                        (crate::value!([
-                                                                                            "emit" => "identifier",
-                                                                                            "value" => "_"
-                                                                                        ])),
-                                                                                        (crate::value!([
-                                                                                            "emit" => "value_instance",
-                                                                                            "children" =>
-                                                                                                (crate::value!([
-                                                                                                    (crate::value!([
-                                                                                                        "emit" => "identifier",
-                                                                                                        "value" => "Expect"
-                                                                                                    ])),
-                                                                                                    (crate::value!([
-                                                                                                        "emit" => "instarg",
-                                                                                                        "children" =>
-                                                                                                            (crate::value!([
-                                                                                                                "emit" => "identifier",
-                                                                                                                "value" => "AddSub"
-                                                                                                            ]))
-                                                                                                    ]))
-                                                                                                ]))
-                                                                                        ])),
-                                                                                        (crate::value!([
-                                                                                            "emit" => "call",
-                                                                                            "children" =>
-                                                                                                (crate::value!([
-                                                                                                    (crate::value!([
-                                                                                                        "emit" => "identifier",
-                                                                                                        "value" => "ast"
-                                                                                                    ])),
-                                                                                                    (crate::value!([
-                                                                                                        "emit" => "callarg",
-                                                                                                        "children" =>
-                                                                                                            (crate::value!([
-                                                                                                                "emit" => "value_string",
-                                                                                                                "value" => "cmp_eq"
-                                                                                                            ]))
-                                                                                                    ]))
-                                                                                                ]))
-                                                                                        ]))
-                                                                                    ]))
-                                                                            ])),
+                                                                                        "emit" => "ccl",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                "emit" => "char",
+                                                                                                "value" => "="
+                                                                                            ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "_"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_instance",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Expect"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
                                                                             (crate::value!([
-                                                                                "emit" => "sequence",
+                                                                                "emit" => "identifier",
+                                                                                "value" => "AddSub"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "call",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "ast"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "callarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_string",
+                                                                                "value" => "op_binary_shl"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "Shift"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_token_touch",
+                                                            "value" => ">>"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_instance",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Not"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_token_ccl",
                                                                                 "children" =>
                                                                                     (crate::value!([
-                                                                                        (crate::value!([
-                                                                                            "emit" => "value_token_touch",
-                                                                                            "value" => "!="
-                                                                                        ])),
-                                                                                        (crate::value!([
-                                                                                            "emit" => "identifier",
-                                                                                            "value" => "_"
-                                                                                        ])),
-                                                                                        (crate::value!([
-                                                                                            "emit" => "value_instance",
-                                                                                            "children" =>
-                                                                                                (crate::value!([
-                                                                                                    (crate::value!([
-                                                                                                        "emit" => "identifier",
-                                                                                                        "value" => "Expect"
-                                                                                                    ])),
-                                                                                                    (crate::value!([
-                                                                                                        "emit" => "instarg",
-                                                                                                        "children" =>
-                                                                                                            (crate::value!([
-                                                                                                                "emit" => "identifier",
-                                                                                                                "value" => "AddSub"
-                                                                                                            ]))
-                                                                                                    ]))
-                                                                                                ]))
-                                                                                        ])),
-                                                                                        (crate::value!([
-                                                                                            "emit" => "call",
-                                                                                            "children" =>
-                                                                                                (crate::value!([
-                                                                                                    (crate::value!([
-                                                                                                        "emit" => "identifier",
-                                                                                                        "value" => "ast"
-                                                                                                    ])),
-                                                                                                    (crate::value!([
-                                                                                                        "emit" => "callarg",
-                                                                                                        "children" =>
-                                                                                                            (crate::value!([
-                                                                                                                "emit" => "value_string",
-                                                                                                                "value" => "cmp_neq"
-                                                                                                            ]))
-                                                                                                    ]))
-                                                                                                ]))
-                                                                                        ]))
+                                                                                        "emit" => "ccl",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                "emit" => "char",
+                                                                                                "value" => "="
+                                                                                            ]))
                                                                                     ]))
-                                                                            ])),
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "_"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_instance",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Expect"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
                                                                             (crate::value!([
-                                                                                "emit" => "sequence",
+                                                                                "emit" => "identifier",
+                                                                                "value" => "AddSub"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "call",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "ast"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "callarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_string",
+                                                                                "value" => "op_binary_shr"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "identifier",
+                                                "value" => "AddSub"
+                                            ]))
+                                        ]))
+                                ]))
+                        ]))
+                    ]))
+            ])),
+            (crate::value!([
+                "emit" => "constant",
+                "children" =>
+                    (crate::value!([
+                        (crate::value!([
+                            "emit" => "identifier",
+                            "value" => "BitAnd"
+                        ])),
+                        (crate::value!([
+                            "emit" => "value_parselet",
+                            "children" =>
+                                (crate::value!([
+                                    "emit" => "body",
+                                    "children" =>
+                                        (crate::value!([
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "BitAnd"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_token_touch",
+                                                            "value" => "&"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_instance",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Not"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_token_ccl",
                                                                                 "children" =>
                                                                                     (crate::value!([
-                                                                                        (crate::value!([
-                                                                                            "emit" => "value_token_touch",
-                                                                                            "value" => "<="
-                                                                                        ])),
-                                                                                        (crate::value!([
-                                                                                            "emit" => "identifier",
-                                                                                            "value" => "_"
-                                                                                        ])),
-                                                                                        (crate::value!([
-                                                                                            "emit" => "value_instance",
-                                                                                            "children" =>
+                                                                                        "emit" => "ccl",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
                                                                                                 (crate::value!([
-                                                                                                    (crate::value!([
-                                                                                                        "emit" => "identifier",
-                                                                                                        "value" => "Expect"
-                                                                                                    ])),
-                                                                                                    (crate::value!([
-                                                                                                        "emit" => "instarg",
-                                                                                                        "children" =>
-                                                                                                            (crate::value!([
-                                                                                                                "emit" => "identifier",
-                                                                                                                "value" => "AddSub"
-                                                                                                            ]))
-                                                                                                    ]))
-                                                                                                ]))
-                                                                                        ])),
-                                                                                        (crate::value!([
-                                                                                            "emit" => "call",
-                                                                                            "children" =>
+                                                                                                    "emit" => "char",
+                                                                                                    "value" => "&"
+                                                                                                ])),
                                                                                                 (crate::value!([
-                                                                                                    (crate::value!([
-                                                                                                        "emit" => "identifier",
-                                                                                                        "value" => "ast"
-                                                                                                    ])),
-                                                                                                    (crate::value!([
-                                                                                                        "emit" => "callarg",
-                                                                                                        "children" =>
-                                                                                                            (crate::value!([
-                                                                                                                "emit" => "value_string",
-                                                                                                                "value" => "cmp_lteq"
-                                                                                                            ]))
-                                                                                                    ]))
-                                                                                                ]))
-                                                                                        ]))
-                                                                                    ]))
-                                                                            ])),
-                                                                            (crate::value!([
-                                                                                "emit" => "sequence",
-                                                                                "children" =>
-                                                                                    (crate::value!([
-                                                                                        (crate::value!([
-                                                                                            "emit" => "value_token_touch",
-                                                                                            "value" => ">="
-                                                                                        ])),
-                                                                                        (crate::value!([
-                                                                                            "emit" => "identifier",
-                                                                                            "value" => "_"
-                                                                                        ])),
-                                                                                        (crate::value!([
-                                                                                            "emit" => "value_instance",
-                                                                                            "children" =>
-                                                                                                (crate::value!([
-                                                                                                    (crate::value!([
-                                                                                                        "emit" => "identifier",
-                                                                                                        "value" => "Expect"
-                                                                                                    ])),
-                                                                                                    (crate::value!([
-                                                                                                        "emit" => "instarg",
-                                                                                                        "children" =>
-                                                                                                            (crate::value!([
-                                                                                                                "emit" => "identifier",
-                                                                                                                "value" => "AddSub"
-                                                                                                            ]))
-                                                                                                    ]))
-                                                                                                ]))
-                                                                                        ])),
-                                                                                        (crate::value!([
-                                                                                            "emit" => "call",
-                                                                                            "children" =>
-                                                                                                (crate::value!([
-                                                                                                    (crate::value!([
-                                                                                                        "emit" => "identifier",
-                                                                                                        "value" => "ast"
-                                                                                                    ])),
-                                                                                                    (crate::value!([
-                                                                                                        "emit" => "callarg",
-                                                                                                        "children" =>
-                                                                                                            (crate::value!([
-                                                                                                                "emit" => "value_string",
-                                                                                                                "value" => "cmp_gteq"
-                                                                                                            ]))
-                                                                                                    ]))
-                                                                                                ]))
-                                                                                        ]))
-                                                                                    ]))
-                                                                            ])),
-                                                                            (crate::value!([
-                                                                                "emit" => "sequence",
-                                                                                "children" =>
-                                                                                    (crate::value!([
-                                                                                        (crate::value!([
-                                                                                            "emit" => "value_token_touch",
-                                                                                            "value" => "<"
-                                                                                        ])),
-                                                                                        (crate::value!([
-                                                                                            "emit" => "identifier",
-                                                                                            "value" => "_"
-                                                                                        ])),
-                                                                                        (crate::value!([
-                                                                                            "emit" => "value_instance",
-                                                                                            "children" =>
-                                                                                                (crate::value!([
-                                                                                                    (crate::value!([
-                                                                                                        "emit" => "identifier",
-                                                                                                        "value" => "Expect"
-                                                                                                    ])),
-                                                                                                    (crate::value!([
-                                                                                                        "emit" => "instarg",
-                                                                                                        "children" =>
-                                                                                                            (crate::value!([
-                                                                                                                "emit" => "identifier",
-                                                                                                                "value" => "AddSub"
-                                                                                                            ]))
-                                                                                                    ]))
-                                                                                                ]))
-                                                                                        ])),
-                                                                                        (crate::value!([
-                                                                                            "emit" => "call",
-                                                                                            "children" =>
-                                                                                                (crate::value!([
-                                                                                                    (crate::value!([
-                                                                                                        "emit" => "identifier",
-                                                                                                        "value" => "ast"
-                                                                                                    ])),
-                                                                                                    (crate::value!([
-                                                                                                        "emit" => "callarg",
-                                                                                                        "children" =>
-                                                                                                            (crate::value!([
-                                                                                                                "emit" => "value_string",
-                                                                                                                "value" => "cmp_lt"
-                                                                                                            ]))
-                                                                                                    ]))
+                                                                                                    "emit" => "char",
+                                                                                                    "value" => "="
                                                                                                 ]))
-                                                                                        ]))
+                                                                                            ]))
                                                                                     ]))
-                                                                            ])),
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "_"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_instance",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Expect"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
                                                                             (crate::value!([
-                                                                                "emit" => "sequence",
-                                                                                "children" =>
-                                                                                    (crate::value!([
-                                                                                        (crate::value!([
-                                                                                            "emit" => "value_token_touch",
-                                                                                            "value" => ">"
-                                                                                        ])),
-                                                                                        (crate::value!([
-                                                                                            "emit" => "identifier",
-                                                                                            "value" => "_"
-                                                                                        ])),
-                                                                                        (crate::value!([
-                                                                                            "emit" => "value_instance",
-                                                                                            "children" =>
-                                                                                                (crate::value!([
-                                                                                                    (crate::value!([
-                                                                                                        "emit" => "identifier",
-                                                                                                        "value" => "Expect"
-                                                                                                    ])),
-                                                                                                    (crate::value!([
-                                                                                                        "emit" => "instarg",
-                                                                                                        "children" =>
-                                                                                                            (crate::value!([
-                                                                                                                "emit" => "identifier",
-                                                                                                                "value" => "AddSub"
-                                                                                                            ]))
-                                                                                                    ]))
-                                                                                                ]))
-                                                                                        ])),
-                                                                                        (crate::value!([
-                                                                                            "emit" => "call",
-                                                                                            "children" =>
-                                                                                                (crate::value!([
-                                                                                                    (crate::value!([
-                                                                                                        "emit" => "identifier",
-                                                                                                        "value" => "ast"
-                                                                                                    ])),
-                                                                                                    (crate::value!([
-                                                                                                        "emit" => "callarg",
-                                                                                                        "children" =>
-                                                                                                            (crate::value!([
-                                                                                                                "emit" => "value_string",
-                                                                                                                "value" => "cmp_gt"
-                                                                                                            ]))
-                                                                                                    ]))
-                                                                                                ]))
-                                                                                        ]))
-                                                                                    ]))
+                                                                                "emit" => "identifier",
+                                                                                "value" => "Shift"
                                                                             ]))
-                                                                        ]))
+                                                                    ]))
                                                                 ]))
                                                         ])),
                                                         (crate::value!([
@@ -6463,7 +7916,7 @@ crate::value!([
                                                                         "children" =>
                                                                             (crate::value!([
                                                                                 "emit" => "value_string",
-                                                                                "value" => "comparison"
+                                                                                "value" => "op_binary_bitand"
                                                                             ]))
                                                                     ]))
                                                                 ]))
@@ -6472,7 +7925,7 @@ crate::value!([
                                             ])),
                                             (crate::value!([
                                                 "emit" => "identifier",
-                                                "value" => "AddSub"
+                                                "value" => "Shift"
                                             ]))
                                         ]))
                                 ]))
@@ -6485,7 +7938,7 @@ crate::value!([
                     (crate::value!([
                         (crate::value!([
                             "emit" => "identifier",
-                            "value" => "LogicalAnd"
+                            "value" => "BitXor"
                         ])),
                         (crate::value!([
                             "emit" => "value_parselet",
@@ -6500,11 +7953,37 @@ crate::value!([
                                                     (crate::value!([
                                                         (crate::value!([
                                                             "emit" => "identifier",
-                                                            "value" => "LogicalAnd"
+                                                            "value" => "BitXor"
                                                         ])),
                                                         (crate::value!([
                                                             "emit" => "value_token_touch",
-                                                            "value" => "&&"
+                                                            "value" => "^"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_instance",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Not"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_token_ccl",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        "emit" => "ccl",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                "emit" => "char",
+                                                                                                "value" => "="
+                                                                                            ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
                                                         ])),
                                                         (crate::value!([
                                                             "emit" => "identifier",
@@ -6523,7 +8002,7 @@ crate::value!([
                                                                         "children" =>
                                                                             (crate::value!([
                                                                                 "emit" => "identifier",
-                                                                                "value" => "Comparison"
+                                                                                "value" => "BitAnd"
                                                                             ]))
                                                                     ]))
                                                                 ]))
@@ -6541,7 +8020,7 @@ crate::value!([
                                                                         "children" =>
                                                                             (crate::value!([
                                                                                 "emit" => "value_string",
-                                                                                "value" => "op_logical_and"
+                                                                                "value" => "op_binary_bitxor"
                                                                             ]))
                                                                     ]))
                                                                 ]))
@@ -6550,7 +8029,7 @@ crate::value!([
                                             ])),
                                             (crate::value!([
                                                 "emit" => "identifier",
-                                                "value" => "Comparison"
+                                                "value" => "BitAnd"
                                             ]))
                                         ]))
                                 ]))
@@ -6563,7 +8042,7 @@ crate::value!([
                     (crate::value!([
                         (crate::value!([
                             "emit" => "identifier",
-                            "value" => "LogicalOr"
+                            "value" => "BitOr"
                         ])),
                         (crate::value!([
                             "emit" => "value_parselet",
@@ -6578,11 +8057,43 @@ crate::value!([
                                                     (crate::value!([
                                                         (crate::value!([
                                                             "emit" => "identifier",
-                                                            "value" => "LogicalOr"
+                                                            "value" => "BitOr"
                                                         ])),
                                                         (crate::value!([
                                                             "emit" => "value_token_touch",
-                                                            "value" => "||"
+                                                            "value" => "|"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_instance",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Not"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_token_ccl",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        "emit" => "ccl",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "char",
+                                                                                                    "value" => "|"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "char",
+                                                                                                    "value" => "="
+                                                                                                ]))
+                                                                                            ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
                                                         ])),
                                                         (crate::value!([
                                                             "emit" => "identifier",
@@ -6601,7 +8112,7 @@ crate::value!([
                                                                         "children" =>
                                                                             (crate::value!([
                                                                                 "emit" => "identifier",
-                                                                                "value" => "LogicalAnd"
+                                                                                "value" => "BitXor"
                                                                             ]))
                                                                     ]))
                                                                 ]))
@@ -6619,7 +8130,7 @@ crate::value!([
                                                                         "children" =>
                                                                             (crate::value!([
                                                                                 "emit" => "value_string",
-                                                                                "value" => "op_logical_or"
+                                                                                "value" => "op_binary_bitor"
                                                                             ]))
                                                                     ]))
                                                                 ]))
@@ -6628,7 +8139,7 @@ crate::value!([
                                             ])),
                                             (crate::value!([
                                                 "emit" => "identifier",
-                                                "value" => "LogicalAnd"
+                                                "value" => "BitXor"
                                             ]))
                                         ]))
                                 ]))
@@ -6641,21 +8152,7 @@ crate::value!([
                     (crate::value!([
                         (crate::value!([
                             "emit" => "identifier",
-                            "value" => "Expression"
-                        ])),
-                        (crate::value!([
-                            "emit" => "identifier",
-                            "value" => "LogicalOr"
-                        ]))
-                    ]))
-            ])),
-            (crate::value!([
-                "emit" => "constant",
-                "children" =>
-                    (crate::value!([
-                        (crate::value!([
-                            "emit" => "identifier",
-                            "value" => "ExpressionList"
+                            "value" => "Comparison"
                         ])),
                         (crate::value!([
                             "emit" => "value_parselet",
@@ -6670,94 +8167,2208 @@ crate::value!([
                                                     (crate::value!([
                                                         (crate::value!([
                                                             "emit" => "identifier",
-                                                            "value" => "Expression"
+                                                            "value" => "BitOr"
                                                         ])),
                                                         (crate::value!([
                                                             "emit" => "op_mod_pos",
                                                             "children" =>
                                                                 (crate::value!([
-                                                                    "emit" => "sequence",
+                                                                    "emit" => "block",
                                                                     "children" =>
                                                                         (crate::value!([
                                                                             (crate::value!([
-                                                                                "emit" => "value_token_touch",
-                                                                                "value" => ","
+                                                                                "emit" => "sequence",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        (crate::value!([
+                                                                                            "emit" => "value_token_touch",
+                                                                                            "value" => "=="
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "identifier",
+                                                                                            "value" => "_"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "value_instance",
+                                                                                            "children" =>
+                                                                                                (crate::value!([
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "Expect"
+                                                                                                    ])),
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "instarg",
+                                                                                                        "children" =>
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "identifier",
+                                                                                                                "value" => "BitOr"
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "call",
+                                                                                            "children" =>
+                                                                                                (crate::value!([
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "ast"
+                                                                                                    ])),
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "callarg",
+                                                                                                        "children" =>
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "value_string",
+                                                                                                                "value" => "cmp_eq"
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ]))
+                                                                                    ]))
                                                                             ])),
                                                                             (crate::value!([
-                                                                                "emit" => "identifier",
-                                                                                "value" => "_"
+                                                                                "emit" => "sequence",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        (crate::value!([
+                                                                                            "emit" => "value_token_touch",
+                                                                                            "value" => "!="
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "identifier",
+                                                                                            "value" => "_"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "value_instance",
+                                                                                            "children" =>
+                                                                                                (crate::value!([
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "Expect"
+                                                                                                    ])),
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "instarg",
+                                                                                                        "children" =>
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "identifier",
+                                                                                                                "value" => "BitOr"
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "call",
+                                                                                            "children" =>
+                                                                                                (crate::value!([
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "ast"
+                                                                                                    ])),
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "callarg",
+                                                                                                        "children" =>
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "value_string",
+                                                                                                                "value" => "cmp_neq"
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ]))
+                                                                                    ]))
                                                                             ])),
                                                                             (crate::value!([
-                                                                                "emit" => "identifier",
-                                                                                "value" => "Expression"
-                                                                            ]))
-                                                                        ]))
-                                                                ]))
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "op_mod_opt",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    "emit" => "sequence",
-                                                                    "children" =>
-                                                                        (crate::value!([
-                                                                            (crate::value!([
-                                                                                "emit" => "value_token_touch",
-                                                                                "value" => ","
+                                                                                "emit" => "sequence",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        (crate::value!([
+                                                                                            "emit" => "value_token_touch",
+                                                                                            "value" => "<="
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "identifier",
+                                                                                            "value" => "_"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "value_instance",
+                                                                                            "children" =>
+                                                                                                (crate::value!([
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "Expect"
+                                                                                                    ])),
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "instarg",
+                                                                                                        "children" =>
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "identifier",
+                                                                                                                "value" => "BitOr"
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "call",
+                                                                                            "children" =>
+                                                                                                (crate::value!([
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "ast"
+                                                                                                    ])),
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "callarg",
+                                                                                                        "children" =>
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "value_string",
+                                                                                                                "value" => "cmp_lteq"
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ]))
+                                                                                    ]))
                                                                             ])),
                                                                             (crate::value!([
-                                                                                "emit" => "identifier",
-                                                                                "value" => "_"
-                                                                            ]))
-                                                                        ]))
-                                                                ]))
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "call",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "ast"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "callarg",
-                                                                        "children" =>
+                                                                                "emit" => "sequence",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        (crate::value!([
+                                                                                            "emit" => "value_token_touch",
+                                                                                            "value" => ">="
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "identifier",
+                                                                                            "value" => "_"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "value_instance",
+                                                                                            "children" =>
+                                                                                                (crate::value!([
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "Expect"
+                                                                                                    ])),
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "instarg",
+                                                                                                        "children" =>
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "identifier",
+                                                                                                                "value" => "BitOr"
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "call",
+                                                                                            "children" =>
+                                                                                                (crate::value!([
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "ast"
+                                                                                                    ])),
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "callarg",
+                                                                                                        "children" =>
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "value_string",
+                                                                                                                "value" => "cmp_gteq"
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ])),
                                                                             (crate::value!([
-                                                                                "emit" => "value_string",
-                                                                                "value" => "list"
-                                                                            ]))
-                                                                    ]))
-                                                                ]))
-                                                        ]))
-                                                    ]))
-                                            ])),
-                                            (crate::value!([
-                                                "emit" => "sequence",
-                                                "children" =>
-                                                    (crate::value!([
-                                                        (crate::value!([
-                                                            "emit" => "op_mod_opt",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    "emit" => "identifier",
-                                                                    "value" => "Expression"
-                                                                ]))
-                                                        ])),
+                                                                                "emit" => "sequence",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        (crate::value!([
+                                                                                            "emit" => "value_token_touch",
+                                                                                            "value" => "<"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "value_instance",
+                                                                                            "children" =>
+                                                                                                (crate::value!([
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "Not"
+                                                                                                    ])),
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "instarg",
+                                                                                                        "children" =>
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "value_token_touch",
+                                                                                                                "value" => "<"
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "identifier",
+                                                                                            "value" => "_"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "value_instance",
+                                                                                            "children" =>
+                                                                                                (crate::value!([
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "Expect"
+                                                                                                    ])),
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "instarg",
+                                                                                                        "children" =>
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "identifier",
+                                                                                                                "value" => "BitOr"
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "call",
+                                                                                            "children" =>
+                                                                                                (crate::value!([
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "ast"
+                                                                                                    ])),
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "callarg",
+                                                                                                        "children" =>
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "value_string",
+                                                                                                                "value" => "cmp_lt"
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ])),
+                                                                            (crate::value!([
+                                                                                "emit" => "sequence",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        (crate::value!([
+                                                                                            "emit" => "value_token_touch",
+                                                                                            "value" => ">"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "value_instance",
+                                                                                            "children" =>
+                                                                                                (crate::value!([
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "Not"
+                                                                                                    ])),
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "instarg",
+                                                                                                        "children" =>
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "value_token_touch",
+                                                                                                                "value" => ">"
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "identifier",
+                                                                                            "value" => "_"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "value_instance",
+                                                                                            "children" =>
+                                                                                                (crate::value!([
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "Expect"
+                                                                                                    ])),
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "instarg",
+                                                                                                        "children" =>
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "identifier",
+                                                                                                                "value" => "BitOr"
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "call",
+                                                                                            "children" =>
+                                                                                                (crate::value!([
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "ast"
+                                                                                                    ])),
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "callarg",
+                                                                                                        "children" =>
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "value_string",
+                                                                                                                "value" => "cmp_gt"
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ])),
+                                                                            (crate::value!([
+                                                                                "emit" => "sequence",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        (crate::value!([
+                                                                                            "emit" => "value_instance",
+                                                                                            "children" =>
+                                                                                                (crate::value!([
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "Keyword"
+                                                                                                    ])),
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "instarg",
+                                                                                                        "children" =>
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "value_token_touch",
+                                                                                                                "value" => "in"
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "identifier",
+                                                                                            "value" => "_"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "value_instance",
+                                                                                            "children" =>
+                                                                                                (crate::value!([
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "Expect"
+                                                                                                    ])),
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "instarg",
+                                                                                                        "children" =>
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "identifier",
+                                                                                                                "value" => "BitOr"
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "call",
+                                                                                            "children" =>
+                                                                                                (crate::value!([
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "ast"
+                                                                                                    ])),
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "callarg",
+                                                                                                        "children" =>
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "value_string",
+                                                                                                                "value" => "cmp_in"
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ])),
+                                                                            (crate::value!([
+                                                                                "emit" => "sequence",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        (crate::value!([
+                                                                                            "emit" => "value_instance",
+                                                                                            "children" =>
+                                                                                                (crate::value!([
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "Keyword"
+                                                                                                    ])),
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "instarg",
+                                                                                                        "children" =>
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "value_token_touch",
+                                                                                                                "value" => "not"
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "identifier",
+                                                                                            "value" => "_"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "value_instance",
+                                                                                            "children" =>
+                                                                                                (crate::value!([
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "Keyword"
+                                                                                                    ])),
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "instarg",
+                                                                                                        "children" =>
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "value_token_touch",
+                                                                                                                "value" => "in"
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "identifier",
+                                                                                            "value" => "_"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "value_instance",
+                                                                                            "children" =>
+                                                                                                (crate::value!([
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "Expect"
+                                                                                                    ])),
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "instarg",
+                                                                                                        "children" =>
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "identifier",
+                                                                                                                "value" => "BitOr"
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "call",
+                                                                                            "children" =>
+                                                                                                (crate::value!([
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "ast"
+                                                                                                    ])),
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "callarg",
+                                                                                                        "children" =>
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "value_string",
+                                                                                                                "value" => "cmp_notin"
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                        ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "call",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "ast"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "callarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_string",
+                                                                                "value" => "comparison"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "identifier",
+                                                "value" => "BitOr"
+                                            ]))
+                                        ]))
+                                ]))
+                        ]))
+                    ]))
+            ])),
+            (crate::value!([
+                "emit" => "constant",
+                "children" =>
+                    (crate::value!([
+                        (crate::value!([
+                            "emit" => "identifier",
+                            "value" => "LogicalAnd"
+                        ])),
+                        (crate::value!([
+                            "emit" => "value_parselet",
+                            "children" =>
+                                (crate::value!([
+                                    "emit" => "body",
+                                    "children" =>
+                                        (crate::value!([
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "LogicalAnd"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_token_touch",
+                                                            "value" => "&&"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "_"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_instance",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Expect"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "identifier",
+                                                                                "value" => "Comparison"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "call",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "ast"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "callarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_string",
+                                                                                "value" => "op_logical_and"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "identifier",
+                                                "value" => "Comparison"
+                                            ]))
+                                        ]))
+                                ]))
+                        ]))
+                    ]))
+            ])),
+            (crate::value!([
+                "emit" => "constant",
+                "children" =>
+                    (crate::value!([
+                        (crate::value!([
+                            "emit" => "identifier",
+                            "value" => "LogicalOr"
+                        ])),
+                        (crate::value!([
+                            "emit" => "value_parselet",
+                            "children" =>
+                                (crate::value!([
+                                    "emit" => "body",
+                                    "children" =>
+                                        (crate::value!([
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "LogicalOr"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_token_touch",
+                                                            "value" => "||"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "_"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_instance",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Expect"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "identifier",
+                                                                                "value" => "LogicalAnd"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "call",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "ast"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "callarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_string",
+                                                                                "value" => "op_logical_or"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "identifier",
+                                                "value" => "LogicalAnd"
+                                            ]))
+                                        ]))
+                                ]))
+                        ]))
+                    ]))
+            ])),
+            (crate::value!([
+                "emit" => "constant",
+                "children" =>
+                    (crate::value!([
+                        (crate::value!([
+                            "emit" => "identifier",
+                            "value" => "Expression"
+                        ])),
+                        (crate::value!([
+                            "emit" => "identifier",
+                            "value" => "LogicalOr"
+                        ]))
+                    ]))
+            ])),
+            (crate::value!([
+                "emit" => "constant",
+                "children" =>
+                    (crate::value!([
+                        (crate::value!([
+                            "emit" => "identifier",
+                            "value" => "ExpressionList"
+                        ])),
+                        (crate::value!([
+                            "emit" => "value_parselet",
+                            "children" =>
+                                (crate::value!([
+                                    "emit" => "body",
+                                    "children" =>
+                                        (crate::value!([
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "Expression"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "op_mod_pos",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    "emit" => "sequence",
+                                                                    "children" =>
+                                                                        (crate::value!([
+                                                                            (crate::value!([
+                                                                                "emit" => "value_token_touch",
+                                                                                "value" => ","
+                                                                            ])),
+                                                                            (crate::value!([
+                                                                                "emit" => "identifier",
+                                                                                "value" => "_"
+                                                                            ])),
+                                                                            (crate::value!([
+                                                                                "emit" => "identifier",
+                                                                                "value" => "Expression"
+                                                                            ]))
+                                                                        ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "op_mod_opt",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    "emit" => "sequence",
+                                                                    "children" =>
+                                                                        (crate::value!([
+                                                                            (crate::value!([
+                                                                                "emit" => "value_token_touch",
+                                                                                "value" => ","
+                                                                            ])),
+                                                                            (crate::value!([
+                                                                                "emit" => "identifier",
+                                                                                "value" => "_"
+                                                                            ]))
+                                                                        ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "call",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "ast"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "callarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_string",
+                                                                                "value" => "list"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "op_mod_opt",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    "emit" => "identifier",
+                                                                    "value" => "Expression"
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => ","
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "_"
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "call",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "ast"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "callarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_string",
+                                                                                "value" => "list"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "identifier",
+                                                "value" => "Expression"
+                                            ]))
+                                        ]))
+                                ]))
+                        ]))
+                    ]))
+            ])),
+            (crate::value!([
+                "emit" => "constant",
+                "children" =>
+                    (crate::value!([
+                        (crate::value!([
+                            "emit" => "identifier",
+                            "value" => "Assignment"
+                        ])),
+                        (crate::value!([
+                            "emit" => "value_parselet",
+                            "children" =>
+                                (crate::value!([
+                                    (crate::value!([
+                                        "emit" => "gen",
+                                        "children" =>
+                                            (crate::value!([
+                                                "emit" => "identifier",
+                                                "value" => "Source"
+                                            ]))
+                                    ])),
+                                    (crate::value!([
+                                        "emit" => "sig",
+                                        "children" =>
+                                            (crate::value!([
+                                                (crate::value!([
+                                                    "emit" => "identifier",
+                                                    "value" => "mode"
+                                                ])),
+                                                (crate::value!([
+                                                    "emit" => "value_string",
+                                                    "value" => "hold"
+                                                ]))
+                                            ]))
+                                    ])),
+                                    (crate::value!([
+                                        "emit" => "body",
+                                        "children" =>
+                                            (crate::value!([
+                                                (crate::value!([
+                                                    "emit" => "sequence",
+                                                    "children" =>
+                                                        (crate::value!([
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "Lvalue"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "_"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "value_token_touch",
+                                                                "value" => "+="
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "_"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "value_instance",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "Expect"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "instarg",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Self"
+                                                                                ]))
+                                                                        ]))
+                                                                    ]))
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "call",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "ast"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "callarg",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "op_binary_add",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            (crate::value!([
+                                                                                                "emit" => "value_string",
+                                                                                                "value" => "assign_add_"
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "identifier",
+                                                                                                "value" => "mode"
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ]))
+                                                                        ]))
+                                                                    ]))
+                                                            ]))
+                                                        ]))
+                                                ])),
+                                                (crate::value!([
+                                                    "emit" => "sequence",
+                                                    "children" =>
+                                                        (crate::value!([
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "Lvalue"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "_"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "value_token_touch",
+                                                                "value" => "-="
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "_"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "value_instance",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "Expect"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "instarg",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Self"
+                                                                                ]))
+                                                                        ]))
+                                                                    ]))
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "call",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "ast"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "callarg",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "op_binary_add",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            (crate::value!([
+                                                                                                "emit" => "value_string",
+                                                                                                "value" => "assign_sub_"
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "identifier",
+                                                                                                "value" => "mode"
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ]))
+                                                                        ]))
+                                                                    ]))
+                                                            ]))
+                                                        ]))
+                                                ])),
+                                                (crate::value!([
+                                                    "emit" => "sequence",
+                                                    "children" =>
+                                                        (crate::value!([
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "Lvalue"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "_"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "value_token_touch",
+                                                                "value" => "*="
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "_"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "value_instance",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "Expect"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "instarg",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Self"
+                                                                                ]))
+                                                                        ]))
+                                                                    ]))
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "call",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "ast"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "callarg",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "op_binary_add",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            (crate::value!([
+                                                                                                "emit" => "value_string",
+                                                                                                "value" => "assign_mul_"
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "identifier",
+                                                                                                "value" => "mode"
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ]))
+                                                                        ]))
+                                                                    ]))
+                                                            ]))
+                                                        ]))
+                                                ])),
+                                                (crate::value!([
+                                                    "emit" => "sequence",
+                                                    "children" =>
+                                                        (crate::value!([
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "Lvalue"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "_"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "value_token_touch",
+                                                                "value" => "/="
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "_"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "value_instance",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "Expect"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "instarg",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Self"
+                                                                                ]))
+                                                                        ]))
+                                                                    ]))
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "call",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "ast"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "callarg",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "op_binary_add",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            (crate::value!([
+                                                                                                "emit" => "value_string",
+                                                                                                "value" => "assign_div_"
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "identifier",
+                                                                                                "value" => "mode"
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ]))
+                                                                        ]))
+                                                                    ]))
+                                                            ]))
+                                                        ]))
+                                                ])),
+                                                (crate::value!([
+                                                    "emit" => "sequence",
+                                                    "children" =>
+                                                        (crate::value!([
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "Lvalue"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "_"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "value_token_touch",
+                                                                "value" => "//="
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "_"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "value_instance",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "Expect"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "instarg",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Self"
+                                                                                ]))
+                                                                        ]))
+                                                                    ]))
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "call",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "ast"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "callarg",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "op_binary_add",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            (crate::value!([
+                                                                                                "emit" => "value_string",
+                                                                                                "value" => "assign_divi_"
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "identifier",
+                                                                                                "value" => "mode"
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ]))
+                                                                        ]))
+                                                                    ]))
+                                                            ]))
+                                                        ]))
+                                                ])),
+                                                (crate::value!([
+                                                    "emit" => "sequence",
+                                                    "children" =>
+                                                        (crate::value!([
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "Lvalue"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "_"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "value_token_touch",
+                                                                "value" => "%="
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "_"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "value_instance",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "Expect"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "instarg",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Self"
+                                                                                ]))
+                                                                        ]))
+                                                                    ]))
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "call",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "ast"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "callarg",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "op_binary_add",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            (crate::value!([
+                                                                                                "emit" => "value_string",
+                                                                                                "value" => "assign_mod_"
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "identifier",
+                                                                                                "value" => "mode"
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ]))
+                                                                        ]))
+                                                                    ]))
+                                                            ]))
+                                                        ]))
+                                                ])),
+                                                (crate::value!([
+                                                    "emit" => "sequence",
+                                                    "children" =>
+                                                        (crate::value!([
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "Lvalue"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "_"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "value_token_touch",
+                                                                "value" => "**="
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "_"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "value_instance",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "Expect"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "instarg",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Self"
+                                                                                ]))
+                                                                        ]))
+                                                                    ]))
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "call",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "ast"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "callarg",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "op_binary_add",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            (crate::value!([
+                                                                                                "emit" => "value_string",
+                                                                                                "value" => "assign_pow_"
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "identifier",
+                                                                                                "value" => "mode"
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ]))
+                                                                        ]))
+                                                                    ]))
+                                                            ]))
+                                                        ]))
+                                                ])),
+                                                (crate::value!([
+                                                    "emit" => "sequence",
+                                                    "children" =>
+                                                        (crate::value!([
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "Lvalue"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "_"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "value_token_touch",
+                                                                "value" => "<<="
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "_"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "value_instance",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "Expect"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "instarg",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Self"
+                                                                                ]))
+                                                                        ]))
+                                                                    ]))
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "call",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "ast"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "callarg",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "op_binary_add",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            (crate::value!([
+                                                                                                "emit" => "value_string",
+                                                                                                "value" => "assign_shl_"
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "identifier",
+                                                                                                "value" => "mode"
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ]))
+                                                                        ]))
+                                                                    ]))
+                                                            ]))
+                                                        ]))
+                                                ])),
+                                                (crate::value!([
+                                                    "emit" => "sequence",
+                                                    "children" =>
+                                                        (crate::value!([
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "Lvalue"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "_"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "value_token_touch",
+                                                                "value" => ">>="
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "_"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "value_instance",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "Expect"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "instarg",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Self"
+                                                                                ]))
+                                                                        ]))
+                                                                    ]))
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "call",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "ast"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "callarg",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "op_binary_add",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            (crate::value!([
+                                                                                                "emit" => "value_string",
+                                                                                                "value" => "assign_shr_"
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "identifier",
+                                                                                                "value" => "mode"
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ]))
+                                                                        ]))
+                                                                    ]))
+                                                            ]))
+                                                        ]))
+                                                ])),
+                                                (crate::value!([
+                                                    "emit" => "sequence",
+                                                    "children" =>
+                                                        (crate::value!([
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "Lvalue"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "_"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "value_token_touch",
+                                                                "value" => "&="
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "_"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "value_instance",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "Expect"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "instarg",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Self"
+                                                                                ]))
+                                                                        ]))
+                                                                    ]))
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "call",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "ast"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "callarg",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "op_binary_add",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            (crate::value!([
+                                                                                                "emit" => "value_string",
+                                                                                                "value" => "assign_bitand_"
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "identifier",
+                                                                                                "value" => "mode"
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ]))
+                                                                        ]))
+                                                                    ]))
+                                                            ]))
+                                                        ]))
+                                                ])),
+                                                (crate::value!([
+                                                    "emit" => "sequence",
+                                                    "children" =>
+                                                        (crate::value!([
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "Lvalue"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "_"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "value_token_touch",
+                                                                "value" => "|="
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "_"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "value_instance",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "Expect"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "instarg",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Self"
+                                                                                ]))
+                                                                        ]))
+                                                                    ]))
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "call",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "ast"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "callarg",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "op_binary_add",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            (crate::value!([
+                                                                                                "emit" => "value_string",
+                                                                                                "value" => "assign_bitor_"
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "identifier",
+                                                                                                "value" => "mode"
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ]))
+                                                                        ]))
+                                                                    ]))
+                                                            ]))
+                                                        ]))
+                                                ])),
+                                                (crate::value!([
+                                                    "emit" => "sequence",
+                                                    "children" =>
+                                                        (crate::value!([
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "Lvalue"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "_"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "value_token_touch",
+                                                                "value" => "^="
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "_"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "value_instance",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "Expect"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "instarg",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Self"
+                                                                                ]))
+                                                                        ]))
+                                                                    ]))
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "call",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "ast"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "callarg",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "op_binary_add",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            (crate::value!([
+                                                                                                "emit" => "value_string",
+                                                                                                "value" => "assign_bitxor_"
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "identifier",
+                                                                                                "value" => "mode"
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ]))
+                                                                        ]))
+                                                                    ]))
+                                                            ]))
+                                                        ]))
+                                                ])),
+                                                (crate::value!([
+                                                    "emit" => "sequence",
+                                                    "children" =>
+                                                        (crate::value!([
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "Lvalue"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "_"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "value_token_touch",
+                                                                "value" => "="
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "value_instance",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "Not"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "instarg",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "value_token_ccl",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            "emit" => "ccl",
+                                                                                            "children" =>
+                                                                                                (crate::value!([
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "char",
+                                                                                                        "value" => ">"
+                                                                                                    ])),
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "char",
+                                                                                                        "value" => "="
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ]))
+                                                                                ]))
+                                                                        ]))
+                                                                    ]))
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "_"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "value_instance",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "Expect"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "instarg",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Self"
+                                                                                ]))
+                                                                        ]))
+                                                                    ]))
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "call",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "ast"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "callarg",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "op_binary_add",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            (crate::value!([
+                                                                                                "emit" => "value_string",
+                                                                                                "value" => "assign_"
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "identifier",
+                                                                                                "value" => "mode"
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ]))
+                                                                        ]))
+                                                                    ]))
+                                                            ]))
+                                                        ]))
+                                                ])),
+                                                (crate::value!([
+                                                    "emit" => "identifier",
+                                                    "value" => "Source"
+                                                ]))
+                                            ]))
+                                    ]))
+                                ]))
+                        ]))
+                    ]))
+            ])),
+            (crate::value!([
+                "emit" => "constant",
+                "children" =>
+                    (crate::value!([
+                        (crate::value!([
+                            "emit" => "identifier",
+                            "value" => "Statement"
+                        ])),
+                        (crate::value!([
+                            "emit" => "value_parselet",
+                            "children" =>
+                                (crate::value!([
+                                    "emit" => "body",
+                                    "children" =>
+                                        (crate::value!([
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "value_instance",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Keyword"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_token_touch",
+                                                                                "value" => "accept"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "_"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "op_mod_opt",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    "emit" => "identifier",
+                                                                    "value" => "Expression"
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "call",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "ast"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "callarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_string",
+                                                                                "value" => "op_accept"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "value_instance",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Keyword"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_token_touch",
+                                                                                "value" => "break"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "_"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "op_mod_opt",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    "emit" => "identifier",
+                                                                    "value" => "Expression"
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "call",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "ast"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "callarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_string",
+                                                                                "value" => "op_break"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "value_instance",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Keyword"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_token_touch",
+                                                                                "value" => "continue"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "_"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "op_mod_opt",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    "emit" => "identifier",
+                                                                    "value" => "Expression"
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "call",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "ast"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "callarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_string",
+                                                                                "value" => "op_continue"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "value_instance",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Keyword"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_token_touch",
+                                                                                "value" => "exit"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "_"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "op_mod_opt",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    "emit" => "identifier",
+                                                                    "value" => "Expression"
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "call",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "ast"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "callarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_string",
+                                                                                "value" => "op_exit"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "value_instance",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Keyword"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_token_touch",
+                                                                                "value" => "next"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "_"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "call",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "ast"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "callarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_string",
+                                                                                "value" => "op_next"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "value_instance",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Keyword"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_token_touch",
+                                                                                "value" => "push"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "_"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "op_mod_opt",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    "emit" => "identifier",
+                                                                    "value" => "Expression"
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "call",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "ast"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "callarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_string",
+                                                                                "value" => "op_push"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "value_instance",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Keyword"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_token_touch",
+                                                                                "value" => "reject"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
                                                         (crate::value!([
-                                                            "emit" => "sequence",
+                                                            "emit" => "identifier",
+                                                            "value" => "_"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "call",
                                                             "children" =>
                                                                 (crate::value!([
                                                                     (crate::value!([
-                                                                        "emit" => "value_token_touch",
-                                                                        "value" => ","
+                                                                        "emit" => "identifier",
+                                                                        "value" => "ast"
                                                                     ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "callarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_string",
+                                                                                "value" => "op_reject"
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ]))
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "value_instance",
+                                                            "children" =>
+                                                                (crate::value!([
                                                                     (crate::value!([
                                                                         "emit" => "identifier",
-                                                                        "value" => "_"
+                                                                        "value" => "Keyword"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_token_touch",
+                                                                                "value" => "repeat"
+                                                                            ]))
                                                                     ]))
                                                                 ]))
                                                         ])),
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "_"
+                                                        ])),
                                                         (crate::value!([
                                                             "emit" => "call",
                                                             "children" =>
@@ -6771,7 +10382,7 @@ crate::value!([
                                                                         "children" =>
                                                                             (crate::value!([
                                                                                 "emit" => "value_string",
-                                                                                "value" => "list"
+                                                                                "value" => "op_repeat"
                                                                             ]))
                                                                     ]))
                                                                 ]))
@@ -6779,429 +10390,191 @@ crate::value!([
                                                     ]))
                                             ])),
                                             (crate::value!([
-                                                "emit" => "identifier",
-                                                "value" => "Expression"
-                                            ]))
-                                        ]))
-                                ]))
-                        ]))
-                    ]))
-            ])),
-            (crate::value!([
-                "emit" => "constant",
-                "children" =>
-                    (crate::value!([
-                        (crate::value!([
-                            "emit" => "identifier",
-                            "value" => "Assignment"
-                        ])),
-                        (crate::value!([
-                            "emit" => "value_parselet",
-                            "children" =>
-                                (crate::value!([
-                                    (crate::value!([
-                                        "emit" => "gen",
-                                        "children" =>
-                                            (crate::value!([
-                                                "emit" => "identifier",
-                                                "value" => "Source"
-                                            ]))
-                                    ])),
-                                    (crate::value!([
-                                        "emit" => "sig",
-                                        "children" =>
-                                            (crate::value!([
-                                                (crate::value!([
-                                                    "emit" => "identifier",
-                                                    "value" => "mode"
-                                                ])),
-                                                (crate::value!([
-                                                    "emit" => "value_string",
-                                                    "value" => "hold"
-                                                ]))
-                                            ]))
-                                    ])),
-                                    (crate::value!([
-                                        "emit" => "body",
-                                        "children" =>
-                                            (crate::value!([
-                                                (crate::value!([
-                                                    "emit" => "sequence",
-                                                    "children" =>
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
                                                         (crate::value!([
-                                                            (crate::value!([
-                                                                "emit" => "identifier",
-                                                                "value" => "Lvalue"
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "identifier",
-                                                                "value" => "_"
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "value_token_touch",
-                                                                "value" => "+="
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "identifier",
-                                                                "value" => "_"
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "value_instance",
-                                                                "children" =>
+                                                            "emit" => "value_instance",
+                                                            "children" =>
+                                                                (crate::value!([
                                                                     (crate::value!([
-                                                                        (crate::value!([
-                                                                            "emit" => "identifier",
-                                                                            "value" => "Expect"
-                                                                        ])),
-                                                                        (crate::value!([
-                                                                            "emit" => "instarg",
-                                                                            "children" =>
-                                                                                (crate::value!([
-                                                                                    "emit" => "identifier",
-                                                                                    "value" => "Self"
-                                                                                ]))
-                                                                        ]))
-                                                                    ]))
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "call",
-                                                                "children" =>
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Keyword"
+                                                                    ])),
                                                                     (crate::value!([
-                                                                        (crate::value!([
-                                                                            "emit" => "identifier",
-                                                                            "value" => "ast"
-                                                                        ])),
-                                                                        (crate::value!([
-                                                                            "emit" => "callarg",
-                                                                            "children" =>
-                                                                                (crate::value!([
-                                                                                    "emit" => "op_binary_add",
-                                                                                    "children" =>
-                                                                                        (crate::value!([
-                                                                                            (crate::value!([
-                                                                                                "emit" => "value_string",
-                                                                                                "value" => "assign_add_"
-                                                                                            ])),
-                                                                                            (crate::value!([
-                                                                                                "emit" => "identifier",
-                                                                                                "value" => "mode"
-                                                                                            ]))
-                                                                                        ]))
-                                                                                ]))
-                                                                        ]))
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_token_touch",
+                                                                                "value" => "reset"
+                                                                            ]))
                                                                     ]))
-                                                            ]))
-                                                        ]))
-                                                ])),
-                                                (crate::value!([
-                                                    "emit" => "sequence",
-                                                    "children" =>
+                                                                ]))
+                                                        ])),
                                                         (crate::value!([
-                                                            (crate::value!([
-                                                                "emit" => "identifier",
-                                                                "value" => "Lvalue"
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "identifier",
-                                                                "value" => "_"
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "value_token_touch",
-                                                                "value" => "-="
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "identifier",
-                                                                "value" => "_"
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "value_instance",
-                                                                "children" =>
+                                                            "emit" => "identifier",
+                                                            "value" => "_"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "call",
+                                                            "children" =>
+                                                                (crate::value!([
                                                                     (crate::value!([
-                                                                        (crate::value!([
-                                                                            "emit" => "identifier",
-                                                                            "value" => "Expect"
-                                                                        ])),
-                                                                        (crate::value!([
-                                                                            "emit" => "instarg",
-                                                                            "children" =>
-                                                                                (crate::value!([
-                                                                                    "emit" => "identifier",
-                                                                                    "value" => "Self"
-                                                                                ]))
-                                                                        ]))
-                                                                    ]))
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "call",
-                                                                "children" =>
+                                                                        "emit" => "identifier",
+                                                                        "value" => "ast"
+                                                                    ])),
                                                                     (crate::value!([
-                                                                        (crate::value!([
-                                                                            "emit" => "identifier",
-                                                                            "value" => "ast"
-                                                                        ])),
-                                                                        (crate::value!([
-                                                                            "emit" => "callarg",
-                                                                            "children" =>
-                                                                                (crate::value!([
-                                                                                    "emit" => "op_binary_add",
-                                                                                    "children" =>
-                                                                                        (crate::value!([
-                                                                                            (crate::value!([
-                                                                                                "emit" => "value_string",
-                                                                                                "value" => "assign_sub_"
-                                                                                            ])),
-                                                                                            (crate::value!([
-                                                                                                "emit" => "identifier",
-                                                                                                "value" => "mode"
-                                                                                            ]))
-                                                                                        ]))
-                                                                                ]))
-                                                                        ]))
+                                                                        "emit" => "callarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_string",
+                                                                                "value" => "op_reset"
+                                                                            ]))
                                                                     ]))
-                                                            ]))
+                                                                ]))
                                                         ]))
-                                                ])),
-                                                (crate::value!([
-                                                    "emit" => "sequence",
-                                                    "children" =>
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "sequence",
+                                                "children" =>
+                                                    (crate::value!([
                                                         (crate::value!([
-                                                            (crate::value!([
-                                                                "emit" => "identifier",
-                                                                "value" => "Lvalue"
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "identifier",
-                                                                "value" => "_"
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "value_token_touch",
-                                                                "value" => "*="
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "identifier",
-                                                                "value" => "_"
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "value_instance",
-                                                                "children" =>
+                                                            "emit" => "value_instance",
+                                                            "children" =>
+                                                                (crate::value!([
                                                                     (crate::value!([
-                                                                        (crate::value!([
-                                                                            "emit" => "identifier",
-                                                                            "value" => "Expect"
-                                                                        ])),
-                                                                        (crate::value!([
-                                                                            "emit" => "instarg",
-                                                                            "children" =>
-                                                                                (crate::value!([
-                                                                                    "emit" => "identifier",
-                                                                                    "value" => "Self"
-                                                                                ]))
-                                                                        ]))
-                                                                    ]))
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "call",
-                                                                "children" =>
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Keyword"
+                                                                    ])),
                                                                     (crate::value!([
-                                                                        (crate::value!([
-                                                                            "emit" => "identifier",
-                                                                            "value" => "ast"
-                                                                        ])),
-                                                                        (crate::value!([
-                                                                            "emit" => "callarg",
-                                                                            "children" =>
-                                                                                (crate::value!([
-                                                                                    "emit" => "op_binary_add",
-                                                                                    "children" =>
-                                                                                        (crate::value!([
-                                                                                            (crate::value!([
-                                                                                                "emit" => "value_string",
-                                                                                                "value" => "assign_mul_"
-                                                                                            ])),
-                                                                                            (crate::value!([
-                                                                                                "emit" => "identifier",
-                                                                                                "value" => "mode"
-                                                                                            ]))
-                                                                                        ]))
-                                                                                ]))
-                                                                        ]))
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_token_touch",
+                                                                                "value" => "return"
+                                                                            ]))
                                                                     ]))
-                                                            ]))
-                                                        ]))
-                                                ])),
-                                                (crate::value!([
-                                                    "emit" => "sequence",
-                                                    "children" =>
+                                                                ]))
+                                                        ])),
                                                         (crate::value!([
-                                                            (crate::value!([
-                                                                "emit" => "identifier",
-                                                                "value" => "Lvalue"
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "identifier",
-                                                                "value" => "_"
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "value_token_touch",
-                                                                "value" => "/="
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "identifier",
-                                                                "value" => "_"
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "value_instance",
-                                                                "children" =>
+                                                            "emit" => "identifier",
+                                                            "value" => "_"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "op_mod_opt",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    "emit" => "identifier",
+                                                                    "value" => "Expression"
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "call",
+                                                            "children" =>
+                                                                (crate::value!([
                                                                     (crate::value!([
-                                                                        (crate::value!([
-                                                                            "emit" => "identifier",
-                                                                            "value" => "Expect"
-                                                                        ])),
-                                                                        (crate::value!([
-                                                                            "emit" => "instarg",
-                                                                            "children" =>
-                                                                                (crate::value!([
-                                                                                    "emit" => "identifier",
-                                                                                    "value" => "Self"
-                                                                                ]))
-                                                                        ]))
-                                                                    ]))
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "call",
-                                                                "children" =>
+                                                                        "emit" => "identifier",
+                                                                        "value" => "ast"
+                                                                    ])),
                                                                     (crate::value!([
-                                                                        (crate::value!([
-                                                                            "emit" => "identifier",
-                                                                            "value" => "ast"
-                                                                        ])),
-                                                                        (crate::value!([
-                                                                            "emit" => "callarg",
-                                                                            "children" =>
-                                                                                (crate::value!([
-                                                                                    "emit" => "op_binary_add",
-                                                                                    "children" =>
-                                                                                        (crate::value!([
-                                                                                            (crate::value!([
-                                                                                                "emit" => "value_string",
-                                                                                                "value" => "assign_div_"
-                                                                                            ])),
-                                                                                            (crate::value!([
-                                                                                                "emit" => "identifier",
-                                                                                                "value" => "mode"
-                                                                                            ]))
-                                                                                        ]))
-                                                                                ]))
-                                                                        ]))
+                                                                        "emit" => "callarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_string",
+                                                                                "value" => "op_accept"
+                                                                            ]))
                                                                     ]))
-                                                            ]))
+                                                                ]))
                                                         ]))
-                                                ])),
-                                                (crate::value!([
-                                                    "emit" => "sequence",
-                                                    "children" =>
+                                                    ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "call",
+                                                "children" =>
+                                                    (crate::value!([
                                                         (crate::value!([
-                                                            (crate::value!([
-                                                                "emit" => "identifier",
-                                                                "value" => "Lvalue"
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "identifier",
-                                                                "value" => "_"
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "value_token_touch",
-                                                                "value" => "//="
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "identifier",
-                                                                "value" => "_"
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "value_instance",
-                                                                "children" =>
+                                                            "emit" => "value_instance",
+                                                            "children" =>
+                                                                (crate::value!([
                                                                     (crate::value!([
-                                                                        (crate::value!([
-                                                                            "emit" => "identifier",
-                                                                            "value" => "Expect"
-                                                                        ])),
-                                                                        (crate::value!([
-                                                                            "emit" => "instarg",
-                                                                            "children" =>
-                                                                                (crate::value!([
-                                                                                    "emit" => "identifier",
-                                                                                    "value" => "Self"
-                                                                                ]))
-                                                                        ]))
-                                                                    ]))
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "call",
-                                                                "children" =>
+                                                                        "emit" => "identifier",
+                                                                        "value" => "Assignment"
+                                                                    ])),
                                                                     (crate::value!([
-                                                                        (crate::value!([
-                                                                            "emit" => "identifier",
-                                                                            "value" => "ast"
-                                                                        ])),
-                                                                        (crate::value!([
-                                                                            "emit" => "callarg",
-                                                                            "children" =>
-                                                                                (crate::value!([
-                                                                                    "emit" => "op_binary_add",
-                                                                                    "children" =>
-                                                                                        (crate::value!([
-                                                                                            (crate::value!([
-                                                                                                "emit" => "value_string",
-                                                                                                "value" => "assign_divi_"
-                                                                                            ])),
-                                                                                            (crate::value!([
-                                                                                                "emit" => "identifier",
-                                                                                                "value" => "mode"
-                                                                                            ]))
-                                                                                        ]))
-                                                                                ]))
-                                                                        ]))
+                                                                        "emit" => "instarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "identifier",
+                                                                                "value" => "ExpressionList"
+                                                                            ]))
                                                                     ]))
-                                                            ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "callarg",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    "emit" => "value_string",
+                                                                    "value" => "drop"
+                                                                ]))
                                                         ]))
+                                                    ]))
+                                            ]))
+                                        ]))
+                                ]))
+                        ]))
+                    ]))
+            ])),
+            (crate::value!([
+                "emit" => "constant",
+                "children" =>
+                    (crate::value!([
+                        (crate::value!([
+                            "emit" => "identifier",
+                            "value" => "Block"
+                        ])),
+                        (crate::value!([
+                            "emit" => "value_parselet",
+                            "children" =>
+                                (crate::value!([
+                                    (crate::value!([
+                                        "emit" => "sig",
+                                        "children" =>
+                                            (crate::value!([
+                                                (crate::value!([
+                                                    "emit" => "identifier",
+                                                    "value" => "emit"
                                                 ])),
+                                                (crate::value!([
+                                                    "emit" => "value_string",
+                                                    "value" => "block"
+                                                ]))
+                                            ]))
+                                    ])),
+                                    (crate::value!([
+                                        "emit" => "body",
+                                        "children" =>
+                                            (crate::value!([
                                                 (crate::value!([
                                                     "emit" => "sequence",
                                                     "children" =>
                                                         (crate::value!([
                                                             (crate::value!([
-                                                                "emit" => "identifier",
-                                                                "value" => "Lvalue"
+                                                                "emit" => "value_token_touch",
+                                                                "value" => "{"
                                                             ])),
                                                             (crate::value!([
                                                                 "emit" => "identifier",
                                                                 "value" => "_"
                                                             ])),
-                                                            (crate::value!([
-                                                                "emit" => "value_token_touch",
-                                                                "value" => "%="
-                                                            ])),
                                                             (crate::value!([
                                                                 "emit" => "identifier",
-                                                                "value" => "_"
+                                                                "value" => "___"
                                                             ])),
                                                             (crate::value!([
-                                                                "emit" => "value_instance",
-                                                                "children" =>
-                                                                    (crate::value!([
-                                                                        (crate::value!([
-                                                                            "emit" => "identifier",
-                                                                            "value" => "Expect"
-                                                                        ])),
-                                                                        (crate::value!([
-                                                                            "emit" => "instarg",
-                                                                            "children" =>
-                                                                                (crate::value!([
-                                                                                    "emit" => "identifier",
-                                                                                    "value" => "Self"
-                                                                                ]))
-                                                                        ]))
-                                                                    ]))
+                                                                "emit" => "value_token_touch",
+                                                                "value" => "}"
                                                             ])),
                                                             (crate::value!([
                                                                 "emit" => "call",
@@ -7215,18 +10588,8 @@ crate::value!([
                                                                             "emit" => "callarg",
                                                                             "children" =>
                                                                                 (crate::value!([
-                                                                                    "emit" => "op_binary_add",
-                                                                                    "children" =>
-                                                                                        (crate::value!([
-                                                                                            (crate::value!([
-                                                                                                "emit" => "value_string",
-                                                                                                "value" => "assign_mod_"
-                                                                                            ])),
-                                                                                            (crate::value!([
-                                                                                                "emit" => "identifier",
-                                                                                                "value" => "mode"
-                                                                                            ]))
-                                                                                        ]))
+                                                                                    "emit" => "value_string",
+                                                                                    "value" => "value_void"
                                                                                 ]))
                                                                         ]))
                                                                     ]))
@@ -7238,47 +10601,33 @@ crate::value!([
                                                     "children" =>
                                                         (crate::value!([
                                                             (crate::value!([
-                                                                "emit" => "identifier",
-                                                                "value" => "Lvalue"
+                                                                "emit" => "value_token_touch",
+                                                                "value" => "{"
                                                             ])),
                                                             (crate::value!([
                                                                 "emit" => "identifier",
                                                                 "value" => "_"
                                                             ])),
                                                             (crate::value!([
-                                                                "emit" => "value_token_touch",
-                                                                "value" => "="
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "value_instance",
+                                                                "emit" => "op_mod_kle",
                                                                 "children" =>
                                                                     (crate::value!([
-                                                                        (crate::value!([
-                                                                            "emit" => "identifier",
-                                                                            "value" => "Not"
-                                                                        ])),
-                                                                        (crate::value!([
-                                                                            "emit" => "instarg",
-                                                                            "children" =>
+                                                                        "emit" => "value_instance",
+                                                                        "children" =>
+                                                                            (crate::value!([
                                                                                 (crate::value!([
-                                                                                    "emit" => "value_token_ccl",
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "Tokay"
+                                                                                ])),
+                                                                                (crate::value!([
+                                                                                    "emit" => "instarg",
                                                                                     "children" =>
                                                                                         (crate::value!([
-                                                                                            "emit" => "ccl",
-                                                                                            "children" =>
-                                                                                                (crate::value!([
-                                                                                                    (crate::value!([
-                                                                                                        "emit" => "char",
-                                                                                                        "value" => ">"
-                                                                                                    ])),
-                                                                                                    (crate::value!([
-                                                                                                        "emit" => "char",
-                                                                                                        "value" => "="
-                                                                                                    ]))
-                                                                                                ]))
+                                                                                            "emit" => "value_token_touch",
+                                                                                            "value" => "}"
                                                                                         ]))
                                                                                 ]))
-                                                                        ]))
+                                                                            ]))
                                                                     ]))
                                                             ])),
                                                             (crate::value!([
@@ -7297,8 +10646,8 @@ crate::value!([
                                                                             "emit" => "instarg",
                                                                             "children" =>
                                                                                 (crate::value!([
-                                                                                    "emit" => "identifier",
-                                                                                    "value" => "Self"
+                                                                                    "emit" => "value_token_touch",
+                                                                                    "value" => "}"
                                                                                 ]))
                                                                         ]))
                                                                     ]))
@@ -7315,27 +10664,13 @@ crate::value!([
                                                                             "emit" => "callarg",
                                                                             "children" =>
                                                                                 (crate::value!([
-                                                                                    "emit" => "op_binary_add",
-                                                                                    "children" =>
-                                                                                        (crate::value!([
-                                                                                            (crate::value!([
-                                                                                                "emit" => "value_string",
-                                                                                                "value" => "assign_"
-                                                                                            ])),
-                                                                                            (crate::value!([
-                                                                                                "emit" => "identifier",
-                                                                                                "value" => "mode"
-                                                                                            ]))
-                                                                                        ]))
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "emit"
                                                                                 ]))
                                                                         ]))
                                                                     ]))
                                                             ]))
                                                         ]))
-                                                ])),
-                                                (crate::value!([
-                                                    "emit" => "identifier",
-                                                    "value" => "Source"
                                                 ]))
                                             ]))
                                     ]))
@@ -7349,7 +10684,7 @@ crate::value!([
                     (crate::value!([
                         (crate::value!([
                             "emit" => "identifier",
-                            "value" => "Statement"
+                            "value" => "SequenceItem"
                         ])),
                         (crate::value!([
                             "emit" => "value_parselet",
@@ -7363,197 +10698,39 @@ crate::value!([
                                                 "children" =>
                                                     (crate::value!([
                                                         (crate::value!([
-                                                            "emit" => "value_instance",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "Keyword"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "instarg",
-                                                                        "children" =>
-                                                                            (crate::value!([
-                                                                                "emit" => "value_token_touch",
-                                                                                "value" => "accept"
-                                                                            ]))
-                                                                    ]))
-                                                                ]))
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "_"
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "op_mod_opt",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    "emit" => "identifier",
-                                                                    "value" => "Expression"
-                                                                ]))
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "call",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "ast"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "callarg",
-                                                                        "children" =>
-                                                                            (crate::value!([
-                                                                                "emit" => "value_string",
-                                                                                "value" => "op_accept"
-                                                                            ]))
-                                                                    ]))
-                                                                ]))
-                                                        ]))
-                                                    ]))
-                                            ])),
-                                            (crate::value!([
-                                                "emit" => "sequence",
-                                                "children" =>
-                                                    (crate::value!([
-                                                        (crate::value!([
-                                                            "emit" => "value_instance",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "Keyword"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "instarg",
-                                                                        "children" =>
-                                                                            (crate::value!([
-                                                                                "emit" => "value_token_touch",
-                                                                                "value" => "break"
-                                                                            ]))
-                                                                    ]))
-                                                                ]))
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "_"
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "op_mod_opt",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    "emit" => "identifier",
-                                                                    "value" => "Expression"
-                                                                ]))
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "call",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "ast"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "callarg",
-                                                                        "children" =>
-                                                                            (crate::value!([
-                                                                                "emit" => "value_string",
-                                                                                "value" => "op_break"
-                                                                            ]))
-                                                                    ]))
-                                                                ]))
-                                                        ]))
-                                                    ]))
-                                            ])),
-                                            (crate::value!([
-                                                "emit" => "sequence",
-                                                "children" =>
-                                                    (crate::value!([
-                                                        (crate::value!([
-                                                            "emit" => "value_instance",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "Keyword"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "instarg",
-                                                                        "children" =>
-                                                                            (crate::value!([
-                                                                                "emit" => "value_token_touch",
-                                                                                "value" => "continue"
-                                                                            ]))
-                                                                    ]))
-                                                                ]))
+                                                            "emit" => "identifier",
+                                                            "value" => "T_Alias"
                                                         ])),
                                                         (crate::value!([
                                                             "emit" => "identifier",
                                                             "value" => "_"
                                                         ])),
                                                         (crate::value!([
-                                                            "emit" => "op_mod_opt",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    "emit" => "identifier",
-                                                                    "value" => "Expression"
-                                                                ]))
+                                                            "emit" => "value_token_touch",
+                                                            "value" => "=>"
                                                         ])),
                                                         (crate::value!([
-                                                            "emit" => "call",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "ast"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "callarg",
-                                                                        "children" =>
-                                                                            (crate::value!([
-                                                                                "emit" => "value_string",
-                                                                                "value" => "op_continue"
-                                                                            ]))
-                                                                    ]))
-                                                                ]))
-                                                        ]))
-                                                    ]))
-                                            ])),
-                                            (crate::value!([
-                                                "emit" => "sequence",
-                                                "children" =>
-                                                    (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "_"
+                                                        ])),
                                                         (crate::value!([
                                                             "emit" => "value_instance",
                                                             "children" =>
                                                                 (crate::value!([
                                                                     (crate::value!([
                                                                         "emit" => "identifier",
-                                                                        "value" => "Keyword"
+                                                                        "value" => "Expect"
                                                                     ])),
                                                                     (crate::value!([
                                                                         "emit" => "instarg",
                                                                         "children" =>
                                                                             (crate::value!([
-                                                                                "emit" => "value_token_touch",
-                                                                                "value" => "exit"
+                                                                                "emit" => "identifier",
+                                                                                "value" => "ExpressionList"
                                                                             ]))
                                                                     ]))
                                                                 ]))
                                                         ])),
-                                                        (crate::value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "_"
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "op_mod_opt",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    "emit" => "identifier",
-                                                                    "value" => "Expression"
-                                                                ]))
-                                                        ])),
                                                         (crate::value!([
                                                             "emit" => "call",
                                                             "children" =>
@@ -7567,7 +10744,7 @@ crate::value!([
                                                                         "children" =>
                                                                             (crate::value!([
                                                                                 "emit" => "value_string",
-                                                                                "value" => "op_exit"
+                                                                                "value" => "alias"
                                                                             ]))
                                                                     ]))
                                                                 ]))
@@ -7579,81 +10756,35 @@ crate::value!([
                                                 "children" =>
                                                     (crate::value!([
                                                         (crate::value!([
-                                                            "emit" => "value_instance",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "Keyword"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "instarg",
-                                                                        "children" =>
-                                                                            (crate::value!([
-                                                                                "emit" => "value_token_touch",
-                                                                                "value" => "next"
-                                                                            ]))
-                                                                    ]))
-                                                                ]))
+                                                            "emit" => "identifier",
+                                                            "value" => "Expression"
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_token_touch",
+                                                            "value" => "=>"
                                                         ])),
                                                         (crate::value!([
                                                             "emit" => "identifier",
                                                             "value" => "_"
                                                         ])),
-                                                        (crate::value!([
-                                                            "emit" => "call",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "ast"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "callarg",
-                                                                        "children" =>
-                                                                            (crate::value!([
-                                                                                "emit" => "value_string",
-                                                                                "value" => "op_next"
-                                                                            ]))
-                                                                    ]))
-                                                                ]))
-                                                        ]))
-                                                    ]))
-                                            ])),
-                                            (crate::value!([
-                                                "emit" => "sequence",
-                                                "children" =>
-                                                    (crate::value!([
                                                         (crate::value!([
                                                             "emit" => "value_instance",
                                                             "children" =>
                                                                 (crate::value!([
                                                                     (crate::value!([
                                                                         "emit" => "identifier",
-                                                                        "value" => "Keyword"
+                                                                        "value" => "Expect"
                                                                     ])),
                                                                     (crate::value!([
                                                                         "emit" => "instarg",
                                                                         "children" =>
                                                                             (crate::value!([
-                                                                                "emit" => "value_token_touch",
-                                                                                "value" => "push"
+                                                                                "emit" => "identifier",
+                                                                                "value" => "ExpressionList"
                                                                             ]))
                                                                     ]))
                                                                 ]))
                                                         ])),
-                                                        (crate::value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "_"
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "op_mod_opt",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    "emit" => "identifier",
-                                                                    "value" => "Expression"
-                                                                ]))
-                                                        ])),
                                                         (crate::value!([
                                                             "emit" => "call",
                                                             "children" =>
@@ -7667,7 +10798,7 @@ crate::value!([
                                                                         "children" =>
                                                                             (crate::value!([
                                                                                 "emit" => "value_string",
-                                                                                "value" => "op_push"
+                                                                                "value" => "alias"
                                                                             ]))
                                                                     ]))
                                                                 ]))
@@ -7678,225 +10809,356 @@ crate::value!([
                                                 "emit" => "sequence",
                                                 "children" =>
                                                     (crate::value!([
-                                                        (crate::value!([
-                                                            "emit" => "value_instance",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "Keyword"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "instarg",
-                                                                        "children" =>
-                                                                            (crate::value!([
-                                                                                "emit" => "value_token_touch",
-                                                                                "value" => "reject"
-                                                                            ]))
-                                                                    ]))
-                                                                ]))
-                                                        ])),
                                                         (crate::value!([
                                                             "emit" => "identifier",
-                                                            "value" => "_"
+                                                            "value" => "Statement"
                                                         ])),
                                                         (crate::value!([
-                                                            "emit" => "call",
+                                                            "emit" => "op_if",
                                                             "children" =>
                                                                 (crate::value!([
                                                                     (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "ast"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "callarg",
+                                                                        "emit" => "comparison",
                                                                         "children" =>
                                                                             (crate::value!([
-                                                                                "emit" => "value_string",
-                                                                                "value" => "op_reject"
+                                                                                (crate::value!([
+                                                                                    "emit" => "rvalue",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            (crate::value!([
+                                                                                                "emit" => "capture_index",
+                                                                                                "children" =>
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "value_integer",
+                                                                                                        "value" => 1
+                                                                                                    ]))
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "item",
+                                                                                                "children" =>
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "value_string",
+                                                                                                        "value" => "emit"
+                                                                                                    ]))
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ])),
+                                                                                (crate::value!([
+                                                                                    "emit" => "cmp_eq",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            "emit" => "value_string",
+                                                                                            "value" => "op_binary_bitor"
+                                                                                        ]))
+                                                                                ]))
                                                                             ]))
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "op_next"
                                                                     ]))
                                                                 ]))
                                                         ]))
                                                     ]))
                                             ])),
                                             (crate::value!([
-                                                "emit" => "sequence",
-                                                "children" =>
+                                                "emit" => "identifier",
+                                                "value" => "BitXor"
+                                            ]))
+                                        ]))
+                                ]))
+                        ]))
+                    ]))
+            ])),
+            (crate::value!([
+                "emit" => "constant",
+                "children" =>
+                    (crate::value!([
+                        (crate::value!([
+                            "emit" => "identifier",
+                            "value" => "Sequence"
+                        ])),
+                        (crate::value!([
+                            "emit" => "value_parselet",
+                            "children" =>
+                                (crate::value!([
+                                    "emit" => "body",
+                                    "children" =>
+                                        (crate::value!([
+                                            "emit" => "sequence",
+                                            "children" =>
+                                                (crate::value!([
                                                     (crate::value!([
-                                                        (crate::value!([
-                                                            "emit" => "value_instance",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "Keyword"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "instarg",
-                                                                        "children" =>
-                                                                            (crate::value!([
-                                                                                "emit" => "value_token_touch",
-                                                                                "value" => "repeat"
-                                                                            ]))
-                                                                    ]))
-                                                                ]))
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "_"
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "call",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "ast"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "callarg",
-                                                                        "children" =>
-                                                                            (crate::value!([
-                                                                                "emit" => "value_string",
-                                                                                "value" => "op_repeat"
-                                                                            ]))
+                                                        "emit" => "op_mod_pos",
+                                                        "children" =>
+                                                            (crate::value!([
+                                                                "emit" => "sequence",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "_"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "SequenceItem"
+                                                                        ]))
                                                                     ]))
-                                                                ]))
-                                                        ]))
-                                                    ]))
-                                            ])),
-                                            (crate::value!([
-                                                "emit" => "sequence",
-                                                "children" =>
+                                                            ]))
+                                                    ])),
                                                     (crate::value!([
-                                                        (crate::value!([
-                                                            "emit" => "value_instance",
-                                                            "children" =>
+                                                        "emit" => "op_if",
+                                                        "children" =>
+                                                            (crate::value!([
                                                                 (crate::value!([
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "Keyword"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "instarg",
-                                                                        "children" =>
+                                                                    "emit" => "op_logical_or",
+                                                                    "children" =>
+                                                                        (crate::value!([
                                                                             (crate::value!([
-                                                                                "emit" => "value_token_touch",
-                                                                                "value" => "reset"
+                                                                                "emit" => "op_logical_and",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        (crate::value!([
+                                                                                            "emit" => "comparison",
+                                                                                            "children" =>
+                                                                                                (crate::value!([
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "call",
+                                                                                                        "children" =>
+                                                                                                            (crate::value!([
+                                                                                                                (crate::value!([
+                                                                                                                    "emit" => "identifier",
+                                                                                                                    "value" => "type"
+                                                                                                                ])),
+                                                                                                                (crate::value!([
+                                                                                                                    "emit" => "callarg",
+                                                                                                                    "children" =>
+                                                                                                                        (crate::value!([
+                                                                                                                            "emit" => "capture_index",
+                                                                                                                            "children" =>
+                                                                                                                                (crate::value!([
+                                                                                                                                    "emit" => "value_integer",
+                                                                                                                                    "value" => 1
+                                                                                                                                ]))
+                                                                                                                        ]))
+                                                                                                                ]))
+                                                                                                            ]))
+                                                                                                    ])),
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "cmp_eq",
+                                                                                                        "children" =>
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "value_string",
+                                                                                                                "value" => "list"
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "comparison",
+                                                                                            "children" =>
+                                                                                                (crate::value!([
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "rvalue",
+                                                                                                        "children" =>
+                                                                                                            (crate::value!([
+                                                                                                                (crate::value!([
+                                                                                                                    "emit" => "capture_index",
+                                                                                                                    "children" =>
+                                                                                                                        (crate::value!([
+                                                                                                                            "emit" => "value_integer",
+                                                                                                                            "value" => 1
+                                                                                                                        ]))
+                                                                                                                ])),
+                                                                                                                (crate::value!([
+                                                                                                                    "emit" => "attribute",
+                                                                                                                    "children" =>
+                                                                                                                        (crate::value!([
+                                                                                                                            "emit" => "value_string",
+                                                                                                                            "value" => "len"
+                                                                                                                        ]))
+                                                                                                                ]))
+                                                                                                            ]))
+                                                                                                    ])),
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "cmp_gt",
+                                                                                                        "children" =>
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "value_integer",
+                                                                                                                "value" => 1
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ])),
+                                                                            (crate::value!([
+                                                                                "emit" => "comparison",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        (crate::value!([
+                                                                                            "emit" => "rvalue",
+                                                                                            "children" =>
+                                                                                                (crate::value!([
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "capture_index",
+                                                                                                        "children" =>
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "value_integer",
+                                                                                                                "value" => 1
+                                                                                                            ]))
+                                                                                                    ])),
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "item",
+                                                                                                        "children" =>
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "value_string",
+                                                                                                                "value" => "emit"
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "cmp_eq",
+                                                                                            "children" =>
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "value_string",
+                                                                                                    "value" => "alias"
+                                                                                                ]))
+                                                                                        ]))
+                                                                                    ]))
                                                                             ]))
-                                                                    ]))
-                                                                ]))
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "_"
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "call",
-                                                            "children" =>
+                                                                        ]))
+                                                                ])),
                                                                 (crate::value!([
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "ast"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "callarg",
-                                                                        "children" =>
+                                                                    "emit" => "call",
+                                                                    "children" =>
+                                                                        (crate::value!([
                                                                             (crate::value!([
-                                                                                "emit" => "value_string",
-                                                                                "value" => "op_reset"
+                                                                                "emit" => "identifier",
+                                                                                "value" => "ast"
+                                                                            ])),
+                                                                            (crate::value!([
+                                                                                "emit" => "callarg",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        "emit" => "value_string",
+                                                                                        "value" => "sequence"
+                                                                                    ]))
                                                                             ]))
-                                                                    ]))
+                                                                        ]))
                                                                 ]))
-                                                        ]))
+                                                            ]))
                                                     ]))
-                                            ])),
+                                                ]))
+                                        ]))
+                                ]))
+                        ]))
+                    ]))
+            ])),
+            (crate::value!([
+                "emit" => "constant",
+                "children" =>
+                    (crate::value!([
+                        (crate::value!([
+                            "emit" => "identifier",
+                            "value" => "Sequences"
+                        ])),
+                        (crate::value!([
+                            "emit" => "value_parselet",
+                            "children" =>
+                                (crate::value!([
+                                    "emit" => "body",
+                                    "children" =>
+                                        (crate::value!([
                                             (crate::value!([
                                                 "emit" => "sequence",
                                                 "children" =>
                                                     (crate::value!([
-                                                        (crate::value!([
-                                                            "emit" => "value_instance",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "Keyword"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "instarg",
-                                                                        "children" =>
-                                                                            (crate::value!([
-                                                                                "emit" => "value_token_touch",
-                                                                                "value" => "return"
-                                                                            ]))
-                                                                    ]))
-                                                                ]))
-                                                        ])),
                                                         (crate::value!([
                                                             "emit" => "identifier",
-                                                            "value" => "_"
+                                                            "value" => "Sequences"
                                                         ])),
                                                         (crate::value!([
-                                                            "emit" => "op_mod_opt",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    "emit" => "identifier",
-                                                                    "value" => "Expression"
-                                                                ]))
+                                                            "emit" => "value_token_touch",
+                                                            "value" => "|"
                                                         ])),
                                                         (crate::value!([
-                                                            "emit" => "call",
+                                                            "emit" => "value_instance",
                                                             "children" =>
                                                                 (crate::value!([
                                                                     (crate::value!([
                                                                         "emit" => "identifier",
-                                                                        "value" => "ast"
+                                                                        "value" => "Not"
                                                                     ])),
                                                                     (crate::value!([
-                                                                        "emit" => "callarg",
+                                                                        "emit" => "instarg",
                                                                         "children" =>
                                                                             (crate::value!([
-                                                                                "emit" => "value_string",
-                                                                                "value" => "op_accept"
+                                                                                "emit" => "value_token_ccl",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        "emit" => "ccl",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "char",
+                                                                                                    "value" => "|"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "char",
+                                                                                                    "value" => "="
+                                                                                                ]))
+                                                                                            ]))
+                                                                                    ]))
                                                                             ]))
                                                                     ]))
                                                                 ]))
-                                                        ]))
-                                                    ]))
-                                            ])),
-                                            (crate::value!([
-                                                "emit" => "call",
-                                                "children" =>
-                                                    (crate::value!([
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "_"
+                                                        ])),
                                                         (crate::value!([
                                                             "emit" => "value_instance",
                                                             "children" =>
                                                                 (crate::value!([
                                                                     (crate::value!([
                                                                         "emit" => "identifier",
-                                                                        "value" => "Assignment"
+                                                                        "value" => "Expect"
                                                                     ])),
                                                                     (crate::value!([
                                                                         "emit" => "instarg",
                                                                         "children" =>
                                                                             (crate::value!([
                                                                                 "emit" => "identifier",
-                                                                                "value" => "ExpressionList"
+                                                                                "value" => "Sequence"
                                                                             ]))
                                                                     ]))
                                                                 ]))
                                                         ])),
                                                         (crate::value!([
-                                                            "emit" => "callarg",
+                                                            "emit" => "call",
                                                             "children" =>
                                                                 (crate::value!([
-                                                                    "emit" => "value_string",
-                                                                    "value" => "drop"
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "ast"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "callarg",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "value_string",
+                                                                                "value" => "op_binary_bitor"
+                                                                            ]))
+                                                                    ]))
                                                                 ]))
                                                         ]))
                                                     ]))
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "identifier",
+                                                "value" => "Sequence"
                                             ]))
                                         ]))
                                 ]))
@@ -7909,24 +11171,18 @@ crate::value!([
                     (crate::value!([
                         (crate::value!([
                             "emit" => "identifier",
-                            "value" => "Block"
+                            "value" => "Tokay"
                         ])),
                         (crate::value!([
                             "emit" => "value_parselet",
                             "children" =>
                                 (crate::value!([
                                     (crate::value!([
-                                        "emit" => "sig",
+                                        "emit" => "gen",
                                         "children" =>
                                             (crate::value!([
-                                                (crate::value!([
-                                                    "emit" => "identifier",
-                                                    "value" => "emit"
-                                                ])),
-                                                (crate::value!([
-                                                    "emit" => "value_string",
-                                                    "value" => "block"
-                                                ]))
+                                                "emit" => "identifier",
+                                                "value" => "Close"
                                             ]))
                                     ])),
                                     (crate::value!([
@@ -7934,847 +11190,726 @@ crate::value!([
                                         "children" =>
                                             (crate::value!([
                                                 (crate::value!([
-                                                    "emit" => "sequence",
+                                                    "emit" => "identifier",
+                                                    "value" => "T_EOL"
+                                                ])),
+                                                (crate::value!([
+                                                    "emit" => "op_try",
                                                     "children" =>
                                                         (crate::value!([
                                                             (crate::value!([
-                                                                "emit" => "value_token_touch",
-                                                                "value" => "{"
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "identifier",
-                                                                "value" => "_"
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "identifier",
-                                                                "value" => "___"
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "value_token_touch",
-                                                                "value" => "}"
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "call",
+                                                                "emit" => "block",
                                                                 "children" =>
                                                                     (crate::value!([
                                                                         (crate::value!([
-                                                                            "emit" => "identifier",
-                                                                            "value" => "ast"
+                                                                            "emit" => "sequence",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    (crate::value!([
+                                                                                        "emit" => "value_instance",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "Keyword"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "instarg",
+                                                                                                    "children" =>
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "value_token_touch",
+                                                                                                            "value" => "begin"
+                                                                                                        ]))
+                                                                                                ]))
+                                                                                            ]))
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "identifier",
+                                                                                        "value" => "_"
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "value_instance",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "Expect"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "instarg",
+                                                                                                    "children" =>
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "identifier",
+                                                                                                            "value" => "Sequences"
+                                                                                                        ]))
+                                                                                                ]))
+                                                                                            ]))
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "value_instance",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "Expect"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "instarg",
+                                                                                                    "children" =>
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "identifier",
+                                                                                                            "value" => "T_EOL"
+                                                                                                        ]))
+                                                                                                ]))
+                                                                                            ]))
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "call",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "ast"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "callarg",
+                                                                                                    "children" =>
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "value_string",
+                                                                                                            "value" => "begin"
+                                                                                                        ]))
+                                                                                                ]))
+                                                                                            ]))
+                                                                                    ]))
+                                                                                ]))
                                                                         ])),
                                                                         (crate::value!([
-                                                                            "emit" => "callarg",
+                                                                            "emit" => "sequence",
                                                                             "children" =>
                                                                                 (crate::value!([
-                                                                                    "emit" => "value_string",
-                                                                                    "value" => "value_void"
+                                                                                    (crate::value!([
+                                                                                        "emit" => "value_instance",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "Keyword"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "instarg",
+                                                                                                    "children" =>
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "value_token_touch",
+                                                                                                            "value" => "end"
+                                                                                                        ]))
+                                                                                                ]))
+                                                                                            ]))
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "identifier",
+                                                                                        "value" => "_"
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "value_instance",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "Expect"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "instarg",
+                                                                                                    "children" =>
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "identifier",
+                                                                                                            "value" => "Sequences"
+                                                                                                        ]))
+                                                                                                ]))
+                                                                                            ]))
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "value_instance",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "Expect"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "instarg",
+                                                                                                    "children" =>
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "identifier",
+                                                                                                            "value" => "T_EOL"
+                                                                                                        ]))
+                                                                                                ]))
+                                                                                            ]))
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "call",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "ast"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "callarg",
+                                                                                                    "children" =>
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "value_string",
+                                                                                                            "value" => "end"
+                                                                                                        ]))
+                                                                                                ]))
+                                                                                            ]))
+                                                                                    ]))
                                                                                 ]))
-                                                                        ]))
-                                                                    ]))
-                                                            ]))
-                                                        ]))
-                                                ])),
-                                                (crate::value!([
-                                                    "emit" => "sequence",
-                                                    "children" =>
-                                                        (crate::value!([
-                                                            (crate::value!([
-                                                                "emit" => "value_token_touch",
-                                                                "value" => "{"
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "identifier",
-                                                                "value" => "_"
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "op_mod_kle",
-                                                                "children" =>
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "Tokay"
-                                                                    ]))
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "identifier",
-                                                                "value" => "_"
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "value_instance",
-                                                                "children" =>
-                                                                    (crate::value!([
-                                                                        (crate::value!([
-                                                                            "emit" => "identifier",
-                                                                            "value" => "Expect"
                                                                         ])),
                                                                         (crate::value!([
-                                                                            "emit" => "instarg",
+                                                                            "emit" => "sequence",
                                                                             "children" =>
                                                                                 (crate::value!([
-                                                                                    "emit" => "value_token_touch",
-                                                                                    "value" => "}"
+                                                                                    (crate::value!([
+                                                                                        "emit" => "value_instance",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "Keyword"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "instarg",
+                                                                                                    "children" =>
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "value_token_touch",
+                                                                                                            "value" => "beginfile"
+                                                                                                        ]))
+                                                                                                ]))
+                                                                                            ]))
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "identifier",
+                                                                                        "value" => "_"
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "value_instance",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "Expect"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "instarg",
+                                                                                                    "children" =>
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "identifier",
+                                                                                                            "value" => "Sequences"
+                                                                                                        ]))
+                                                                                                ]))
+                                                                                            ]))
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "value_instance",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "Expect"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "instarg",
+                                                                                                    "children" =>
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "identifier",
+                                                                                                            "value" => "T_EOL"
+                                                                                                        ]))
+                                                                                                ]))
+                                                                                            ]))
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "call",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "ast"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "callarg",
+                                                                                                    "children" =>
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "value_string",
+                                                                                                            "value" => "beginfile"
+                                                                                                        ]))
+                                                                                                ]))
+                                                                                            ]))
+                                                                                    ]))
                                                                                 ]))
-                                                                        ]))
-                                                                    ]))
-                                                            ])),
-                                                            (crate::value!([
-                                                                "emit" => "call",
-                                                                "children" =>
-                                                                    (crate::value!([
-                                                                        (crate::value!([
-                                                                            "emit" => "identifier",
-                                                                            "value" => "ast"
                                                                         ])),
                                                                         (crate::value!([
-                                                                            "emit" => "callarg",
+                                                                            "emit" => "sequence",
                                                                             "children" =>
                                                                                 (crate::value!([
-                                                                                    "emit" => "identifier",
-                                                                                    "value" => "emit"
-                                                                                ]))
-                                                                        ]))
-                                                                    ]))
-                                                            ]))
-                                                        ]))
-                                                ]))
-                                            ]))
-                                    ]))
-                                ]))
-                        ]))
-                    ]))
-            ])),
-            (crate::value!([
-                "emit" => "constant",
-                "children" =>
-                    (crate::value!([
-                        (crate::value!([
-                            "emit" => "identifier",
-                            "value" => "SequenceItem"
-                        ])),
-                        (crate::value!([
-                            "emit" => "value_parselet",
-                            "children" =>
-                                (crate::value!([
-                                    "emit" => "body",
-                                    "children" =>
-                                        (crate::value!([
-                                            (crate::value!([
-                                                "emit" => "sequence",
-                                                "children" =>
-                                                    (crate::value!([
-                                                        (crate::value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "T_Alias"
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "_"
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "value_token_touch",
-                                                            "value" => "=>"
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "_"
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "value_instance",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "Expect"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "instarg",
-                                                                        "children" =>
-                                                                            (crate::value!([
-                                                                                "emit" => "identifier",
-                                                                                "value" => "ExpressionList"
-                                                                            ]))
-                                                                    ]))
-                                                                ]))
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "call",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "ast"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "callarg",
-                                                                        "children" =>
-                                                                            (crate::value!([
-                                                                                "emit" => "value_string",
-                                                                                "value" => "alias"
-                                                                            ]))
-                                                                    ]))
-                                                                ]))
-                                                        ]))
-                                                    ]))
-                                            ])),
-                                            (crate::value!([
-                                                "emit" => "sequence",
-                                                "children" =>
-                                                    (crate::value!([
-                                                        (crate::value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "Expression"
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "value_token_touch",
-                                                            "value" => "=>"
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "_"
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "value_instance",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "Expect"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "instarg",
-                                                                        "children" =>
-                                                                            (crate::value!([
-                                                                                "emit" => "identifier",
-                                                                                "value" => "ExpressionList"
-                                                                            ]))
-                                                                    ]))
-                                                                ]))
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "call",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "ast"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "callarg",
-                                                                        "children" =>
-                                                                            (crate::value!([
-                                                                                "emit" => "value_string",
-                                                                                "value" => "alias"
-                                                                            ]))
-                                                                    ]))
-                                                                ]))
-                                                        ]))
-                                                    ]))
-                                            ])),
-                                            (crate::value!([
-                                                "emit" => "identifier",
-                                                "value" => "Statement"
-                                            ]))
-                                        ]))
-                                ]))
-                        ]))
-                    ]))
-            ])),
-            (crate::value!([
-                "emit" => "constant",
-                "children" =>
-                    (crate::value!([
-                        (crate::value!([
-                            "emit" => "identifier",
-                            "value" => "Sequence"
-                        ])),
-                        (crate::value!([
-                            "emit" => "value_parselet",
-                            "children" =>
-                                (crate::value!([
-                                    "emit" => "body",
-                                    "children" =>
-                                        (crate::value!([
-                                            "emit" => "sequence",
-                                            "children" =>
-                                                (crate::value!([
-                                                    (crate::value!([
-                                                        "emit" => "op_mod_pos",
-                                                        "children" =>
-                                                            (crate::value!([
-                                                                "emit" => "identifier",
-                                                                "value" => "SequenceItem"
-                                                            ]))
-                                                    ])),
-                                                    (crate::value!([
-                                                        "emit" => "op_if",
-                                                        "children" =>
-                                                            (crate::value!([
-                                                                (crate::value!([
-                                                                    "emit" => "op_logical_or",
-                                                                    "children" =>
-                                                                        (crate::value!([
-                                                                            (crate::value!([
-                                                                                "emit" => "op_logical_and",
-                                                                                "children" =>
                                                                                     (crate::value!([
-                                                                                        (crate::value!([
-                                                                                            "emit" => "comparison",
-                                                                                            "children" =>
+                                                                                        "emit" => "value_instance",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "Keyword"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "instarg",
+                                                                                                    "children" =>
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "value_token_touch",
+                                                                                                            "value" => "endfile"
+                                                                                                        ]))
+                                                                                                ]))
+                                                                                            ]))
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "identifier",
+                                                                                        "value" => "_"
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "value_instance",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "Expect"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "instarg",
+                                                                                                    "children" =>
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "identifier",
+                                                                                                            "value" => "Sequences"
+                                                                                                        ]))
+                                                                                                ]))
+                                                                                            ]))
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "value_instance",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
                                                                                                 (crate::value!([
-                                                                                                    (crate::value!([
-                                                                                                        "emit" => "call",
-                                                                                                        "children" =>
-                                                                                                            (crate::value!([
-                                                                                                                (crate::value!([
-                                                                                                                    "emit" => "identifier",
-                                                                                                                    "value" => "type"
-                                                                                                                ])),
-                                                                                                                (crate::value!([
-                                                                                                                    "emit" => "callarg",
-                                                                                                                    "children" =>
-                                                                                                                        (crate::value!([
-                                                                                                                            "emit" => "capture_index",
-                                                                                                                            "children" =>
-                                                                                                                                (crate::value!([
-                                                                                                                                    "emit" => "value_integer",
-                                                                                                                                    "value" => 1
-                                                                                                                                ]))
-                                                                                                                        ]))
-                                                                                                                ]))
-                                                                                                            ]))
-                                                                                                    ])),
-                                                                                                    (crate::value!([
-                                                                                                        "emit" => "cmp_eq",
-                                                                                                        "children" =>
-                                                                                                            (crate::value!([
-                                                                                                                "emit" => "value_string",
-                                                                                                                "value" => "list"
-                                                                                                            ]))
-                                                                                                    ]))
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "Expect"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "instarg",
+                                                                                                    "children" =>
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "identifier",
+                                                                                                            "value" => "T_EOL"
+                                                                                                        ]))
                                                                                                 ]))
-                                                                                        ])),
-                                                                                        (crate::value!([
-                                                                                            "emit" => "comparison",
-                                                                                            "children" =>
+                                                                                            ]))
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "call",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
                                                                                                 (crate::value!([
-                                                                                                    (crate::value!([
-                                                                                                        "emit" => "rvalue",
-                                                                                                        "children" =>
-                                                                                                            (crate::value!([
-                                                                                                                (crate::value!([
-                                                                                                                    "emit" => "capture_index",
-                                                                                                                    "children" =>
-                                                                                                                        (crate::value!([
-                                                                                                                            "emit" => "value_integer",
-                                                                                                                            "value" => 1
-                                                                                                                        ]))
-                                                                                                                ])),
-                                                                                                                (crate::value!([
-                                                                                                                    "emit" => "attribute",
-                                                                                                                    "children" =>
-                                                                                                                        (crate::value!([
-                                                                                                                            "emit" => "value_string",
-                                                                                                                            "value" => "len"
-                                                                                                                        ]))
-                                                                                                                ]))
-                                                                                                            ]))
-                                                                                                    ])),
-                                                                                                    (crate::value!([
-                                                                                                        "emit" => "cmp_gt",
-                                                                                                        "children" =>
-                                                                                                            (crate::value!([
-                                                                                                                "emit" => "value_integer",
-                                                                                                                "value" => 1
-                                                                                                            ]))
-                                                                                                    ]))
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "ast"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "callarg",
+                                                                                                    "children" =>
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "value_string",
+                                                                                                            "value" => "endfile"
+                                                                                                        ]))
                                                                                                 ]))
-                                                                                        ]))
+                                                                                            ]))
                                                                                     ]))
-                                                                            ])),
-                                                                            (crate::value!([
-                                                                                "emit" => "comparison",
-                                                                                "children" =>
+                                                                                ]))
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "sequence",
+                                                                            "children" =>
+                                                                                (crate::value!([
                                                                                     (crate::value!([
-                                                                                        (crate::value!([
-                                                                                            "emit" => "rvalue",
-                                                                                            "children" =>
+                                                                                        "emit" => "value_instance",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
                                                                                                 (crate::value!([
-                                                                                                    (crate::value!([
-                                                                                                        "emit" => "capture_index",
-                                                                                                        "children" =>
-                                                                                                            (crate::value!([
-                                                                                                                "emit" => "value_integer",
-                                                                                                                "value" => 1
-                                                                                                            ]))
-                                                                                                    ])),
-                                                                                                    (crate::value!([
-                                                                                                        "emit" => "item",
-                                                                                                        "children" =>
-                                                                                                            (crate::value!([
-                                                                                                                "emit" => "value_string",
-                                                                                                                "value" => "emit"
-                                                                                                            ]))
-                                                                                                    ]))
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "Keyword"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "instarg",
+                                                                                                    "children" =>
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "value_token_touch",
+                                                                                                            "value" => "use"
+                                                                                                        ]))
                                                                                                 ]))
-                                                                                        ])),
-                                                                                        (crate::value!([
-                                                                                            "emit" => "cmp_eq",
-                                                                                            "children" =>
+                                                                                            ]))
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "identifier",
+                                                                                        "value" => "_"
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "value_instance",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
                                                                                                 (crate::value!([
-                                                                                                    "emit" => "value_string",
-                                                                                                    "value" => "alias"
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "Expect"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "instarg",
+                                                                                                    "children" =>
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "identifier",
+                                                                                                            "value" => "T_Module"
+                                                                                                        ]))
                                                                                                 ]))
-                                                                                        ]))
-                                                                                    ]))
-                                                                            ]))
-                                                                        ]))
-                                                                ])),
-                                                                (crate::value!([
-                                                                    "emit" => "call",
-                                                                    "children" =>
-                                                                        (crate::value!([
-                                                                            (crate::value!([
-                                                                                "emit" => "identifier",
-                                                                                "value" => "ast"
-                                                                            ])),
-                                                                            (crate::value!([
-                                                                                "emit" => "callarg",
-                                                                                "children" =>
+                                                                                            ]))
+                                                                                    ])),
                                                                                     (crate::value!([
-                                                                                        "emit" => "value_string",
-                                                                                        "value" => "sequence"
-                                                                                    ]))
-                                                                            ]))
-                                                                        ]))
-                                                                ]))
-                                                            ]))
-                                                    ]))
-                                                ]))
-                                        ]))
-                                ]))
-                        ]))
-                    ]))
-            ])),
-            (crate::value!([
-                "emit" => "constant",
-                "children" =>
-                    (crate::value!([
-                        (crate::value!([
-                            "emit" => "identifier",
-                            "value" => "Sequences"
-                        ])),
-                        (crate::value!([
-                            "emit" => "value_parselet",
-                            "children" =>
-                                (crate::value!([
-                                    "emit" => "body",
-                                    "children" =>
-                                        (crate::value!([
-                                            (crate::value!([
-                                                "emit" => "sequence",
-                                                "children" =>
-                                                    (crate::value!([
-                                                        (crate::value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "Sequence"
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "op_mod_pos",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    "emit" => "sequence",
-                                                                    "children" =>
-                                                                        (crate::value!([
-                                                                            (crate::value!([
-                                                                                "emit" => "value_token_touch",
-                                                                                "value" => "|"
-                                                                            ])),
-                                                                            (crate::value!([
-                                                                                "emit" => "identifier",
-                                                                                "value" => "_"
-                                                                            ])),
-                                                                            (crate::value!([
-                                                                                "emit" => "value_instance",
-                                                                                "children" =>
+                                                                                        "emit" => "identifier",
+                                                                                        "value" => "_"
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "op_mod_opt",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                "emit" => "sequence",
+                                                                                                "children" =>
+                                                                                                    (crate::value!([
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "value_instance",
+                                                                                                            "children" =>
+                                                                                                                (crate::value!([
+                                                                                                                    (crate::value!([
+                                                                                                                        "emit" => "identifier",
+                                                                                                                        "value" => "Keyword"
+                                                                                                                    ])),
+                                                                                                                    (crate::value!([
+                                                                                                                        "emit" => "instarg",
+                                                                                                                        "children" =>
+                                                                                                                            (crate::value!([
+                                                                                                                                "emit" => "value_token_touch",
+                                                                                                                                "value" => "as"
+                                                                                                                            ]))
+                                                                                                                    ]))
+                                                                                                                ]))
+                                                                                                        ])),
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "identifier",
+                                                                                                            "value" => "_"
+                                                                                                        ])),
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "value_instance",
+                                                                                                            "children" =>
+                                                                                                                (crate::value!([
+                                                                                                                    (crate::value!([
+                                                                                                                        "emit" => "identifier",
+                                                                                                                        "value" => "Expect"
+                                                                                                                    ])),
+                                                                                                                    (crate::value!([
+                                                                                                                        "emit" => "instarg",
+                                                                                                                        "children" =>
+                                                                                                                            (crate::value!([
+                                                                                                                                "emit" => "identifier",
+                                                                                                                                "value" => "T_Alias"
+                                                                                                                            ]))
+                                                                                                                    ]))
+                                                                                                                ]))
+                                                                                                        ])),
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "identifier",
+                                                                                                            "value" => "_"
+                                                                                                        ]))
+                                                                                                    ]))
+                                                                                            ]))
+                                                                                    ])),
                                                                                     (crate::value!([
-                                                                                        (crate::value!([
-                                                                                            "emit" => "identifier",
-                                                                                            "value" => "Expect"
-                                                                                        ])),
-                                                                                        (crate::value!([
-                                                                                            "emit" => "instarg",
-                                                                                            "children" =>
+                                                                                        "emit" => "value_instance",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
                                                                                                 (crate::value!([
                                                                                                     "emit" => "identifier",
-                                                                                                    "value" => "Sequence"
+                                                                                                    "value" => "Expect"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "instarg",
+                                                                                                    "children" =>
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "identifier",
+                                                                                                            "value" => "T_EOL"
+                                                                                                        ]))
                                                                                                 ]))
-                                                                                        ]))
+                                                                                            ]))
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "call",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "ast"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "callarg",
+                                                                                                    "children" =>
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "value_string",
+                                                                                                            "value" => "use"
+                                                                                                        ]))
+                                                                                                ]))
+                                                                                            ]))
                                                                                     ]))
-                                                                            ]))
-                                                                        ]))
-                                                                ]))
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "call",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "ast"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "callarg",
-                                                                        "children" =>
-                                                                            (crate::value!([
-                                                                                "emit" => "value_string",
-                                                                                "value" => "block"
-                                                                            ]))
-                                                                    ]))
-                                                                ]))
-                                                        ]))
-                                                    ]))
-                                            ])),
-                                            (crate::value!([
-                                                "emit" => "identifier",
-                                                "value" => "Sequence"
-                                            ]))
-                                        ]))
-                                ]))
-                        ]))
-                    ]))
-            ])),
-            (crate::value!([
-                "emit" => "constant",
-                "children" =>
-                    (crate::value!([
-                        (crate::value!([
-                            "emit" => "identifier",
-                            "value" => "Tokay"
-                        ])),
-                        (crate::value!([
-                            "emit" => "value_parselet",
-                            "children" =>
-                                (crate::value!([
-                                    "emit" => "body",
-                                    "children" =>
-                                        (crate::value!([
-                                            (crate::value!([
-                                                "emit" => "identifier",
-                                                "value" => "T_EOL"
-                                            ])),
-                                            (crate::value!([
-                                                "emit" => "sequence",
-                                                "children" =>
-                                                    (crate::value!([
-                                                        (crate::value!([
-                                                            "emit" => "value_instance",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "Keyword"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "instarg",
-                                                                        "children" =>
-                                                                            (crate::value!([
-                                                                                "emit" => "value_token_touch",
-                                                                                "value" => "begin"
-                                                                            ]))
-                                                                    ]))
-                                                                ]))
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "_"
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "value_instance",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "Expect"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "instarg",
-                                                                        "children" =>
-                                                                            (crate::value!([
-                                                                                "emit" => "identifier",
-                                                                                "value" => "Sequences"
-                                                                            ]))
-                                                                    ]))
-                                                                ]))
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "value_instance",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "Expect"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "instarg",
-                                                                        "children" =>
-                                                                            (crate::value!([
-                                                                                "emit" => "identifier",
-                                                                                "value" => "T_EOL"
-                                                                            ]))
-                                                                    ]))
-                                                                ]))
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "call",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "ast"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "callarg",
-                                                                        "children" =>
-                                                                            (crate::value!([
-                                                                                "emit" => "value_string",
-                                                                                "value" => "begin"
-                                                                            ]))
-                                                                    ]))
-                                                                ]))
-                                                        ]))
-                                                    ]))
-                                            ])),
-                                            (crate::value!([
-                                                "emit" => "sequence",
-                                                "children" =>
-                                                    (crate::value!([
-                                                        (crate::value!([
-                                                            "emit" => "value_instance",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "Keyword"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "instarg",
-                                                                        "children" =>
-                                                                            (crate::value!([
-                                                                                "emit" => "value_token_touch",
-                                                                                "value" => "end"
-                                                                            ]))
-                                                                    ]))
-                                                                ]))
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "_"
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "value_instance",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "Expect"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "instarg",
-                                                                        "children" =>
-                                                                            (crate::value!([
-                                                                                "emit" => "identifier",
-                                                                                "value" => "Sequences"
-                                                                            ]))
-                                                                    ]))
-                                                                ]))
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "value_instance",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "Expect"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "instarg",
-                                                                        "children" =>
-                                                                            (crate::value!([
-                                                                                "emit" => "identifier",
-                                                                                "value" => "T_EOL"
-                                                                            ]))
+                                                                                ]))
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "sequence",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    (crate::value!([
+                                                                                        "emit" => "identifier",
+                                                                                        "value" => "T_Identifier"
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "identifier",
+                                                                                        "value" => "_"
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "value_token_touch",
+                                                                                        "value" => ":"
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "identifier",
+                                                                                        "value" => "_"
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "block",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "sequence",
+                                                                                                    "children" =>
+                                                                                                        (crate::value!([
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "identifier",
+                                                                                                                "value" => "Literal"
+                                                                                                            ])),
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "identifier",
+                                                                                                                "value" => "_"
+                                                                                                            ])),
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "value_instance",
+                                                                                                                "children" =>
+                                                                                                                    (crate::value!([
+                                                                                                                        (crate::value!([
+                                                                                                                            "emit" => "identifier",
+                                                                                                                            "value" => "Peek"
+                                                                                                                        ])),
+                                                                                                                        (crate::value!([
+                                                                                                                            "emit" => "instarg",
+                                                                                                                            "children" =>
+                                                                                                                                (crate::value!([
+                                                                                                                                    "emit" => "identifier",
+                                                                                                                                    "value" => "T_EOL"
+                                                                                                                                ]))
+                                                                                                                        ]))
+                                                                                                                    ]))
+                                                                                                            ]))
+                                                                                                        ]))
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "sequence",
+                                                                                                    "children" =>
+                                                                                                        (crate::value!([
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "identifier",
+                                                                                                                "value" => "Token"
+                                                                                                            ])),
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "identifier",
+                                                                                                                "value" => "_"
+                                                                                                            ])),
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "value_instance",
+                                                                                                                "children" =>
+                                                                                                                    (crate::value!([
+                                                                                                                        (crate::value!([
+                                                                                                                            "emit" => "identifier",
+                                                                                                                            "value" => "Peek"
+                                                                                                                        ])),
+                                                                                                                        (crate::value!([
+                                                                                                                            "emit" => "instarg",
+                                                                                                                            "children" =>
+                                                                                                                                (crate::value!([
+                                                                                                                                    "emit" => "identifier",
+                                                                                                                                    "value" => "T_EOL"
+                                                                                                                                ]))
+                                                                                                                        ]))
+                                                                                                                    ]))
+                                                                                                            ]))
+                                                                                                        ]))
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "Sequences"
+                                                                                                ]))
+                                                                                            ]))
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "value_instance",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "Expect"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "instarg",
+                                                                                                    "children" =>
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "identifier",
+                                                                                                            "value" => "T_EOL"
+                                                                                                        ]))
+                                                                                                ]))
+                                                                                            ]))
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "call",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "ast"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "callarg",
+                                                                                                    "children" =>
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "value_string",
+                                                                                                            "value" => "constant"
+                                                                                                        ]))
+                                                                                                ]))
+                                                                                            ]))
+                                                                                    ]))
+                                                                                ]))
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "sequence",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    (crate::value!([
+                                                                                        "emit" => "identifier",
+                                                                                        "value" => "Sequences"
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "op_mod_opt",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                "emit" => "identifier",
+                                                                                                "value" => "T_EOL"
+                                                                                            ]))
+                                                                                    ]))
+                                                                                ]))
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "value_instance",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    (crate::value!([
+                                                                                        "emit" => "identifier",
+                                                                                        "value" => "Unexpected"
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "instarg",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                "emit" => "identifier",
+                                                                                                "value" => "Close"
+                                                                                            ]))
+                                                                                    ]))
+                                                                                ]))
+                                                                        ]))
                                                                     ]))
-                                                                ]))
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "call",
-                                                            "children" =>
-                                                                (crate::value!([
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "lvalue",
+                                                                "children" =>
                                                                     (crate::value!([
                                                                         "emit" => "identifier",
-                                                                        "value" => "ast"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "callarg",
-                                                                        "children" =>
-                                                                            (crate::value!([
-                                                                                "emit" => "value_string",
-                                                                                "value" => "end"
-                                                                            ]))
+                                                                        "value" => "e"
                                                                     ]))
-                                                                ]))
-                                                        ]))
-                                                    ]))
-                                            ])),
-                                            (crate::value!([
-                                                "emit" => "sequence",
-                                                "children" =>
-                                                    (crate::value!([
-                                                        (crate::value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "T_Identifier"
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "_"
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "value_token_touch",
-                                                            "value" => ":"
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "_"
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "block",
-                                                            "children" =>
-                                                                (crate::value!([
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "block",
+                                                                "children" =>
                                                                     (crate::value!([
                                                                         "emit" => "sequence",
                                                                         "children" =>
                                                                             (crate::value!([
                                                                                 (crate::value!([
-                                                                                    "emit" => "identifier",
-                                                                                    "value" => "Literal"
-                                                                                ])),
-                                                                                (crate::value!([
-                                                                                    "emit" => "identifier",
-                                                                                    "value" => "_"
-                                                                                ])),
-                                                                                (crate::value!([
-                                                                                    "emit" => "value_instance",
+                                                                                    "emit" => "call",
                                                                                     "children" =>
                                                                                         (crate::value!([
                                                                                             (crate::value!([
                                                                                                 "emit" => "identifier",
-                                                                                                "value" => "Peek"
+                                                                                                "value" => "error"
                                                                                             ])),
                                                                                             (crate::value!([
-                                                                                                "emit" => "instarg",
+                                                                                                "emit" => "callarg",
                                                                                                 "children" =>
                                                                                                     (crate::value!([
                                                                                                         "emit" => "identifier",
-                                                                                                        "value" => "T_EOL"
+                                                                                                        "value" => "e"
+                                                                                                    ]))
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "callarg_named",
+                                                                                                "children" =>
+                                                                                                    (crate::value!([
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "identifier",
+                                                                                                            "value" => "recover"
+                                                                                                        ])),
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "value_true"
+                                                                                                        ]))
                                                                                                     ]))
                                                                                             ]))
                                                                                         ]))
-                                                                                ]))
-                                                                            ]))
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "sequence",
-                                                                        "children" =>
-                                                                            (crate::value!([
-                                                                                (crate::value!([
-                                                                                    "emit" => "identifier",
-                                                                                    "value" => "Token"
                                                                                 ])),
                                                                                 (crate::value!([
-                                                                                    "emit" => "identifier",
-                                                                                    "value" => "_"
-                                                                                ])),
-                                                                                (crate::value!([
-                                                                                    "emit" => "value_instance",
+                                                                                    "emit" => "call",
                                                                                     "children" =>
                                                                                         (crate::value!([
                                                                                             (crate::value!([
                                                                                                 "emit" => "identifier",
-                                                                                                "value" => "Peek"
+                                                                                                "value" => "Recover"
                                                                                             ])),
                                                                                             (crate::value!([
-                                                                                                "emit" => "instarg",
+                                                                                                "emit" => "callarg",
                                                                                                 "children" =>
                                                                                                     (crate::value!([
                                                                                                         "emit" => "identifier",
-                                                                                                        "value" => "T_EOL"
+                                                                                                        "value" => "e"
                                                                                                     ]))
                                                                                             ]))
                                                                                         ]))
                                                                                 ]))
                                                                             ]))
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "Sequences"
-                                                                    ]))
-                                                                ]))
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "value_instance",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "Expect"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "instarg",
-                                                                        "children" =>
-                                                                            (crate::value!([
-                                                                                "emit" => "identifier",
-                                                                                "value" => "T_EOL"
-                                                                            ]))
-                                                                    ]))
-                                                                ]))
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "call",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    (crate::value!([
-                                                                        "emit" => "identifier",
-                                                                        "value" => "ast"
-                                                                    ])),
-                                                                    (crate::value!([
-                                                                        "emit" => "callarg",
-                                                                        "children" =>
-                                                                            (crate::value!([
-                                                                                "emit" => "value_string",
-                                                                                "value" => "constant"
-                                                                            ]))
                                                                     ]))
-                                                                ]))
-                                                        ]))
-                                                    ]))
-                                            ])),
-                                            (crate::value!([
-                                                "emit" => "sequence",
-                                                "children" =>
-                                                    (crate::value!([
-                                                        (crate::value!([
-                                                            "emit" => "identifier",
-                                                            "value" => "Sequences"
-                                                        ])),
-                                                        (crate::value!([
-                                                            "emit" => "op_mod_opt",
-                                                            "children" =>
-                                                                (crate::value!([
-                                                                    "emit" => "identifier",
-                                                                    "value" => "T_EOL"
-                                                                ]))
+                                                            ]))
                                                         ]))
-                                                    ]))
+                                                ]))
                                             ]))
-                                        ]))
+                                    ]))
                                 ]))
                         ]))
                     ]))
@@ -8891,8 +12026,22 @@ crate::value!([
                             "emit" => "op_mod_kle",
                             "children" =>
                                 (crate::value!([
-                                    "emit" => "identifier",
-                                    "value" => "Tokay"
+                                    "emit" => "value_instance",
+                                    "children" =>
+                                        (crate::value!([
+                                            (crate::value!([
+                                                "emit" => "identifier",
+                                                "value" => "Tokay"
+                                            ])),
+                                            (crate::value!([
+                                                "emit" => "instarg",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        "emit" => "identifier",
+                                                        "value" => "EOF"
+                                                    ]))
+                                            ]))
+                                        ]))
                                 ]))
                         ])),
                         (crate::value!([
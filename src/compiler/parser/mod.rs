@@ -1,5 +1,5 @@
 use super::*;
-use crate::error::Error;
+use crate::error::{Error, ErrorKind};
 use crate::reader::Reader;
 use crate::value::{Dict, RefValue};
 use log;
@@ -55,7 +55,10 @@ impl Parser {
         Self(PARSER.with(|parser| parser.clone()))
     }
 
-    pub fn parse(&self, mut reader: Reader) -> Result<RefValue, Error> {
+    /** Parses a Tokay program from a reader into its abstract syntax tree.
+
+    The parser recovers from syntax errors, so that all of them are returned. */
+    pub fn parse(&self, mut reader: Reader) -> Result<RefValue, Vec<Error>> {
         let program = self.0.borrow();
         let mut thread = Thread::new(&*program, vec![&mut reader]);
 
@@ -65,17 +68,36 @@ impl Parser {
             thread.debug = 0;
         }
 
-        match thread.run() {
-            Ok(Some(ast)) => {
+        let ret = thread.run();
+
+        // Syntax errors the parser recovered from fail the parse, followed by a final error
+        let mut errors: Vec<Error> = thread.errors.drain(..).collect();
+
+        match ret {
+            Ok(Some(ast)) if errors.is_empty() => {
                 if ast.borrow().object::<Dict>().is_some() {
-                    Ok(ast)
-                } else {
-                    Err(Error::new(None, "Parse error".to_string()))
+                    return Ok(ast);
                 }
+
+                errors.push(Error::new(None, "Parse error".to_string()));
             }
-            Ok(None) => Ok(crate::value!(void)),
-            Err(error) => Err(error),
+            Ok(None) if errors.is_empty() => return Ok(crate::value!(void)),
+            Ok(_) => {}
+            Err(error) => errors.push(error),
         }
+
+        Err(errors
+            .into_iter()
+            .map(|mut error| {
+                // The parser's input is the program's source
+                if let Some(input) = error.input.take() {
+                    error.source = Some(input);
+                }
+
+                error.backtrace.clear();
+                error.with_kind(ErrorKind::Syntax)
+            })
+            .collect())
     }
 }
 
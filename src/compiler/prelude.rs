@@ -11,7 +11,7 @@ impl Compiler {
     pub(super) fn load_prelude(&mut self) {
         // fixme: Make this lazy_static, so its created only once!
         let ast =
-            /*GENERATE tokay -vmode=ast2rust -vlevel=3 src/compiler/Tokay.tok -- src/prelude.tok */
+            /*GENERATE tokay -vmode=ast2rust -vlevel=3 src/compiler/parser/Tokay.tok -- src/prelude.tok */
             crate::value!([
                 "emit" => "main",
                 "children" =>
@@ -223,15 +223,21 @@ impl Compiler {
                                                                                                                                             "emit" => "instarg",
                                                                                                                                             "children" =>
                                                                                                                                                 (crate::value!([
-                                                                                                                                                    "emit" => "block",
+                                                                                                                                                    "emit" => "op_binary_bitor",
                                                                                                                                                     "children" =>
                                                                                                                                                         (crate::value!([
                                                                                                                                                             (crate::value!([
-                                                                                                                                                                "emit" => "identifier",
-                                                                                                                                                                "value" => "Token"
-                                                                                                                                                            ])),
-                                                                                                                                                            (crate::value!([
-                                                                                                                                                                "emit" => "value_token_any"
+                                                                                                                                                                "emit" => "op_binary_bitor",
+                                                                                                                                                                "children" =>
+                                                                                                                                                                    (crate::value!([
+                                                                                                                                                                        (crate::value!([
+                                                                                                                                                                            "emit" => "identifier",
+                                                                                                                                                                            "value" => "Token"
+                                                                                                                                                                        ])),
+                                                                                                                                                                        (crate::value!([
+                                                                                                                                                                            "emit" => "value_token_any"
+                                                                                                                                                                        ]))
+                                                                                                                                                                    ]))
                                                                                                                                                             ])),
                                                                                                                                                             (crate::value!([
                                                                                                                                                                 "emit" => "value_string",
@@ -258,6 +264,255 @@ impl Compiler {
                                     ]))
                                 ]))
                         ])),
+                        (crate::value!([
+                            "emit" => "constant",
+                            "children" =>
+                                (crate::value!([
+                                    (crate::value!([
+                                        "emit" => "identifier",
+                                        "value" => "ExpectAndRecover"
+                                    ])),
+                                    (crate::value!([
+                                        "emit" => "value_parselet",
+                                        "children" =>
+                                            (crate::value!([
+                                                (crate::value!([
+                                                    "emit" => "gen",
+                                                    "children" =>
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "P"
+                                                        ]))
+                                                ])),
+                                                (crate::value!([
+                                                    "emit" => "gen",
+                                                    "children" =>
+                                                        (crate::value!([
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "Recover"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "value_token_touch",
+                                                                "value" => "\n"
+                                                            ]))
+                                                        ]))
+                                                ])),
+                                                (crate::value!([
+                                                    "emit" => "sig",
+                                                    "children" =>
+                                                        (crate::value!([
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "msg"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "value_void"
+                                                            ]))
+                                                        ]))
+                                                ])),
+                                                (crate::value!([
+                                                    "emit" => "body",
+                                                    "children" =>
+                                                        (crate::value!([
+                                                            (crate::value!([
+                                                                "emit" => "op_accept",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        "emit" => "identifier",
+                                                                        "value" => "P"
+                                                                    ]))
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "sequence",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "call",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    (crate::value!([
+                                                                                        "emit" => "identifier",
+                                                                                        "value" => "error"
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "callarg",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                "emit" => "op_logical_or",
+                                                                                                "children" =>
+                                                                                                    (crate::value!([
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "identifier",
+                                                                                                            "value" => "msg"
+                                                                                                        ])),
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "op_binary_add",
+                                                                                                            "children" =>
+                                                                                                                (crate::value!([
+                                                                                                                    (crate::value!([
+                                                                                                                        "emit" => "op_binary_add",
+                                                                                                                        "children" =>
+                                                                                                                            (crate::value!([
+                                                                                                                                (crate::value!([
+                                                                                                                                    "emit" => "op_binary_add",
+                                                                                                                                    "children" =>
+                                                                                                                                        (crate::value!([
+                                                                                                                                            (crate::value!([
+                                                                                                                                                "emit" => "value_string",
+                                                                                                                                                "value" => "Expecting "
+                                                                                                                                            ])),
+                                                                                                                                            (crate::value!([
+                                                                                                                                                "emit" => "op_deref",
+                                                                                                                                                "children" =>
+                                                                                                                                                    (crate::value!([
+                                                                                                                                                        "emit" => "identifier",
+                                                                                                                                                        "value" => "P"
+                                                                                                                                                    ]))
+                                                                                                                                            ]))
+                                                                                                                                        ]))
+                                                                                                                                ])),
+                                                                                                                                (crate::value!([
+                                                                                                                                    "emit" => "value_string",
+                                                                                                                                    "value" => ", but got "
+                                                                                                                                ]))
+                                                                                                                            ]))
+                                                                                                                    ])),
+                                                                                                                    (crate::value!([
+                                                                                                                        "emit" => "call",
+                                                                                                                        "children" =>
+                                                                                                                            (crate::value!([
+                                                                                                                                (crate::value!([
+                                                                                                                                    "emit" => "identifier",
+                                                                                                                                    "value" => "repr"
+                                                                                                                                ])),
+                                                                                                                                (crate::value!([
+                                                                                                                                    "emit" => "callarg",
+                                                                                                                                    "children" =>
+                                                                                                                                        (crate::value!([
+                                                                                                                                            "emit" => "value_instance",
+                                                                                                                                            "children" =>
+                                                                                                                                                (crate::value!([
+                                                                                                                                                    (crate::value!([
+                                                                                                                                                        "emit" => "identifier",
+                                                                                                                                                        "value" => "Peek"
+                                                                                                                                                    ])),
+                                                                                                                                                    (crate::value!([
+                                                                                                                                                        "emit" => "instarg",
+                                                                                                                                                        "children" =>
+                                                                                                                                                            (crate::value!([
+                                                                                                                                                                "emit" => "op_binary_bitor",
+                                                                                                                                                                "children" =>
+                                                                                                                                                                    (crate::value!([
+                                                                                                                                                                        (crate::value!([
+                                                                                                                                                                            "emit" => "op_binary_bitor",
+                                                                                                                                                                            "children" =>
+                                                                                                                                                                                (crate::value!([
+                                                                                                                                                                                    (crate::value!([
+                                                                                                                                                                                        "emit" => "identifier",
+                                                                                                                                                                                        "value" => "Token"
+                                                                                                                                                                                    ])),
+                                                                                                                                                                                    (crate::value!([
+                                                                                                                                                                                        "emit" => "value_token_any"
+                                                                                                                                                                                    ]))
+                                                                                                                                                                                ]))
+                                                                                                                                                                        ])),
+                                                                                                                                                                        (crate::value!([
+                                                                                                                                                                            "emit" => "value_string",
+                                                                                                                                                                            "value" => "end-of-file"
+                                                                                                                                                                        ]))
+                                                                                                                                                                    ]))
+                                                                                                                                                            ]))
+                                                                                                                                                    ]))
+                                                                                                                                                ]))
+                                                                                                                                        ]))
+                                                                                                                                ]))
+                                                                                                                            ]))
+                                                                                                                    ]))
+                                                                                                                ]))
+                                                                                                        ]))
+                                                                                                    ]))
+                                                                                            ]))
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "callarg_named",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "identifier",
+                                                                                                    "value" => "recover"
+                                                                                                ])),
+                                                                                                (crate::value!([
+                                                                                                    "emit" => "value_true"
+                                                                                                ]))
+                                                                                            ]))
+                                                                                    ]))
+                                                                                ]))
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "op_loop",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "block",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            (crate::value!([
+                                                                                                "emit" => "sequence",
+                                                                                                "children" =>
+                                                                                                    (crate::value!([
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "value_instance",
+                                                                                                            "children" =>
+                                                                                                                (crate::value!([
+                                                                                                                    (crate::value!([
+                                                                                                                        "emit" => "identifier",
+                                                                                                                        "value" => "Peek"
+                                                                                                                    ])),
+                                                                                                                    (crate::value!([
+                                                                                                                        "emit" => "instarg",
+                                                                                                                        "children" =>
+                                                                                                                            (crate::value!([
+                                                                                                                                "emit" => "identifier",
+                                                                                                                                "value" => "Recover"
+                                                                                                                            ]))
+                                                                                                                    ]))
+                                                                                                                ]))
+                                                                                                        ])),
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "op_break"
+                                                                                                        ]))
+                                                                                                    ]))
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "sequence",
+                                                                                                "children" =>
+                                                                                                    (crate::value!([
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "identifier",
+                                                                                                            "value" => "EOF"
+                                                                                                        ])),
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "op_break"
+                                                                                                        ]))
+                                                                                                    ]))
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "value_token_any"
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ]))
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "value_void"
+                                                                        ]))
+                                                                    ]))
+                                                            ]))
+                                                        ]))
+                                                ]))
+                                            ]))
+                                    ]))
+                                ]))
+                        ])),
                         (crate::value!([
                             "emit" => "constant",
                             "children" =>
@@ -855,7 +1110,7 @@ impl Compiler {
                                                     "emit" => "body",
                                                     "children" =>
                                                         (crate::value!([
-                                                            "emit" => "block",
+                                                            "emit" => "op_binary_bitor",
                                                             "children" =>
                                                                 (crate::value!([
                                                                     (crate::value!([
@@ -1069,7 +1324,7 @@ impl Compiler {
                                                                                     "emit" => "instarg",
                                                                                     "children" =>
                                                                                         (crate::value!([
-                                                                                            "emit" => "block",
+                                                                                            "emit" => "op_binary_bitor",
                                                                                             "children" =>
                                                                                                 (crate::value!([
                                                                                                     (crate::value!([
@@ -1098,43 +1353,1264 @@ impl Compiler {
                                 (crate::value!([
                                     (crate::value!([
                                         "emit" => "identifier",
-                                        "value" => "Number"
-                                    ])),
-                                    (crate::value!([
-                                        "emit" => "block",
-                                        "children" =>
-                                            (crate::value!([
-                                                (crate::value!([
-                                                    "emit" => "identifier",
-                                                    "value" => "Float"
-                                                ])),
-                                                (crate::value!([
-                                                    "emit" => "identifier",
-                                                    "value" => "Int"
-                                                ]))
-                                            ]))
-                                    ]))
-                                ]))
-                        ])),
-                        (crate::value!([
-                            "emit" => "constant",
-                            "children" =>
-                                (crate::value!([
-                                    (crate::value!([
-                                        "emit" => "identifier",
-                                        "value" => "Token"
+                                        "value" => "EscapeSequence"
                                     ])),
                                     (crate::value!([
-                                        "emit" => "block",
+                                        "emit" => "value_parselet",
                                         "children" =>
                                             (crate::value!([
-                                                (crate::value!([
-                                                    "emit" => "identifier",
-                                                    "value" => "AsciiPunctuation"
-                                                ])),
-                                                (crate::value!([
-                                                    "emit" => "identifier",
-                                                    "value" => "Word"
+                                                "emit" => "body",
+                                                "children" =>
+                                                    (crate::value!([
+                                                        (crate::value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "a"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "value_string",
+                                                                        "value" => ""
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "b"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "value_string",
+                                                                        "value" => ""
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "f"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "value_string",
+                                                                        "value" => ""
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "n"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "value_string",
+                                                                        "value" => "\n"
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "r"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "value_string",
+                                                                        "value" => "\r"
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "t"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "value_string",
+                                                                        "value" => "\t"
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "v"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "value_string",
+                                                                        "value" => ""
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "value_token_ccl",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "ccl",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        "emit" => "range",
+                                                                                        "value" => "07"
+                                                                                    ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "value_token_ccl",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "ccl",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        "emit" => "range",
+                                                                                        "value" => "07"
+                                                                                    ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "value_token_ccl",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "ccl",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        "emit" => "range",
+                                                                                        "value" => "07"
+                                                                                    ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "call",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                (crate::value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "chr"
+                                                                                ])),
+                                                                                (crate::value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            "emit" => "op_binary_add",
+                                                                                            "children" =>
+                                                                                                (crate::value!([
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "op_binary_add",
+                                                                                                        "children" =>
+                                                                                                            (crate::value!([
+                                                                                                                (crate::value!([
+                                                                                                                    "emit" => "op_binary_mul",
+                                                                                                                    "children" =>
+                                                                                                                        (crate::value!([
+                                                                                                                            (crate::value!([
+                                                                                                                                "emit" => "call",
+                                                                                                                                "children" =>
+                                                                                                                                    (crate::value!([
+                                                                                                                                        (crate::value!([
+                                                                                                                                            "emit" => "identifier",
+                                                                                                                                            "value" => "int"
+                                                                                                                                        ])),
+                                                                                                                                        (crate::value!([
+                                                                                                                                            "emit" => "callarg",
+                                                                                                                                            "children" =>
+                                                                                                                                                (crate::value!([
+                                                                                                                                                    "emit" => "capture_index",
+                                                                                                                                                    "children" =>
+                                                                                                                                                        (crate::value!([
+                                                                                                                                                            "emit" => "value_integer",
+                                                                                                                                                            "value" => 1
+                                                                                                                                                        ]))
+                                                                                                                                                ]))
+                                                                                                                                        ]))
+                                                                                                                                    ]))
+                                                                                                                            ])),
+                                                                                                                            (crate::value!([
+                                                                                                                                "emit" => "value_integer",
+                                                                                                                                "value" => 64
+                                                                                                                            ]))
+                                                                                                                        ]))
+                                                                                                                ])),
+                                                                                                                (crate::value!([
+                                                                                                                    "emit" => "op_binary_mul",
+                                                                                                                    "children" =>
+                                                                                                                        (crate::value!([
+                                                                                                                            (crate::value!([
+                                                                                                                                "emit" => "call",
+                                                                                                                                "children" =>
+                                                                                                                                    (crate::value!([
+                                                                                                                                        (crate::value!([
+                                                                                                                                            "emit" => "identifier",
+                                                                                                                                            "value" => "int"
+                                                                                                                                        ])),
+                                                                                                                                        (crate::value!([
+                                                                                                                                            "emit" => "callarg",
+                                                                                                                                            "children" =>
+                                                                                                                                                (crate::value!([
+                                                                                                                                                    "emit" => "capture_index",
+                                                                                                                                                    "children" =>
+                                                                                                                                                        (crate::value!([
+                                                                                                                                                            "emit" => "value_integer",
+                                                                                                                                                            "value" => 2
+                                                                                                                                                        ]))
+                                                                                                                                                ]))
+                                                                                                                                        ]))
+                                                                                                                                    ]))
+                                                                                                                            ])),
+                                                                                                                            (crate::value!([
+                                                                                                                                "emit" => "value_integer",
+                                                                                                                                "value" => 8
+                                                                                                                            ]))
+                                                                                                                        ]))
+                                                                                                                ]))
+                                                                                                            ]))
+                                                                                                    ])),
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "call",
+                                                                                                        "children" =>
+                                                                                                            (crate::value!([
+                                                                                                                (crate::value!([
+                                                                                                                    "emit" => "identifier",
+                                                                                                                    "value" => "int"
+                                                                                                                ])),
+                                                                                                                (crate::value!([
+                                                                                                                    "emit" => "callarg",
+                                                                                                                    "children" =>
+                                                                                                                        (crate::value!([
+                                                                                                                            "emit" => "capture_index",
+                                                                                                                            "children" =>
+                                                                                                                                (crate::value!([
+                                                                                                                                    "emit" => "value_integer",
+                                                                                                                                    "value" => 3
+                                                                                                                                ]))
+                                                                                                                        ]))
+                                                                                                                ]))
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "x"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "value_token_ccl",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "ccl",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "09"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "AF"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "af"
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "value_token_ccl",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "ccl",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "09"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "AF"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "af"
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "call",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                (crate::value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "chr"
+                                                                                ])),
+                                                                                (crate::value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            "emit" => "call",
+                                                                                            "children" =>
+                                                                                                (crate::value!([
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "int"
+                                                                                                    ])),
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "callarg",
+                                                                                                        "children" =>
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "op_binary_add",
+                                                                                                                "children" =>
+                                                                                                                    (crate::value!([
+                                                                                                                        (crate::value!([
+                                                                                                                            "emit" => "value_string",
+                                                                                                                            "value" => "0x"
+                                                                                                                        ])),
+                                                                                                                        (crate::value!([
+                                                                                                                            "emit" => "call",
+                                                                                                                            "children" =>
+                                                                                                                                (crate::value!([
+                                                                                                                                    (crate::value!([
+                                                                                                                                        "emit" => "rvalue",
+                                                                                                                                        "children" =>
+                                                                                                                                            (crate::value!([
+                                                                                                                                                (crate::value!([
+                                                                                                                                                    "emit" => "capture_index",
+                                                                                                                                                    "children" =>
+                                                                                                                                                        (crate::value!([
+                                                                                                                                                            "emit" => "value_integer",
+                                                                                                                                                            "value" => 0
+                                                                                                                                                        ]))
+                                                                                                                                                ])),
+                                                                                                                                                (crate::value!([
+                                                                                                                                                    "emit" => "attribute",
+                                                                                                                                                    "children" =>
+                                                                                                                                                        (crate::value!([
+                                                                                                                                                            "emit" => "value_string",
+                                                                                                                                                            "value" => "substr"
+                                                                                                                                                        ]))
+                                                                                                                                                ]))
+                                                                                                                                            ]))
+                                                                                                                                    ])),
+                                                                                                                                    (crate::value!([
+                                                                                                                                        "emit" => "callarg",
+                                                                                                                                        "children" =>
+                                                                                                                                            (crate::value!([
+                                                                                                                                                "emit" => "value_integer",
+                                                                                                                                                "value" => 1
+                                                                                                                                            ]))
+                                                                                                                                    ]))
+                                                                                                                                ]))
+                                                                                                                        ]))
+                                                                                                                    ]))
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "u"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "value_token_ccl",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "ccl",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "09"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "AF"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "af"
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "value_token_ccl",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "ccl",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "09"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "AF"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "af"
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "value_token_ccl",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "ccl",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "09"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "AF"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "af"
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "value_token_ccl",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "ccl",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "09"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "AF"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "af"
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "call",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                (crate::value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "chr"
+                                                                                ])),
+                                                                                (crate::value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            "emit" => "call",
+                                                                                            "children" =>
+                                                                                                (crate::value!([
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "int"
+                                                                                                    ])),
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "callarg",
+                                                                                                        "children" =>
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "op_binary_add",
+                                                                                                                "children" =>
+                                                                                                                    (crate::value!([
+                                                                                                                        (crate::value!([
+                                                                                                                            "emit" => "value_string",
+                                                                                                                            "value" => "0x"
+                                                                                                                        ])),
+                                                                                                                        (crate::value!([
+                                                                                                                            "emit" => "call",
+                                                                                                                            "children" =>
+                                                                                                                                (crate::value!([
+                                                                                                                                    (crate::value!([
+                                                                                                                                        "emit" => "rvalue",
+                                                                                                                                        "children" =>
+                                                                                                                                            (crate::value!([
+                                                                                                                                                (crate::value!([
+                                                                                                                                                    "emit" => "capture_index",
+                                                                                                                                                    "children" =>
+                                                                                                                                                        (crate::value!([
+                                                                                                                                                            "emit" => "value_integer",
+                                                                                                                                                            "value" => 0
+                                                                                                                                                        ]))
+                                                                                                                                                ])),
+                                                                                                                                                (crate::value!([
+                                                                                                                                                    "emit" => "attribute",
+                                                                                                                                                    "children" =>
+                                                                                                                                                        (crate::value!([
+                                                                                                                                                            "emit" => "value_string",
+                                                                                                                                                            "value" => "substr"
+                                                                                                                                                        ]))
+                                                                                                                                                ]))
+                                                                                                                                            ]))
+                                                                                                                                    ])),
+                                                                                                                                    (crate::value!([
+                                                                                                                                        "emit" => "callarg",
+                                                                                                                                        "children" =>
+                                                                                                                                            (crate::value!([
+                                                                                                                                                "emit" => "value_integer",
+                                                                                                                                                "value" => 1
+                                                                                                                                            ]))
+                                                                                                                                    ]))
+                                                                                                                                ]))
+                                                                                                                        ]))
+                                                                                                                    ]))
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "sequence",
+                                                            "children" =>
+                                                                (crate::value!([
+                                                                    (crate::value!([
+                                                                        "emit" => "value_token_touch",
+                                                                        "value" => "U"
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "value_token_ccl",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "ccl",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "09"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "AF"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "af"
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "value_token_ccl",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "ccl",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "09"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "AF"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "af"
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "value_token_ccl",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "ccl",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "09"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "AF"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "af"
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "value_token_ccl",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "ccl",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "09"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "AF"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "af"
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "value_token_ccl",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "ccl",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "09"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "AF"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "af"
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "value_token_ccl",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "ccl",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "09"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "AF"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "af"
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "value_token_ccl",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "ccl",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "09"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "AF"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "af"
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "value_token_ccl",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                "emit" => "ccl",
+                                                                                "children" =>
+                                                                                    (crate::value!([
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "09"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "AF"
+                                                                                        ])),
+                                                                                        (crate::value!([
+                                                                                            "emit" => "range",
+                                                                                            "value" => "af"
+                                                                                        ]))
+                                                                                    ]))
+                                                                            ]))
+                                                                    ])),
+                                                                    (crate::value!([
+                                                                        "emit" => "call",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                (crate::value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "chr"
+                                                                                ])),
+                                                                                (crate::value!([
+                                                                                    "emit" => "callarg",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            "emit" => "call",
+                                                                                            "children" =>
+                                                                                                (crate::value!([
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "identifier",
+                                                                                                        "value" => "int"
+                                                                                                    ])),
+                                                                                                    (crate::value!([
+                                                                                                        "emit" => "callarg",
+                                                                                                        "children" =>
+                                                                                                            (crate::value!([
+                                                                                                                "emit" => "op_binary_add",
+                                                                                                                "children" =>
+                                                                                                                    (crate::value!([
+                                                                                                                        (crate::value!([
+                                                                                                                            "emit" => "value_string",
+                                                                                                                            "value" => "0x"
+                                                                                                                        ])),
+                                                                                                                        (crate::value!([
+                                                                                                                            "emit" => "call",
+                                                                                                                            "children" =>
+                                                                                                                                (crate::value!([
+                                                                                                                                    (crate::value!([
+                                                                                                                                        "emit" => "rvalue",
+                                                                                                                                        "children" =>
+                                                                                                                                            (crate::value!([
+                                                                                                                                                (crate::value!([
+                                                                                                                                                    "emit" => "capture_index",
+                                                                                                                                                    "children" =>
+                                                                                                                                                        (crate::value!([
+                                                                                                                                                            "emit" => "value_integer",
+                                                                                                                                                            "value" => 0
+                                                                                                                                                        ]))
+                                                                                                                                                ])),
+                                                                                                                                                (crate::value!([
+                                                                                                                                                    "emit" => "attribute",
+                                                                                                                                                    "children" =>
+                                                                                                                                                        (crate::value!([
+                                                                                                                                                            "emit" => "value_string",
+                                                                                                                                                            "value" => "substr"
+                                                                                                                                                        ]))
+                                                                                                                                                ]))
+                                                                                                                                            ]))
+                                                                                                                                    ])),
+                                                                                                                                    (crate::value!([
+                                                                                                                                        "emit" => "callarg",
+                                                                                                                                        "children" =>
+                                                                                                                                            (crate::value!([
+                                                                                                                                                "emit" => "value_integer",
+                                                                                                                                                "value" => 1
+                                                                                                                                            ]))
+                                                                                                                                    ]))
+                                                                                                                                ]))
+                                                                                                                        ]))
+                                                                                                                    ]))
+                                                                                                            ]))
+                                                                                                    ]))
+                                                                                                ]))
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
+                                                                ]))
+                                                        ])),
+                                                        (crate::value!([
+                                                            "emit" => "value_token_any"
+                                                        ]))
+                                                    ]))
+                                            ]))
+                                    ]))
+                                ]))
+                        ])),
+                        (crate::value!([
+                            "emit" => "constant",
+                            "children" =>
+                                (crate::value!([
+                                    (crate::value!([
+                                        "emit" => "identifier",
+                                        "value" => "Until"
+                                    ])),
+                                    (crate::value!([
+                                        "emit" => "value_parselet",
+                                        "children" =>
+                                            (crate::value!([
+                                                (crate::value!([
+                                                    "emit" => "gen",
+                                                    "children" =>
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "P"
+                                                        ]))
+                                                ])),
+                                                (crate::value!([
+                                                    "emit" => "gen",
+                                                    "children" =>
+                                                        (crate::value!([
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "Escape"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "value_token_touch",
+                                                                "value" => "\\"
+                                                            ]))
+                                                        ]))
+                                                ])),
+                                                (crate::value!([
+                                                    "emit" => "body",
+                                                    "children" =>
+                                                        (crate::value!([
+                                                            (crate::value!([
+                                                                "emit" => "assign_drop",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "lvalue",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "res"
+                                                                                ]))
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "value_string",
+                                                                            "value" => ""
+                                                                        ]))
+                                                                    ]))
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "op_loop",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        "emit" => "block",
+                                                                        "children" =>
+                                                                            (crate::value!([
+                                                                                (crate::value!([
+                                                                                    "emit" => "sequence",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            (crate::value!([
+                                                                                                "emit" => "value_instance",
+                                                                                                "children" =>
+                                                                                                    (crate::value!([
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "identifier",
+                                                                                                            "value" => "Peek"
+                                                                                                        ])),
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "instarg",
+                                                                                                            "children" =>
+                                                                                                                (crate::value!([
+                                                                                                                    "emit" => "identifier",
+                                                                                                                    "value" => "P"
+                                                                                                                ]))
+                                                                                                        ]))
+                                                                                                    ]))
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "op_break"
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ])),
+                                                                                (crate::value!([
+                                                                                    "emit" => "sequence",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            (crate::value!([
+                                                                                                "emit" => "identifier",
+                                                                                                "value" => "EOF"
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "op_break"
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ])),
+                                                                                (crate::value!([
+                                                                                    "emit" => "sequence",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            (crate::value!([
+                                                                                                "emit" => "identifier",
+                                                                                                "value" => "Escape"
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "identifier",
+                                                                                                "value" => "EscapeSequence"
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "assign_add_drop",
+                                                                                                "children" =>
+                                                                                                    (crate::value!([
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "lvalue",
+                                                                                                            "children" =>
+                                                                                                                (crate::value!([
+                                                                                                                    "emit" => "identifier",
+                                                                                                                    "value" => "res"
+                                                                                                                ]))
+                                                                                                        ])),
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "capture_index",
+                                                                                                            "children" =>
+                                                                                                                (crate::value!([
+                                                                                                                    "emit" => "value_integer",
+                                                                                                                    "value" => 2
+                                                                                                                ]))
+                                                                                                        ]))
+                                                                                                    ]))
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ])),
+                                                                                (crate::value!([
+                                                                                    "emit" => "sequence",
+                                                                                    "children" =>
+                                                                                        (crate::value!([
+                                                                                            (crate::value!([
+                                                                                                "emit" => "value_token_any"
+                                                                                            ])),
+                                                                                            (crate::value!([
+                                                                                                "emit" => "assign_add_drop",
+                                                                                                "children" =>
+                                                                                                    (crate::value!([
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "lvalue",
+                                                                                                            "children" =>
+                                                                                                                (crate::value!([
+                                                                                                                    "emit" => "identifier",
+                                                                                                                    "value" => "res"
+                                                                                                                ]))
+                                                                                                        ])),
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "capture_index",
+                                                                                                            "children" =>
+                                                                                                                (crate::value!([
+                                                                                                                    "emit" => "value_integer",
+                                                                                                                    "value" => 1
+                                                                                                                ]))
+                                                                                                        ]))
+                                                                                                    ]))
+                                                                                            ]))
+                                                                                        ]))
+                                                                                ]))
+                                                                            ]))
+                                                                    ]))
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "op_if",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "op_unary_not",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "identifier",
+                                                                                    "value" => "res"
+                                                                                ]))
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "op_reject"
+                                                                        ]))
+                                                                    ]))
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "res"
+                                                            ]))
+                                                        ]))
+                                                ]))
+                                            ]))
+                                    ]))
+                                ]))
+                        ])),
+                        (crate::value!([
+                            "emit" => "constant",
+                            "children" =>
+                                (crate::value!([
+                                    (crate::value!([
+                                        "emit" => "identifier",
+                                        "value" => "String"
+                                    ])),
+                                    (crate::value!([
+                                        "emit" => "value_parselet",
+                                        "children" =>
+                                            (crate::value!([
+                                                (crate::value!([
+                                                    "emit" => "gen",
+                                                    "children" =>
+                                                        (crate::value!([
+                                                            "emit" => "identifier",
+                                                            "value" => "Start"
+                                                        ]))
+                                                ])),
+                                                (crate::value!([
+                                                    "emit" => "gen",
+                                                    "children" =>
+                                                        (crate::value!([
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "End"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "Start"
+                                                            ]))
+                                                        ]))
+                                                ])),
+                                                (crate::value!([
+                                                    "emit" => "gen",
+                                                    "children" =>
+                                                        (crate::value!([
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "Escape"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "value_token_touch",
+                                                                "value" => "\\"
+                                                            ]))
+                                                        ]))
+                                                ])),
+                                                (crate::value!([
+                                                    "emit" => "body",
+                                                    "children" =>
+                                                        (crate::value!([
+                                                            (crate::value!([
+                                                                "emit" => "sequence",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "Start"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "End"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "value_string",
+                                                                            "value" => ""
+                                                                        ]))
+                                                                    ]))
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "sequence",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "Start"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "value_instance",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    (crate::value!([
+                                                                                        "emit" => "identifier",
+                                                                                        "value" => "Until"
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "instarg",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                "emit" => "identifier",
+                                                                                                "value" => "End"
+                                                                                            ]))
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "instarg",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                "emit" => "identifier",
+                                                                                                "value" => "Escape"
+                                                                                            ]))
+                                                                                    ]))
+                                                                                ]))
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "identifier",
+                                                                            "value" => "End"
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "capture_index",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    "emit" => "value_integer",
+                                                                                    "value" => 2
+                                                                                ]))
+                                                                        ]))
+                                                                    ]))
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "sequence",
+                                                                "children" =>
+                                                                    (crate::value!([
+                                                                        (crate::value!([
+                                                                            "emit" => "value_instance",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    (crate::value!([
+                                                                                        "emit" => "identifier",
+                                                                                        "value" => "Peek"
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "instarg",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                "emit" => "identifier",
+                                                                                                "value" => "Start"
+                                                                                            ]))
+                                                                                    ]))
+                                                                                ]))
+                                                                        ])),
+                                                                        (crate::value!([
+                                                                            "emit" => "call",
+                                                                            "children" =>
+                                                                                (crate::value!([
+                                                                                    (crate::value!([
+                                                                                        "emit" => "identifier",
+                                                                                        "value" => "error"
+                                                                                    ])),
+                                                                                    (crate::value!([
+                                                                                        "emit" => "callarg",
+                                                                                        "children" =>
+                                                                                            (crate::value!([
+                                                                                                "emit" => "op_binary_add",
+                                                                                                "children" =>
+                                                                                                    (crate::value!([
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "value_string",
+                                                                                                            "value" => "Unclosed string, expecting "
+                                                                                                        ])),
+                                                                                                        (crate::value!([
+                                                                                                            "emit" => "op_deref",
+                                                                                                            "children" =>
+                                                                                                                (crate::value!([
+                                                                                                                    "emit" => "identifier",
+                                                                                                                    "value" => "End"
+                                                                                                                ]))
+                                                                                                        ]))
+                                                                                                    ]))
+                                                                                            ]))
+                                                                                    ]))
+                                                                                ]))
+                                                                        ]))
+                                                                    ]))
+                                                            ]))
+                                                        ]))
+                                                ]))
+                                            ]))
+                                    ]))
+                                ]))
+                        ])),
+                        (crate::value!([
+                            "emit" => "constant",
+                            "children" =>
+                                (crate::value!([
+                                    (crate::value!([
+                                        "emit" => "identifier",
+                                        "value" => "Number"
+                                    ])),
+                                    (crate::value!([
+                                        "emit" => "op_binary_bitor",
+                                        "children" =>
+                                            (crate::value!([
+                                                (crate::value!([
+                                                    "emit" => "identifier",
+                                                    "value" => "Float"
+                                                ])),
+                                                (crate::value!([
+                                                    "emit" => "identifier",
+                                                    "value" => "Int"
+                                                ]))
+                                            ]))
+                                    ]))
+                                ]))
+                        ])),
+                        (crate::value!([
+                            "emit" => "constant",
+                            "children" =>
+                                (crate::value!([
+                                    (crate::value!([
+                                        "emit" => "identifier",
+                                        "value" => "Token"
+                                    ])),
+                                    (crate::value!([
+                                        "emit" => "op_binary_bitor",
+                                        "children" =>
+                                            (crate::value!([
+                                                (crate::value!([
+                                                    "emit" => "op_binary_bitor",
+                                                    "children" =>
+                                                        (crate::value!([
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "AsciiPunctuation"
+                                                            ])),
+                                                            (crate::value!([
+                                                                "emit" => "identifier",
+                                                                "value" => "Word"
+                                                            ]))
+                                                        ]))
                                                 ])),
                                                 (crate::value!([
                                                     "emit" => "identifier",
@@ -6,7 +6,7 @@ Loops introduce a new loop scope.
 */
 use super::*;
 use crate::builtin::Builtin;
-use crate::error::Error;
+use crate::error::{Error, ErrorKind};
 use crate::reader::*;
 use crate::value::{RefValue, Token};
 use indexmap::IndexMap;
@@ -201,7 +201,26 @@ impl<'compiler, 'parent> Scope<'compiler, 'parent> {
 
     /// Push an Error to the scope's error log, with given offset and msg.
     pub fn push_error(&self, offset: Option<Offset>, msg: String) {
-        self.errors.borrow_mut().push(Error::new(offset, msg))
+        self.errors
+            .borrow_mut()
+            .push(Error::new(offset, msg).with_kind(ErrorKind::Syntax))
+    }
+
+    /// Push an Error for the use of an undefined name, spanning the name.
+    pub fn push_undefined(&self, usage: &ImlValue) {
+        let name = usage.to_string();
+        let mut error = Error::new(usage.offset(), format!("Use of undefined name '{}'", name))
+            .with_kind(ErrorKind::Name);
+
+        if let Some(offset) = error.offset() {
+            error = error.with_stop(Offset {
+                offset: offset.offset + name.len(),
+                row: offset.row,
+                col: offset.col + name.chars().count() as u32,
+            })
+        }
+
+        self.errors.borrow_mut().push(error)
     }
 }
 
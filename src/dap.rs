@@ -0,0 +1,580 @@
+//! Debug Adapter Protocol (DAP) server for Tokay programs
+#![cfg(feature = "dap")]
+use crate::reader::Reader;
+use crate::utils::{read_message, write_message};
+use crate::vm::{
+    self, Breakpoint, Context, DebugFrontend, Debugger, Position, Reason, Step, Thread,
+};
+use crate::{Compiler, Dict, Error, List, Object, RefValue, Reject};
+use serde_json::{Value as Json, json};
+use std::cell::RefCell;
+use std::collections::BTreeSet;
+use std::io::{self, BufRead, Write};
+use std::rc::Rc;
+use std::sync::mpsc::{Receiver, channel};
+
+// Thread id reported to the client; Tokay programs run in a single thread.
+const THREAD_ID: i64 = 1;
+
+// Variable references for scopes; Structured values are referenced starting at VALUES.
+const LOCALS: i64 = 1;
+const CAPTURES: i64 = 2;
+const GLOBALS: i64 = 3;
+const VALUES: i64 = 1000;
+
+/// Connection to a DAP client
+struct Connection {
+    requests: Receiver<Json>, // Incoming requests, read by a separate thread
+    output: Box<dyn Write>,   // Outgoing messages
+    seq: i64,                 // Sequence number of the last message sent
+    disconnected: bool,       // Client requested to disconnect
+}
+
+impl Connection {
+    fn send(&mut self, mut message: Json) {
+        self.seq += 1;
+        message["seq"] = json!(self.seq);
+
+        // A broken connection is noticed by the request reader.
+        let _ = write_message(&mut self.output, &message);
+    }
+
+    fn respond(&mut self, request: &Json, body: Json) {
+        self.send(json!({
+            "type": "response",
+            "request_seq": request["seq"],
+            "command": request["command"],
+            "success": true,
+            "body": body,
+        }))
+    }
+
+    fn fail(&mut self, request: &Json, message: &str) {
+        self.send(json!({
+            "type": "response",
+            "request_seq": request["seq"],
+            "command": request["command"],
+            "success": false,
+            "message": message,
+        }))
+    }
+
+    fn event(&mut self, event: &str, body: Json) {
+        self.send(json!({
+            "type": "event",
+            "event": event,
+            "body": body,
+        }))
+    }
+}
+
+/// Writer sending program output as output events.
+struct Output(Rc<RefCell<Connection>>);
+
+impl Write for Output {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().event(
+            "output",
+            json!({"category": "stdout", "output": String::from_utf8_lossy(buf)}),
+        );
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Debugger frontend answering DAP requests
+struct Adapter {
+    debugger: Debugger,
+    connection: Rc<RefCell<Connection>>,
+    source: Json,          // Source of the debugged program
+    values: Vec<RefValue>, // Structured values referenced while stopped
+}
+
+impl Adapter {
+    fn variable(&mut self, name: String, value: RefValue) -> Json {
+        let structured = {
+            let value = value.borrow();
+
+            value.object::<List>().is_some_and(|list| !list.is_empty())
+                || value.object::<Dict>().is_some_and(|dict| !dict.is_empty())
+        };
+
+        let reference = if structured {
+            self.values.push(value.clone());
+            VALUES + self.values.len() as i64 - 1
+        } else {
+            0
+        };
+
+        json!({
+            "name": name,
+            "value": value.repr(),
+            "type": value.name(),
+            "variablesReference": reference,
+        })
+    }
+
+    fn variables(&mut self, context: &Context, reference: i64) -> Option<Vec<Json>> {
+        let variables = match reference {
+            LOCALS => vm::locals(context),
+            CAPTURES => vm::captures(context),
+            GLOBALS => vm::globals(context),
+            _ => {
+                let value = self.values.get((reference - VALUES) as usize)?.clone();
+                let value = value.borrow();
+
+                if let Some(list) = value.object::<List>() {
+                    list.iter()
+                        .enumerate()
+                        .map(|(i, item)| (i.to_string(), item.clone()))
+                        .collect()
+                } else if let Some(dict) = value.object::<Dict>() {
+                    dict.iter()
+                        .map(|(key, item)| (key.to_string(), item.clone()))
+                        .collect()
+                } else {
+                    return None;
+                }
+            }
+        };
+
+        Some(
+            variables
+                .into_iter()
+                .map(|(name, value)| self.variable(name, value))
+                .collect(),
+        )
+    }
+
+    /** Handle a request, either while running or stopped.
+
+    Returns true when execution shall continue. */
+    fn handle(
+        &mut self,
+        request: Json,
+        mut stopped: Option<(&mut Context, &Position)>,
+    ) -> Result<bool, Reject> {
+        let connection = self.connection.clone();
+        let mut connection = connection.borrow_mut();
+        let arguments = &request["arguments"];
+
+        match request["command"].as_str().unwrap_or_default() {
+            "threads" => {
+                connection.respond(
+                    &request,
+                    json!({"threads": [{"id": THREAD_ID, "name": "main"}]}),
+                );
+            }
+            "setBreakpoints" => {
+                let lines = set_breakpoints(&mut self.debugger.breakpoints, arguments);
+
+                connection.respond(
+                    &request,
+                    json!({"breakpoints": lines
+                        .iter()
+                        .map(|line| json!({"verified": true, "line": line}))
+                        .collect::<Vec<_>>()}),
+                );
+            }
+            "setExceptionBreakpoints" => connection.respond(&request, json!({})),
+            "pause" => {
+                if stopped.is_none() {
+                    self.debugger.step = Step::Pause;
+                }
+
+                connection.respond(&request, json!({}));
+            }
+            "continue" => {
+                connection.respond(&request, json!({"allThreadsContinued": true}));
+                return Ok(stopped.is_some());
+            }
+            command @ ("next" | "stepIn" | "stepOut") => {
+                let Some((context, position)) = stopped else {
+                    connection.fail(&request, "Program is not stopped");
+                    return Ok(false);
+                };
+
+                self.debugger.step = match command {
+                    "next" => Step::Over(position.offset.row, context.depth),
+                    "stepIn" => Step::In,
+                    _ => Step::Out(context.depth),
+                };
+
+                connection.respond(&request, json!({}));
+                return Ok(true);
+            }
+            "stackTrace" => {
+                let frames: Vec<Json> = self
+                    .debugger
+                    .calls
+                    .iter()
+                    .enumerate()
+                    .rev()
+                    .map(|(id, (_, name, offset))| {
+                        json!({
+                            "id": id,
+                            "name": name,
+                            "source": self.source,
+                            "line": offset.row,
+                            "column": offset.col,
+                        })
+                    })
+                    .collect();
+
+                connection.respond(
+                    &request,
+                    json!({"totalFrames": frames.len(), "stackFrames": frames}),
+                );
+            }
+            "scopes" => {
+                let top = self.debugger.calls.len() as i64 - 1;
+                let mut scopes = Vec::new();
+
+                // Locals and captures are only available for the innermost parselet
+                if arguments["frameId"].as_i64() == Some(top) {
+                    scopes.push(
+                        json!({"name": "Locals", "variablesReference": LOCALS, "expensive": false}),
+                    );
+                    scopes.push(json!({"name": "Captures", "variablesReference": CAPTURES, "expensive": false}));
+                }
+
+                scopes.push(
+                    json!({"name": "Globals", "variablesReference": GLOBALS, "expensive": false}),
+                );
+                connection.respond(&request, json!({"scopes": scopes}));
+            }
+            "variables" => {
+                let variables = match (stopped.as_mut(), arguments["variablesReference"].as_i64()) {
+                    (Some((context, _)), Some(reference)) => self.variables(context, reference),
+                    _ => None,
+                };
+
+                match variables {
+                    Some(variables) => {
+                        connection.respond(&request, json!({"variables": variables}))
+                    }
+                    None => connection.fail(&request, "Variables not available"),
+                }
+            }
+            "evaluate" => {
+                let name = arguments["expression"].as_str().unwrap_or_default().trim();
+
+                // Only variable names and captures can be evaluated
+                let value = stopped.as_ref().and_then(|(context, _)| {
+                    vm::locals(context)
+                        .into_iter()
+                        .chain(vm::captures(context))
+                        .chain(vm::globals(context))
+                        .find(|(var, _)| var == name)
+                });
+
+                match value {
+                    Some((_, value)) => {
+                        let variable = self.variable(name.to_string(), value);
+                        connection.respond(
+                            &request,
+                            json!({
+                                "result": variable["value"],
+                                "type": variable["type"],
+                                "variablesReference": variable["variablesReference"],
+                            }),
+                        )
+                    }
+                    None => connection.fail(&request, &format!("Unknown variable '{}'", name)),
+                }
+            }
+            command @ ("disconnect" | "terminate") => {
+                connection.disconnected |= command == "disconnect";
+                connection.respond(&request, json!({}));
+
+                return Err(Error::new(None, "Program terminated by debugger".to_string()).into());
+            }
+            command => connection.fail(&request, &format!("Unsupported request '{}'", command)),
+        }
+
+        Ok(false)
+    }
+}
+
+impl DebugFrontend for Adapter {
+    fn debugger(&mut self) -> &mut Debugger {
+        &mut self.debugger
+    }
+
+    fn poll(&mut self) -> Result<(), Reject> {
+        loop {
+            let request = self.connection.borrow().requests.try_recv();
+
+            match request {
+                Ok(request) => {
+                    self.handle(request, None)?;
+                }
+                Err(_) => return Ok(()),
+            }
+        }
+    }
+
+    fn stop(&mut self, context: &mut Context, position: Position) -> Result<(), Reject> {
+        self.connection.borrow_mut().event(
+            "stopped",
+            json!({
+                "reason": match position.reason {
+                    Reason::Breakpoint => "breakpoint",
+                    Reason::Step => "step",
+                    Reason::Pause => "pause",
+                },
+                "threadId": THREAD_ID,
+                "allThreadsStopped": true,
+            }),
+        );
+
+        loop {
+            let request = self.connection.borrow().requests.recv();
+
+            let Ok(request) = request else {
+                return Err(Error::new(None, "Debugger connection closed".to_string()).into());
+            };
+
+            if self.handle(request, Some((context, &position)))? {
+                self.values.clear();
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Extract breakpoint lines from setBreakpoints arguments.
+fn breakpoint_lines(arguments: &Json) -> Vec<u32> {
+    match arguments["breakpoints"].as_array() {
+        Some(breakpoints) => breakpoints
+            .iter()
+            .filter_map(|breakpoint| breakpoint["line"].as_u64())
+            .map(|line| line as u32)
+            .collect(),
+        None => arguments["lines"]
+            .as_array()
+            .map(|lines| {
+                lines
+                    .iter()
+                    .filter_map(|line| line.as_u64())
+                    .map(|line| line as u32)
+                    .collect()
+            })
+            .unwrap_or_default(),
+    }
+}
+
+/// Replace the breakpoints of the source given by a `setBreakpoints` request, returning its lines.
+fn set_breakpoints(breakpoints: &mut BTreeSet<Breakpoint>, arguments: &Json) -> Vec<u32> {
+    let path = arguments["source"]["path"].as_str().map(String::from);
+    let lines = breakpoint_lines(arguments);
+
+    breakpoints.retain(|(filename, _)| *filename != path);
+    breakpoints.extend(lines.iter().map(|line| (path.clone(), *line)));
+    lines
+}
+
+/// Create readers from the `input` launch argument, either file names or strings.
+fn launch_readers(arguments: &Json) -> Vec<Reader> {
+    let input = match &arguments["input"] {
+        Json::String(input) => vec![input.clone()],
+        Json::Array(input) => input
+            .iter()
+            .filter_map(|input| input.as_str().map(String::from))
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    let mut readers: Vec<Reader> = input
+        .into_iter()
+        .map(|input| match std::fs::File::open(&input) {
+            Ok(file) => Reader::new(Some(input), Box::new(io::BufReader::new(file))),
+            Err(_) => Reader::new(None, Box::new(io::Cursor::new(input))),
+        })
+        .collect();
+
+    // stdin is used by the protocol, so work on an empty input by default
+    if readers.is_empty() {
+        readers.push(Reader::new(None, Box::new(io::Cursor::new(""))));
+    }
+
+    readers
+}
+
+/** Serve a single debug session on the given streams.
+
+The client initializes the session and launches a program by its path with the `launch` request.
+Supported launch arguments are `program`, `input` (file names or strings) and `stopOnEntry`.
+*/
+pub fn serve(input: Box<dyn BufRead + Send>, output: Box<dyn Write>) -> io::Result<()> {
+    let (sender, requests) = channel();
+
+    std::thread::spawn(move || {
+        let mut input = input;
+
+        while let Ok(Some(message)) = read_message(&mut *input) {
+            if sender.send(message).is_err() {
+                break;
+            }
+        }
+    });
+
+    let connection = Rc::new(RefCell::new(Connection {
+        requests,
+        output,
+        seq: 0,
+        disconnected: false,
+    }));
+
+    let mut launch = None;
+    let mut breakpoints = BTreeSet::new();
+
+    // Configuration phase
+    loop {
+        let request = connection.borrow().requests.recv();
+        let Ok(request) = request else {
+            return Ok(());
+        };
+
+        let mut connection = connection.borrow_mut();
+
+        match request["command"].as_str().unwrap_or_default() {
+            "initialize" => {
+                connection.respond(
+                    &request,
+                    json!({
+                        "supportsConfigurationDoneRequest": true,
+                        "supportsTerminateRequest": true,
+                        "supportsEvaluateForHovers": true,
+                    }),
+                );
+                connection.event("initialized", json!({}));
+            }
+            "launch" => {
+                if request["arguments"]["program"].is_string() {
+                    connection.respond(&request, json!({}));
+                    launch = Some(request["arguments"].clone());
+                } else {
+                    connection.fail(&request, "Launch requires a 'program'");
+                }
+            }
+            "setBreakpoints" => {
+                let lines = set_breakpoints(&mut breakpoints, &request["arguments"]);
+
+                connection.respond(
+                    &request,
+                    json!({"breakpoints": lines
+                        .iter()
+                        .map(|line| json!({"verified": true, "line": line}))
+                        .collect::<Vec<_>>()}),
+                );
+            }
+            "setExceptionBreakpoints" => connection.respond(&request, json!({})),
+            "threads" => connection.respond(&request, json!({"threads": []})),
+            "configurationDone" => {
+                connection.respond(&request, json!({}));
+
+                if launch.is_some() {
+                    break;
+                }
+            }
+            "disconnect" => {
+                connection.respond(&request, json!({}));
+                return Ok(());
+            }
+            command => connection.fail(&request, &format!("Unsupported request '{}'", command)),
+        }
+    }
+
+    let launch = launch.unwrap();
+    let path = launch["program"].as_str().unwrap_or_default().to_string();
+
+    let report = |message: String| {
+        connection.borrow_mut().event(
+            "output",
+            json!({"category": "stderr", "output": message + "\n"}),
+        );
+    };
+
+    let exit_code = match std::fs::File::open(&path) {
+        Err(err) => {
+            report(format!("Can't open PROGRAM file '{}': {}", path, err));
+            1
+        }
+        Ok(file) => match Compiler::new().compile(Reader::new(
+            Some(path.clone()),
+            Box::new(io::BufReader::new(file)),
+        )) {
+            Ok(None) => 0,
+            Ok(Some(program)) => {
+                let mut debugger = Debugger::new(launch["stopOnEntry"].as_bool() == Some(true));
+                debugger.breakpoints = breakpoints;
+
+                let adapter = Adapter {
+                    debugger,
+                    connection: connection.clone(),
+                    source: json!({
+                        "name": std::path::Path::new(&path).file_name().map(|name| name.to_string_lossy()),
+                        "path": std::fs::canonicalize(&path).unwrap_or(path.clone().into()),
+                    }),
+                    values: Vec::new(),
+                };
+
+                let mut readers = launch_readers(&launch);
+                let mut thread = Thread::new(&program, readers.iter_mut().collect());
+                thread.output = Some(Box::new(Output(connection.clone())));
+                thread.debugger = Some(Box::new(adapter));
+
+                let ret = thread.run();
+
+                for error in thread.errors.drain(..) {
+                    report(format!("{}", error));
+                }
+
+                match ret {
+                    Ok(_) => 0,
+                    Err(error) => {
+                        report(format!("{}", error));
+                        1
+                    }
+                }
+            }
+            Err(errors) => {
+                for error in errors {
+                    report(format!("{}", error));
+                }
+
+                1
+            }
+        },
+    };
+
+    connection
+        .borrow_mut()
+        .event("exited", json!({"exitCode": exit_code}));
+    connection.borrow_mut().event("terminated", json!({}));
+
+    // Wait for the client to disconnect
+    while !connection.borrow().disconnected {
+        let request = connection.borrow().requests.recv();
+        let Ok(request) = request else {
+            break;
+        };
+
+        let mut connection = connection.borrow_mut();
+
+        match request["command"].as_str().unwrap_or_default() {
+            "disconnect" => {
+                connection.respond(&request, json!({}));
+                connection.disconnected = true;
+            }
+            "threads" => connection.respond(&request, json!({"threads": []})),
+            _ => connection.fail(&request, "Program has terminated"),
+        }
+    }
+
+    Ok(())
+}
@@ -1,41 +1,400 @@
 //! Implementation of an error object that can occur during Tokay's program compilation or execution
-use crate::reader::Offset;
-use crate::value::{Object, Str};
+use crate::reader::{Offset, Reader, Source};
+use crate::value;
+use crate::value::{BoxedObject, Object, RefValue, Str};
+use crate::vm::{Accept, Context, Reject};
 extern crate self as tokay;
-use tokay_macros::tokay_function;
+use tokay_macros::{tokay_function, tokay_method};
 
+/// Kind of an error, describing where it originates from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ErrorKind {
+    Syntax,   // Syntax or semantic error in the Tokay program
+    Type,     // Operation not supported by a value's type
+    Name,     // Undefined or unresolved name
+    Argument, // Missing, superfluous or invalid arguments to a call
+    Io,       // Failure while reading or opening files or input
+    User,     // Error raised by the program using `error()`
+    #[default]
+    Runtime, // Any other error raised during execution
+}
+
+impl ErrorKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Syntax => "syntax",
+            Self::Type => "type",
+            Self::Name => "name",
+            Self::Argument => "argument",
+            Self::Io => "io",
+            Self::User => "user",
+            Self::Runtime => "runtime",
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Position of an error within the program's source or the input, with the line it is found in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Location {
+    pub filename: Option<String>,  // Filename of the source or input
+    pub offset: Offset,            // Start of the span
+    pub stop: Option<Offset>,      // End of the span
+    pub snippet: Option<Box<str>>, // Line containing the span's start
+}
+
+impl Location {
+    pub fn new(filename: Option<String>, offset: Offset) -> Self {
+        Self {
+            filename,
+            offset,
+            stop: None,
+            snippet: None,
+        }
+    }
+
+    /// Creates a location within the input of a reader.
+    pub fn from_reader(reader: &Reader, offset: Offset) -> Self {
+        Self {
+            filename: reader.filename.clone(),
+            offset,
+            stop: None,
+            snippet: reader.line(&offset).map(|line| line.into()),
+        }
+    }
+
+    /// Describes the position as line and column, prefixed by the filename when available.
+    fn position(&self) -> String {
+        match &self.filename {
+            Some(filename) => format!(
+                "'{}', line {}, column {}",
+                filename, self.offset.row, self.offset.col
+            ),
+            None => format!("line {}, column {}", self.offset.row, self.offset.col),
+        }
+    }
+
+    /// Renders the snippet with the span underlined.
+    fn render(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some(snippet) = &self.snippet else {
+            return Ok(());
+        };
+
+        let (offset, row) = (self.offset, self.offset.row.to_string());
+        let col = offset.col.saturating_sub(1) as usize;
+
+        let length = match self.stop {
+            Some(stop) if stop.row == offset.row && stop.col > offset.col => {
+                (stop.col - offset.col) as usize
+            }
+            Some(stop) if stop.row > offset.row => {
+                snippet.chars().count().saturating_sub(col).max(1)
+            }
+            _ => 1,
+        };
+
+        // Keep tabs, so that the caret is aligned with the snippet
+        let indent: String = snippet
+            .chars()
+            .take(col)
+            .map(|ch| if ch == '\t' { '\t' } else { ' ' })
+            .collect();
+
+        write!(
+            f,
+            "\n {} | {}\n {} | {}{}",
+            row,
+            snippet,
+            " ".repeat(row.len()),
+            indent,
+            "^".repeat(length)
+        )
+    }
+}
+
+/** Error raised during compilation or execution of a Tokay program.
+
+An error carries its kind, a message and the locations it refers to, both in the program's
+source and, at runtime, in the input. Errors raised at runtime also hold a backtrace of the
+parselets they passed through.
+
+The `Display` implementation prints a one-line message. The alternate form `{:#}` additionally
+renders the line of the error with its span underlined, the other location and the backtrace.
+*/
 #[derive(Debug, Clone, PartialEq)]
 pub struct Error {
-    pub offset: Option<Offset>,
+    pub kind: ErrorKind,
     pub message: String,
+    pub source: Option<Box<Location>>, // Location in the program's source
+    pub input: Option<Box<Location>>,  // Location in the input
+    pub backtrace: Vec<(String, Option<Offset>)>, // Parselets and source offsets, innermost first
 }
 
 impl Error {
     /// Creates a new Error object with a message.
     pub fn new(offset: Option<Offset>, message: String) -> Error {
-        Error { offset, message }
+        Error {
+            kind: ErrorKind::default(),
+            message,
+            source: offset.map(|offset| Box::new(Location::new(None, offset))),
+            input: None,
+            backtrace: Vec::new(),
+        }
+    }
+
+    /// Sets the error's kind.
+    pub fn with_kind(mut self, kind: ErrorKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Sets the end of the error's span in the source.
+    pub fn with_stop(mut self, stop: Offset) -> Self {
+        if let Some(source) = &mut self.source {
+            source.stop = Some(stop);
+        }
+
+        self
+    }
+
+    /** Offset the error is reported at, which formerly was the error's `offset` field.
+
+    This is the offset of the error's primary location, see `Error::location()`. */
+    pub fn offset(&self) -> Option<Offset> {
+        self.location().map(|location| location.offset)
+    }
+
+    /** Location the error is reported at.
+
+    This is the input location for errors raised by the program using `error()` and for errors
+    reading the input, otherwise the source location. */
+    pub fn location(&self) -> Option<&Location> {
+        self.primary().0
     }
 
     /// Attaches position information to an error message when not already present
     pub fn patch_offset(&mut self, offset: Offset) {
-        if let None = self.offset {
-            self.offset = Some(offset);
+        if self.source.is_none() {
+            self.source = Some(Box::new(Location::new(None, offset)));
+        }
+    }
+
+    /** Attaches filename and snippet from the program or module source the error refers to.
+
+    This is only done for errors with a source location which is not already located. */
+    pub fn locate(&mut self, source: &Source) {
+        if let Some(location) = &mut self.source
+            && location.snippet.is_none()
+            && let Some(line) = source.line(location.offset.row)
+        {
+            location.filename = source.filename.clone();
+            location.snippet = Some(line.into());
+        }
+    }
+
+    /// Primary and secondary location of the error, see `Error::location()`.
+    fn primary(&self) -> (Option<&Location>, Option<&Location>) {
+        match (self.source.as_deref(), self.input.as_deref()) {
+            (source, Some(input))
+                if matches!(self.kind, ErrorKind::User | ErrorKind::Io) || source.is_none() =>
+            {
+                (Some(input), source)
+            }
+            (source, input) => (source, input),
         }
     }
 }
 
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if let Some(offset) = self.offset {
-            write!(
+        let (primary, secondary) = self.primary();
+
+        match primary {
+            Some(Location {
+                filename: Some(filename),
+                offset,
+                ..
+            }) => write!(
+                f,
+                "File '{}', line {}, column {}: {}",
+                filename, offset.row, offset.col, self.message
+            )?,
+            Some(Location { offset, .. }) => write!(
                 f,
                 "Line {}, column {}: {}",
                 offset.row, offset.col, self.message
-            )
+            )?,
+            None => write!(f, "{}", self.message)?,
+        }
+
+        if !f.alternate() {
+            return Ok(());
+        }
+
+        if let Some(primary) = primary {
+            primary.render(f)?;
+        }
+
+        // The backtrace is only of interest when the error passed further parselets
+        let backtrace = self.backtrace.len() > 1;
+
+        let is_input = |location: &Location| {
+            self.input
+                .as_deref()
+                .is_some_and(|input| std::ptr::eq(input, location))
+        };
+
+        match secondary {
+            // Empty input without filename isn't worth mentioning
+            Some(input)
+                if is_input(input)
+                    && (input.filename.is_some()
+                        || input.snippet.as_deref().is_some_and(|s| !s.is_empty())) =>
+            {
+                write!(f, "\n  at input {}", input.position())?
+            }
+            Some(source) if !is_input(source) && !backtrace => {
+                write!(f, "\n  at program {}", source.position())?
+            }
+            _ => {}
+        }
+
+        if backtrace {
+            for (parselet, offset) in &self.backtrace {
+                match offset {
+                    Some(offset) => write!(
+                        f,
+                        "\n  in {}, line {}, column {}",
+                        parselet, offset.row, offset.col
+                    )?,
+                    None => write!(f, "\n  in {}", parselet)?,
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Error {
+    /// Retrieves an attribute of the error object `e`, used by the attribute methods.
+    fn attr(
+        e: &RefValue,
+        function: &str,
+        attr: impl Fn(&Error) -> RefValue,
+    ) -> Result<RefValue, Error> {
+        let e = e.borrow();
+
+        if let Some(error) = e.object::<Error>() {
+            Ok(attr(error))
         } else {
-            write!(f, "{}", self.message)
+            Err(Error::from(format!(
+                "{} only accepts '{}' as parameter, not '{}'",
+                function,
+                "error",
+                e.name()
+            )))
         }
     }
+
+    // The error's message.
+    tokay_method!("error_msg : @e", {
+        Self::attr(&e, __function, |error| {
+            RefValue::from(error.message.clone())
+        })
+    });
+
+    // The error's kind, e.g. "user" or "type".
+    tokay_method!("error_kind : @e", {
+        Self::attr(&e, __function, |error| RefValue::from(error.kind.as_str()))
+    });
+
+    // Filename of the error's location, or void.
+    tokay_method!("error_filename : @e", {
+        Self::attr(&e, __function, |error| {
+            error
+                .location()
+                .and_then(|location| location.filename.clone())
+                .map_or(value!(void), RefValue::from)
+        })
+    });
+
+    // Offset of the error's location, or void.
+    tokay_method!("error_offset : @e", {
+        Self::attr(&e, __function, |error| {
+            error
+                .location()
+                .map_or(value!(void), |location| value!(location.offset.offset))
+        })
+    });
+
+    // Row of the error's location, or void.
+    tokay_method!("error_row : @e", {
+        Self::attr(&e, __function, |error| {
+            error
+                .location()
+                .map_or(value!(void), |location| value!(location.offset.row))
+        })
+    });
+
+    // Column of the error's location, or void.
+    tokay_method!("error_col : @e", {
+        Self::attr(&e, __function, |error| {
+            error
+                .location()
+                .map_or(value!(void), |location| value!(location.offset.col))
+        })
+    });
+}
+
+impl Object for Error {
+    fn severity(&self) -> u8 {
+        10
+    }
+
+    fn name(&self) -> &'static str {
+        "error"
+    }
+
+    fn repr(&self) -> String {
+        let msg = Str::from(self.message.as_str()).repr();
+
+        match self.location() {
+            Some(location) => format!("<error {} at {}>", msg, location.position()),
+            None => format!("<error {}>", msg),
+        }
+    }
+
+    fn to_string(&self) -> String {
+        format!("{}", self)
+    }
+}
+
+/// Errors are ordered by their location, then by their message.
+impl PartialOrd for Error {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        let key = |error: &Error| {
+            (
+                error
+                    .location()
+                    .map(|location| (location.filename.clone(), location.offset.offset)),
+                error.message.clone(),
+            )
+        };
+
+        key(self).partial_cmp(&key(other))
+    }
+}
+
+impl From<Error> for RefValue {
+    fn from(error: Error) -> Self {
+        RefValue::from(Box::new(error) as BoxedObject)
+    }
 }
 
 impl From<String> for Error {
@@ -50,8 +409,19 @@ impl From<&str> for Error {
     }
 }
 
-tokay_function!("error : @msg, collect=false", {
+tokay_function!("error : @msg, collect=false, recover=false, raise=true", {
     let context = context.unwrap();
+
+    // An error object is raised again as is, or recorded when recovered
+    let error = msg.borrow().object::<Error>().cloned();
+    if let Some(error) = error {
+        if !recover.is_true() {
+            return error.into();
+        }
+
+        return recover_error(context, error);
+    }
+
     let mut msg = msg.to_string();
 
     if collect.is_true() {
@@ -67,5 +437,49 @@ tokay_function!("error : @msg, collect=false", {
         }
     }
 
-    Error::new(Some(context.thread.reader.tell()), msg).into()
+    // The error is located in the input, spanning the collected input when requested
+    let reader = &context.thread.reader;
+    let input = if collect.is_true() {
+        let mut input = Location::from_reader(reader, context.frame0().reader_start);
+        input.stop = Some(reader.tell());
+        input
+    } else {
+        Location::from_reader(reader, reader.tell())
+    };
+
+    let mut error = Error::new(None, msg).with_kind(ErrorKind::User);
+    error.input = Some(Box::new(input));
+
+    if !recover.is_true() && raise.is_true() {
+        return error.into();
+    }
+
+    if let Some(offset) = context.source_offset {
+        error.patch_offset(offset);
+    }
+
+    // Without raising, the error is returned as a value.
+    if !raise.is_true() {
+        return RefValue::from(error).into();
+    }
+
+    recover_error(context, error)
 });
+
+// A recovered error is recorded, and execution continues.
+fn recover_error(context: &mut Context, error: Error) -> Result<Accept, Reject> {
+    // Only the first error of an input line is recorded, as further ones are usually consequential.
+    let errors = &mut context.thread.errors;
+
+    if !errors.last().is_some_and(|last| {
+        last.input.as_ref().is_some_and(|last| {
+            error.input.as_ref().is_some_and(|input| {
+                last.filename == input.filename && last.offset.row == input.offset.row
+            })
+        })
+    }) {
+        errors.push(error);
+    }
+
+    crate::value!(void).into()
+}
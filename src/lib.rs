@@ -12,7 +12,9 @@
 mod _builtins; // Generated builtin registry
 pub mod builtin;
 pub mod compiler;
+pub mod dap;
 pub mod error;
+pub mod lsp;
 pub mod reader;
 #[cfg(test)]
 pub mod test;
@@ -21,8 +23,8 @@ pub mod value;
 pub mod vm;
 
 pub use compiler::Compiler;
-pub use error::Error;
-pub use reader::Reader;
+pub use error::{Error, ErrorKind, Location};
+pub use reader::{Encoding, Reader, Source};
 pub use value::{Dict, List, Object, RefValue, Str, Value};
 pub use vm::{Accept, Capture, Context, Program, Reject};
 
@@ -49,16 +51,12 @@ pub fn eval(
 
     match compiler.compile_from_str(code) {
         Ok(None) => Ok(RefValue::from(Value::Void)),
-        Ok(Some(program)) => {
-            let mut reader = Reader::new(None, Box::new(std::io::Cursor::new(input.to_string())));
-            let mut thread = vm::Thread::new(&program, vec![&mut reader]);
-
-            match thread.run() {
-                Ok(Some(value)) => Ok(value),
-                Err(error) => Err(error),
-                _ => Ok(RefValue::from(Value::Void)),
-            }
-        }
+        Ok(Some(program)) => program
+            .run_from_reader(Reader::new(
+                None,
+                Box::new(std::io::Cursor::new(input.to_string())),
+            ))
+            .map(|value| value.unwrap_or_else(|| RefValue::from(Value::Void))),
         Err(mut errors) => Err(errors.remove(0)),
     }
 }
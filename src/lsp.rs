@@ -0,0 +1,701 @@
+//! Language Server Protocol (LSP) server for Tokay programs
+#![cfg(feature = "lsp")]
+use crate::_builtins::BUILTINS;
+use crate::compiler::RESERVED_KEYWORDS;
+use crate::reader::Reader;
+use crate::utils::{read_message, write_message};
+use crate::{Compiler, Dict, List, Object, RefValue};
+use serde_json::{Value as Json, json};
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+
+/// Position by row and column, both starting at 1, as used by the Tokay parser
+type Pos = (u32, u32);
+
+/// Source range from start to stop
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Span {
+    start: Pos,
+    stop: Pos,
+}
+
+impl Span {
+    const ALL: Span = Span {
+        start: (0, 0),
+        stop: (u32::MAX, u32::MAX),
+    };
+
+    fn contains(&self, pos: Pos) -> bool {
+        self.start <= pos && pos <= self.stop
+    }
+}
+
+/// Kind of a defined symbol
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    Parselet,
+    Constant,
+    Variable,
+    Parameter,
+    Generic,
+}
+
+impl Kind {
+    // LSP CompletionItemKind
+    fn completion(&self) -> u8 {
+        match self {
+            Kind::Parselet => 3,
+            Kind::Constant => 21,
+            Kind::Variable | Kind::Parameter => 6,
+            Kind::Generic => 25,
+        }
+    }
+}
+
+/// Symbol defined in a source
+#[derive(Debug, Clone)]
+struct Symbol {
+    name: String,
+    kind: Kind,
+    span: Span,     // Position of the defining identifier
+    scope: Span,    // Scope where the symbol is visible
+    detail: String, // Signature or description for display
+}
+
+/// Scope while indexing
+struct Scope {
+    span: Span,
+    parselet: bool, // Scope of a parselet, holding variables
+    name: String,   // Name of the parselet
+}
+
+/** Index of symbols defined and used in a source, built from its AST.
+
+The index is built even when the source can't be compiled, as long as it can be parsed. */
+#[derive(Debug, Default)]
+struct Index {
+    lines: Vec<String>,          // Source lines
+    symbols: Vec<Symbol>,        // Defined symbols
+    usages: Vec<(String, Span)>, // Identifiers used
+}
+
+fn node_span(node: &Dict) -> Option<Span> {
+    let get = |key| node.get_str(key).and_then(|value| value.to_usize().ok());
+
+    Some(Span {
+        start: (get("row")? as u32, get("col")? as u32),
+        stop: (get("stop_row")? as u32, get("stop_col")? as u32),
+    })
+}
+
+fn node_emit(node: &Dict) -> String {
+    node.get_str("emit")
+        .map(|emit| emit.to_string())
+        .unwrap_or_default()
+}
+
+fn node_children(node: &Dict) -> Vec<RefValue> {
+    let Some(children) = node.get_str("children") else {
+        return Vec::new();
+    };
+
+    if let Some(list) = children.borrow().object::<List>() {
+        return list.iter().cloned().collect();
+    }
+
+    vec![children.clone()]
+}
+
+/// Identifier name and span of a node, if it is an identifier
+fn node_identifier(node: &RefValue) -> Option<(String, Span)> {
+    let node = node.borrow();
+    let node = node.object::<Dict>()?;
+
+    if node_emit(node) != "identifier" {
+        return None;
+    }
+
+    Some((node.get_str("value")?.to_string(), node_span(node)?))
+}
+
+impl Index {
+    fn new(compiler: &mut Compiler, source: &str) -> Self {
+        let mut index = Self {
+            lines: source.lines().map(String::from).collect(),
+            ..Self::default()
+        };
+
+        if let Ok(ast) = compiler.parse(Reader::new(
+            None,
+            Box::new(io::Cursor::new(source.to_string())),
+        )) {
+            let mut scopes = vec![Scope {
+                span: Span::ALL,
+                parselet: true,
+                name: "__main__".to_string(),
+            }];
+
+            index.walk(&ast, &mut scopes);
+        }
+
+        index
+    }
+
+    /// Convert a Tokay position into an LSP position, which counts columns in UTF-16 code units.
+    fn lsp_position(&self, (row, col): Pos) -> Json {
+        let character: usize = match self.lines.get(row as usize - 1) {
+            Some(line) => line
+                .chars()
+                .take(col as usize - 1)
+                .map(char::len_utf16)
+                .sum(),
+            None => col as usize - 1,
+        };
+
+        json!({"line": row - 1, "character": character})
+    }
+
+    /// Convert a span into an LSP range.
+    fn lsp_range(&self, span: Span) -> Json {
+        json!({"start": self.lsp_position(span.start), "end": self.lsp_position(span.stop)})
+    }
+
+    /// Convert the LSP position of a request into a Tokay position.
+    fn position(&self, params: &Json) -> Pos {
+        let position = &params["position"];
+        let row = position["line"].as_u64().unwrap_or_default() as u32 + 1;
+        let character = position["character"].as_u64().unwrap_or_default() as usize;
+
+        let col = match self.lines.get(row as usize - 1) {
+            Some(line) => {
+                let mut units = 0;
+                line.chars()
+                    .take_while(|ch| {
+                        units += ch.len_utf16();
+                        units <= character
+                    })
+                    .count()
+            }
+            None => character,
+        };
+
+        (row, col as u32 + 1)
+    }
+
+    /// Source text within a span, with comments removed and whitespace collapsed.
+    fn text(&self, span: Span) -> String {
+        let mut text = String::new();
+
+        for row in span.start.0..=span.stop.0 {
+            let Some(line) = self.lines.get(row as usize - 1) else {
+                break;
+            };
+
+            let start = if row == span.start.0 {
+                span.start.1 - 1
+            } else {
+                0
+            };
+            let stop = if row == span.stop.0 {
+                span.stop.1 - 1
+            } else {
+                u32::MAX
+            };
+
+            text.extend(
+                line.chars()
+                    .skip(start as usize)
+                    .take(stop.saturating_sub(start) as usize)
+                    .take_while(|ch| *ch != '#'), // strip comments
+            );
+            text.push(' ');
+        }
+
+        text.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+
+    fn define(&mut self, name: String, kind: Kind, span: Span, scope: Span, detail: String) {
+        self.symbols.push(Symbol {
+            name,
+            kind,
+            span,
+            scope,
+            detail,
+        });
+    }
+
+    fn walk(&mut self, node: &RefValue, scopes: &mut Vec<Scope>) {
+        if let Some(list) = node.borrow().object::<List>() {
+            for item in list.iter() {
+                self.walk(item, scopes);
+            }
+
+            return;
+        }
+
+        let node = node.borrow();
+        let Some(node) = node.object::<Dict>() else {
+            return;
+        };
+
+        let children = node_children(node);
+        let scope = scopes.last().unwrap().span;
+
+        match node_emit(node).as_str() {
+            "identifier" => {
+                if let (Some(name), Some(span)) = (node.get_str("value"), node_span(node)) {
+                    self.usages.push((name.to_string(), span));
+                }
+            }
+            "constant" if children.len() == 2 => {
+                let Some((name, span)) = node_identifier(&children[0]) else {
+                    return;
+                };
+
+                let value = children[1].borrow();
+                let value = value.object::<Dict>().unwrap();
+                let value_span = node_span(value).unwrap_or(span);
+
+                let (kind, signature) = if node_emit(value) == "value_parselet" {
+                    // Signature reaches until the parselet's body
+                    let stop = node_children(value)
+                        .iter()
+                        .find_map(|child| {
+                            let child = child.borrow();
+                            let child = child.object::<Dict>()?;
+
+                            if node_emit(child) == "body" {
+                                node_span(child).map(|span| span.start)
+                            } else {
+                                None
+                            }
+                        })
+                        .unwrap_or(value_span.stop);
+
+                    (
+                        Kind::Parselet,
+                        self.text(Span {
+                            start: value_span.start,
+                            stop,
+                        }),
+                    )
+                } else {
+                    (Kind::Constant, self.text(value_span))
+                };
+
+                self.define(
+                    name.clone(),
+                    kind,
+                    span,
+                    scope,
+                    format!("{} : {}", name, signature),
+                );
+                self.usages.push((name.clone(), span));
+
+                if kind == Kind::Parselet {
+                    self.walk_parselet(value, &name, scopes);
+                } else {
+                    self.walk(&children[1], scopes);
+                }
+            }
+            "value_parselet" => self.walk_parselet(node, "parselet", scopes),
+            "block" => {
+                scopes.push(Scope {
+                    span: node_span(node).unwrap_or(scope),
+                    parselet: false,
+                    name: scopes.last().unwrap().name.clone(),
+                });
+
+                for child in &children {
+                    self.walk(child, scopes);
+                }
+
+                scopes.pop();
+            }
+            "lvalue" => {
+                if let Some((name, span)) = children.first().and_then(node_identifier) {
+                    let scope = scopes.iter().rev().find(|scope| scope.parselet).unwrap();
+
+                    // Variables are defined by their first assignment within a parselet
+                    if !self.symbols.iter().any(|symbol| {
+                        symbol.name == name
+                            && symbol.scope == scope.span
+                            && matches!(symbol.kind, Kind::Variable | Kind::Parameter)
+                    }) {
+                        self.define(
+                            name.clone(),
+                            Kind::Variable,
+                            span,
+                            scope.span,
+                            format!("{} (variable of {})", name, scope.name),
+                        );
+                    }
+                }
+
+                for child in &children {
+                    self.walk(child, scopes);
+                }
+            }
+            _ => {
+                for child in &children {
+                    self.walk(child, scopes);
+                }
+            }
+        }
+    }
+
+    fn walk_parselet(&mut self, node: &Dict, name: &str, scopes: &mut Vec<Scope>) {
+        let span = node_span(node).unwrap_or(scopes.last().unwrap().span);
+
+        scopes.push(Scope {
+            span,
+            parselet: true,
+            name: name.to_string(),
+        });
+
+        for child in node_children(node) {
+            let emit = child.borrow().object::<Dict>().map(node_emit);
+
+            let (kind, description) = match emit.as_deref() {
+                Some("gen") => (Kind::Generic, "generic"),
+                Some("sig") => (Kind::Parameter, "parameter"),
+                _ => {
+                    self.walk(&child, scopes);
+                    continue;
+                }
+            };
+
+            let child = child.borrow();
+            let children = node_children(child.object::<Dict>().unwrap());
+
+            if let Some((param, param_span)) = children.first().and_then(node_identifier) {
+                self.define(
+                    param.clone(),
+                    kind,
+                    param_span,
+                    span,
+                    format!("{} ({} of {})", param, description, name),
+                );
+                self.usages.push((param, param_span));
+            }
+
+            for default in children.iter().skip(1) {
+                self.walk(default, scopes);
+            }
+        }
+
+        scopes.pop();
+    }
+}
+
+impl Index {
+    /// Name of the identifier at a position
+    fn name_at(&self, pos: Pos) -> Option<&str> {
+        self.usages
+            .iter()
+            .find(|(_, span)| span.contains(pos))
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Symbols visible at a position, innermost definitions first
+    fn visible(&self, pos: Pos) -> Vec<&Symbol> {
+        let mut symbols: Vec<&Symbol> = self
+            .symbols
+            .iter()
+            .filter(|symbol| symbol.scope.contains(pos))
+            .collect();
+
+        // Stable sort keeps the first definition of a name within the same scope first
+        symbols.sort_by_key(|symbol| std::cmp::Reverse(symbol.scope.start));
+        symbols
+    }
+
+    /// Resolve the definition of a name at a position
+    fn resolve(&self, name: &str, pos: Pos) -> Option<&Symbol> {
+        self.visible(pos)
+            .into_iter()
+            .find(|symbol| symbol.name == name)
+    }
+}
+
+/// Convert a file URI into a filename
+fn uri_to_filename(uri: &str) -> Option<String> {
+    let path = uri.strip_prefix("file://")?;
+    let mut filename = Vec::new();
+    let mut bytes = path.bytes();
+
+    while let Some(byte) = bytes.next() {
+        if byte == b'%' {
+            let hex: String = bytes.by_ref().take(2).map(char::from).collect();
+            filename.push(u8::from_str_radix(&hex, 16).ok()?);
+        } else {
+            filename.push(byte);
+        }
+    }
+
+    String::from_utf8(filename).ok()
+}
+
+/// Language server state
+struct Server {
+    documents: HashMap<String, (String, Index)>, // Open documents by URI, with source and index
+    prelude: Index,                              // Index of the prelude
+}
+
+impl Server {
+    /// Open or change a document, and return its diagnostics.
+    fn update(&mut self, uri: String, source: String) -> Json {
+        // The compiler loads the prelude, so it is created once per change
+        let mut compiler = Compiler::new();
+
+        let index = Index::new(&mut compiler, &source);
+        self.documents.insert(uri.clone(), (source, index));
+
+        self.diagnostics(&mut compiler, &uri)
+    }
+
+    /** Compile a document and report its errors.
+
+    Errors located in other files, like imported modules, are not reported for the document. */
+    fn diagnostics(&self, compiler: &mut Compiler, uri: &str) -> Json {
+        let (source, index) = &self.documents[uri];
+        let filename = uri_to_filename(uri);
+
+        let errors = match compiler.compile(Reader::new(
+            filename.clone(),
+            Box::new(io::Cursor::new(source.clone())),
+        )) {
+            Ok(_) => Vec::new(),
+            Err(errors) => errors,
+        };
+
+        let length = |start: (u32, u32)| {
+            index
+                .lines
+                .get(start.0 as usize - 1)
+                .map(|line| {
+                    line.chars()
+                        .skip(start.1 as usize - 1)
+                        .take_while(|ch| ch.is_alphanumeric() || *ch == '_')
+                        .count()
+                })
+                .unwrap_or_default()
+                .max(1) as u32
+        };
+
+        let diagnostics: Vec<Json> = errors
+            .into_iter()
+            .filter(|error| {
+                error.location().is_none_or(|location| {
+                    location.filename.is_none() || location.filename == filename
+                })
+            })
+            .map(|error| {
+                let start = error
+                    .offset()
+                    .map(|offset| (offset.row, offset.col))
+                    .unwrap_or((1, 1));
+
+                // Mark the error's span, otherwise the identifier at its position or at least
+                // one character
+                let stop = match error.location().and_then(|location| location.stop) {
+                    Some(stop) if (stop.row, stop.col) > start => (stop.row, stop.col),
+                    _ => (start.0, start.1 + length(start)),
+                };
+
+                json!({
+                    "range": index.lsp_range(Span { start, stop }),
+                    "severity": 1,
+                    "source": "tokay",
+                    "code": error.kind.as_str(),
+                    "message": error.message,
+                })
+            })
+            .collect();
+
+        json!({"uri": uri, "diagnostics": diagnostics})
+    }
+
+    fn hover(&self, params: &Json) -> Json {
+        let Some((_, index)) = self
+            .documents
+            .get(params["textDocument"]["uri"].as_str().unwrap_or_default())
+        else {
+            return Json::Null;
+        };
+
+        let pos = index.position(params);
+        let Some(name) = index.name_at(pos) else {
+            return Json::Null;
+        };
+
+        let detail = if let Some(symbol) = index.resolve(name, pos) {
+            symbol.detail.clone()
+        } else if let Some(symbol) = self.prelude.resolve(name, (1, 1)) {
+            symbol.detail.clone()
+        } else if let Some(builtin) = BUILTINS.iter().find(|builtin| builtin.name == name) {
+            builtin.signature.to_string()
+        } else {
+            return Json::Null;
+        };
+
+        json!({"contents": {"kind": "markdown", "value": format!("```tokay\n{}\n```", detail)}})
+    }
+
+    fn definition(&self, params: &Json) -> Json {
+        let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+        let Some((_, index)) = self.documents.get(uri) else {
+            return Json::Null;
+        };
+
+        let pos = index.position(params);
+
+        match index.name_at(pos).and_then(|name| index.resolve(name, pos)) {
+            Some(symbol) => json!({"uri": uri, "range": index.lsp_range(symbol.span)}),
+            None => Json::Null,
+        }
+    }
+
+    fn completion(&self, params: &Json) -> Json {
+        let mut items = Vec::new();
+        let mut names = std::collections::HashSet::new();
+
+        let mut item = |name: &str, kind: u8, detail: &str| {
+            if names.insert(name.to_string()) {
+                items.push(json!({"label": name, "kind": kind, "detail": detail}));
+            }
+        };
+
+        if let Some((_, index)) = self
+            .documents
+            .get(params["textDocument"]["uri"].as_str().unwrap_or_default())
+        {
+            for symbol in index.visible(index.position(params)) {
+                item(&symbol.name, symbol.kind.completion(), &symbol.detail);
+            }
+        }
+
+        for symbol in self.prelude.visible((1, 1)) {
+            item(&symbol.name, symbol.kind.completion(), &symbol.detail);
+        }
+
+        for builtin in BUILTINS.iter() {
+            item(builtin.name, 3, builtin.signature);
+        }
+
+        for keyword in RESERVED_KEYWORDS {
+            item(keyword, 14, "keyword");
+        }
+
+        Json::Array(items)
+    }
+}
+
+/// Server capabilities, answering the `initialize` request.
+fn capabilities() -> Json {
+    json!({
+        "capabilities": {
+            "textDocumentSync": 1,
+            "hoverProvider": true,
+            "definitionProvider": true,
+            "completionProvider": {},
+        },
+        "serverInfo": {"name": "tokay", "version": env!("CARGO_PKG_VERSION")},
+    })
+}
+
+/// Send a notification to the client.
+fn notify(output: &mut dyn Write, method: &str, params: Json) -> io::Result<()> {
+    write_message(
+        output,
+        &json!({"jsonrpc": "2.0", "method": method, "params": params}),
+    )
+}
+
+/// Respond to a request with a result, or an error message.
+fn respond(output: &mut dyn Write, id: &Json, result: Result<Json, String>) -> io::Result<()> {
+    write_message(
+        output,
+        &match result {
+            Ok(result) => json!({"jsonrpc": "2.0", "id": id, "result": result}),
+            Err(message) => {
+                json!({"jsonrpc": "2.0", "id": id, "error": {"code": -32601, "message": message}})
+            }
+        },
+    )
+}
+
+/** Serve a language server session on the given streams.
+
+Documents are synchronized in full. Each change is compiled to report diagnostics. Positions
+are exchanged with columns in UTF-16 code units, as the protocol requires by default. */
+pub fn serve(input: &mut dyn BufRead, output: &mut dyn Write) -> io::Result<()> {
+    let mut server = Server {
+        documents: HashMap::new(),
+        prelude: Index::new(&mut Compiler::new(), include_str!("prelude.tok")),
+    };
+
+    while let Some(message) = read_message(input)? {
+        let params = &message["params"];
+        let method = message["method"].as_str().unwrap_or_default();
+
+        let result = match method {
+            "initialize" => capabilities(),
+            "textDocument/didOpen" | "textDocument/didChange" => {
+                let uri = params["textDocument"]["uri"]
+                    .as_str()
+                    .unwrap_or_default()
+                    .to_string();
+
+                let source = if method == "textDocument/didOpen" {
+                    params["textDocument"]["text"].as_str()
+                } else {
+                    params["contentChanges"]
+                        .as_array()
+                        .and_then(|changes| changes.last())
+                        .and_then(|change| change["text"].as_str())
+                }
+                .unwrap_or_default()
+                .to_string();
+
+                let diagnostics = server.update(uri, source);
+                notify(output, "textDocument/publishDiagnostics", diagnostics)?;
+                continue;
+            }
+            "textDocument/didClose" => {
+                let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+                server.documents.remove(uri);
+
+                notify(
+                    output,
+                    "textDocument/publishDiagnostics",
+                    json!({"uri": uri, "diagnostics": []}),
+                )?;
+                continue;
+            }
+            "textDocument/hover" => server.hover(params),
+            "textDocument/definition" => server.definition(params),
+            "textDocument/completion" => server.completion(params),
+            "shutdown" => Json::Null,
+            "exit" => return Ok(()),
+            _ => {
+                // Requests must be answered, notifications are ignored
+                if !message["id"].is_null() {
+                    respond(
+                        output,
+                        &message["id"],
+                        Err(format!("Unsupported method '{}'", method)),
+                    )?;
+                }
+
+                continue;
+            }
+        };
+
+        if !message["id"].is_null() {
+            respond(output, &message["id"], Ok(result))?;
+        }
+    }
+
+    Ok(())
+}
@@ -6,15 +6,65 @@ use rustyline;
 use std::fs::{self, File};
 #[cfg(feature = "cbor")]
 use std::io::Write;
-use std::io::{self, BufReader};
+use std::io::{self, BufRead, BufReader};
+use std::{cell::Cell, rc::Rc};
 use tokay;
-use tokay::vm::Thread;
-use tokay::{Compiler, Object, Reader, RefValue, Value};
+use tokay::builtin::{Builtin, json, yaml};
+use tokay::vm::{DebugConsole, Debugger, Thread};
+use tokay::{Compiler, Encoding, Object, Reader, RefValue, Value};
 
 fn print_version() {
     println!("Tokay {}", env!("CARGO_PKG_VERSION"));
 }
 
+/// Output formats for the result of the main parselet
+#[derive(Clone, Copy, clap::ValueEnum)]
+enum Output {
+    /// JSON
+    Json,
+    /// JSON Lines, one line per result of the main parselet
+    Jsonl,
+    /// YAML
+    Yaml,
+    /// Tokay value representation
+    Repr,
+    /// Indented tree view of AST nodes created by ast()
+    #[value(name = "tokay-ast")]
+    TokayAst,
+}
+
+/// Print a result value in the given output format, returns false when it can't be printed
+fn print_output(value: &RefValue, output: Output) -> bool {
+    let ret = match output {
+        Output::Json | Output::Jsonl => json::encode(value, None),
+        Output::Yaml => yaml::encode(value),
+        Output::Repr => Ok(value.repr()),
+        Output::TokayAst => {
+            return Builtin::get("ast_print")
+                .unwrap()
+                .call(None, vec![value.clone()], None)
+                .map_or_else(
+                    |err| {
+                        eprintln!("{}", err);
+                        false
+                    },
+                    |_| true,
+                );
+        }
+    };
+
+    match ret {
+        Ok(output) => {
+            println!("{}", output);
+            true
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            false
+        }
+    }
+}
+
 #[derive(clap::Parser)]
 #[clap(
     name = "Tokay",
@@ -53,6 +103,35 @@ struct Opts {
     compile: Option<String>,
 
     // vvv--- named short/long options (sorted by alphabet) ---vvv
+    /// Permit file I/O builtins like open().
+    #[cfg(feature = "io")]
+    #[clap(long, action)]
+    allow_io: bool,
+
+    /// Run PROGRAM in the debugger, stopping at the given source line of PROGRAM or FILE.
+    #[clap(short, long = "break", value_name = "[FILE:]LINE")]
+    breakpoint: Vec<String>,
+
+    /// Serve the Debug Adapter Protocol on stdin and stdout.
+    #[cfg(feature = "dap")]
+    #[clap(long, action)]
+    dap: bool,
+
+    /// Serve the Language Server Protocol on stdin and stdout.
+    #[cfg(feature = "lsp")]
+    #[clap(long, action)]
+    lsp: bool,
+
+    /// Run PROGRAM in the debugger, stopping at its first source line.
+    #[clap(short, long, action)]
+    debug: bool,
+
+    /// Encoding of INPUT files and stdin.
+    ///
+    /// One of utf-8, utf-8-lossy, latin-1, bytes, utf-16le or utf-16be.
+    #[clap(long, value_name = "ENCODING", default_value = "utf-8")]
+    encoding: Encoding,
+
     /// Echo result of executed main parselet
     #[clap(short, long, action)]
     echo: bool,
@@ -61,10 +140,26 @@ struct Opts {
     #[clap(short, long, action)]
     files: bool,
 
+    /// Add directory to the module search path.
+    #[clap(short = 'I', long = "path", value_name = "PATH")]
+    path: Vec<String>,
+
     /// Show license agreement and exit.
     #[clap(short, long, action)]
     license: bool,
 
+    /// Limit the number of memoized parselet results.
+    #[clap(long, value_name = "ENTRIES")]
+    memo_limit: Option<usize>,
+
+    /// Print memoization statistics to stderr after the program finished.
+    #[clap(long, action)]
+    memo_stats: bool,
+
+    /// Output result of executed main parselet in FORMAT.
+    #[clap(short, long, value_name = "FORMAT")]
+    output: Option<Output>,
+
     /// Run Tokay without verbose outputs
     #[clap(short, long, action)]
     quiet: bool,
@@ -88,15 +183,17 @@ fn get_readers(opts: &Opts) -> Vec<Reader> {
 
     for filename in &opts.input {
         if filename == "-" && !opts.files {
-            readers.push(Reader::new(
+            let mut reader = Reader::new(
                 Some(filename.to_string()),
                 Box::new(BufReader::new(io::stdin())),
-            ));
+            );
+            reader.encoding = opts.encoding;
+            readers.push(reader);
         } else if let Ok(file) = File::open(filename) {
-            readers.push(Reader::new(
-                Some(filename.to_string()),
-                Box::new(BufReader::new(file)),
-            ));
+            let mut reader =
+                Reader::new(Some(filename.to_string()), Box::new(BufReader::new(file)));
+            reader.encoding = opts.encoding;
+            readers.push(reader);
         } else if !opts.files {
             readers.push(Reader::new(
                 None,
@@ -157,7 +254,8 @@ fn repl(compiler: &mut Compiler, opts: &Opts) -> rustyline::Result<()> {
                 println!("<<< Debug switched off")
             }
             */
-            _ => match compiler.compile(Reader::new(None, Box::new(io::Cursor::new(code)))) {
+            _ => match compiler.compile(Reader::new(None, Box::new(io::Cursor::new(code.clone()))))
+            {
                 Ok(None) => {}
                 Ok(Some(program)) => {
                     let mut readers = get_readers(&opts);
@@ -170,18 +268,29 @@ fn repl(compiler: &mut Compiler, opts: &Opts) -> rustyline::Result<()> {
                     let mut thread = Thread::new(&program, readers.iter_mut().collect());
                     thread.debug = compiler.debug;
                     thread.globals = globals;
+                    thread.memo.limit = opts.memo_limit;
+
+                    let ret = thread.run();
+
+                    for error in thread.errors.drain(..) {
+                        eprintln!("{:#}", error)
+                    }
 
-                    match thread.run() {
+                    match ret {
                         Ok(Some(value)) => println!("{}", value.repr()),
-                        Err(error) => eprintln!("{}", error),
+                        Err(error) => eprintln!("{:#}", error),
                         _ => {}
                     }
 
+                    if opts.memo_stats {
+                        eprintln!("Memo: {}", thread.memo.stats());
+                    }
+
                     globals = thread.globals;
                 }
                 Err(errors) => {
                     for error in errors {
-                        eprintln!("{}", error);
+                        eprintln!("{:#}", error);
                     }
                 }
             },
@@ -211,6 +320,23 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         std::process::exit(0);
     }
 
+    // Run as debug adapter?
+    #[cfg(feature = "dap")]
+    if opts.dap {
+        tokay::dap::serve(
+            Box::new(BufReader::new(io::stdin())),
+            Box::new(io::stdout()),
+        )?;
+        std::process::exit(0);
+    }
+
+    // Run as language server?
+    #[cfg(feature = "lsp")]
+    if opts.lsp {
+        tokay::lsp::serve(&mut io::stdin().lock(), &mut io::stdout())?;
+        std::process::exit(0);
+    }
+
     // Read program, either from stdin, file or direct string.
     let mut program: Option<Reader> = None;
 
@@ -246,6 +372,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Create a new Tokay compiler
     let mut compiler = Compiler::new();
 
+    #[cfg(feature = "io")]
+    {
+        compiler.allow_io = opts.allow_io;
+    }
+
+    // Directories from command-line are searched before TOKAY_PATH
+    compiler
+        .path
+        .splice(0..0, opts.path.iter().map(std::path::PathBuf::from));
+
     /*
     compiler.constant(
         "printH",
@@ -282,6 +418,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // When a program is provided, compile and run it
     if let Some(program) = program {
+        let filename = program.filename.clone();
+
         match compiler.compile(program) {
             Ok(None) => {}
             Ok(Some(program)) => {
@@ -331,7 +469,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                             )) {
                                 Ok(None) => {}
                                 Ok(Some(value)) => println!("{}", value.to_string()),
-                                Err(error) => eprintln!("{}", error),
+                                Err(error) => eprintln!("{:#}", error),
                             }
                         }
 
@@ -342,10 +480,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     readers.push(
                         // When program's main is consuming, read from stdin
                         if program.main().is_consuming() {
-                            Reader::new(
+                            let mut reader = Reader::new(
                                 Some("-".to_string()),
                                 Box::new(BufReader::new(io::stdin())),
-                            )
+                            );
+                            reader.encoding = opts.encoding;
+                            reader
                         }
                         // otherwise just work on an empty input
                         else {
@@ -359,26 +499,123 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     std::process::exit(1);
                 }
 
+                // Is stdin used by the program or its input?
+                let stdin = filename.as_deref() == Some("-")
+                    || readers
+                        .iter()
+                        .any(|reader| reader.filename.as_deref() == Some("-"));
+
                 let mut thread = Thread::new(&program, readers.iter_mut().collect());
+                thread.memo.limit = opts.memo_limit;
+
+                // JSON Lines are printed as soon as main produces a result
+                let failed = Rc::new(Cell::new(false));
 
-                match thread.run() {
-                    Ok(None) => {
-                        if opts.echo && readers.len() > 1 {
-                            print!("\n")
+                if let Some(Output::Jsonl) = opts.output {
+                    let failed = failed.clone();
+                    thread.on_result = Some(Box::new(move |value| {
+                        if !print_output(&value, Output::Jsonl) {
+                            failed.set(true);
+                        }
+                    }));
+                }
+
+                // Attach the debugger, reading commands from stdin or the terminal
+                if opts.debug || !opts.breakpoint.is_empty() {
+                    let mut debugger = Debugger::new(opts.debug);
+
+                    for breakpoint in &opts.breakpoint {
+                        match Debugger::parse_breakpoint(breakpoint, filename.as_deref()) {
+                            Ok(breakpoint) => {
+                                debugger.breakpoints.insert(breakpoint);
+                            }
+                            Err(err) => {
+                                eprintln!("{}", err);
+                                std::process::exit(1);
+                            }
                         }
                     }
+
+                    // When stdin is used, read commands from the terminal
+                    let commands: Box<dyn BufRead> = if stdin {
+                        #[cfg(windows)]
+                        let terminal = "CONIN$";
+                        #[cfg(not(windows))]
+                        let terminal = "/dev/tty";
+
+                        match File::open(terminal) {
+                            Ok(terminal) => Box::new(BufReader::new(terminal)),
+                            Err(err) => {
+                                eprintln!(
+                                    "Can't read debugger commands from '{}', as stdin is used for input: {}",
+                                    terminal, err
+                                );
+                                std::process::exit(1);
+                            }
+                        }
+                    } else {
+                        Box::new(BufReader::new(io::stdin()))
+                    };
+
+                    thread.debugger = Some(Box::new(DebugConsole::new(
+                        debugger,
+                        commands,
+                        Box::new(io::stderr()),
+                    )));
+                }
+
+                let ret = thread.run();
+
+                if opts.memo_stats {
+                    eprintln!("Memo: {}", thread.memo.stats());
+                }
+
+                // Errors recovered during the run are reported, regardless of the result
+                for error in thread.errors.drain(..) {
+                    eprintln!("{:#}", error)
+                }
+
+                let printed = match ret {
+                    Ok(None) => match opts.output {
+                        Some(output @ (Output::Json | Output::Yaml | Output::Repr)) => {
+                            print_output(&RefValue::from(Value::Null), output)
+                        }
+                        Some(_) => true,
+                        None if opts.echo && readers.len() > 1 => {
+                            print!("\n");
+                            true
+                        }
+                        None => true,
+                    },
                     Ok(Some(value)) => {
-                        if opts.echo {
-                            println!("{}", value.to_string())
+                        if let Some(output) = opts.output {
+                            print_output(&value, output)
+                        } else {
+                            if opts.echo {
+                                println!("{}", value.to_string())
+                            }
+
+                            true
                         }
                     }
-                    Err(error) => eprintln!("{}", error),
+                    Err(error) => {
+                        eprintln!("{:#}", error);
+                        false
+                    }
+                };
+
+                // Runtime errors and results that can't be encoded into the requested output
+                // format are failures
+                if !printed || failed.get() {
+                    std::process::exit(1);
                 }
             }
             Err(errors) => {
                 for error in errors {
-                    eprintln!("{}", error);
+                    eprintln!("{:#}", error);
                 }
+
+                std::process::exit(1);
             }
         }
     } else {
@@ -1,5 +1,7 @@
 //! Universal low-level interface to let Tokay read input from different sources.
+use crate::error::{Error, ErrorKind, Location};
 use num_parse::PeekableIterator;
+use std::cell::RefCell;
 use std::io::BufReader;
 use std::io::prelude::*;
 
@@ -7,7 +9,6 @@ use std::io::prelude::*;
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Offset {
-    // todo: Hold source filename information as well in the future?
     pub offset: usize,
     pub row: u32,
     pub col: u32,
@@ -15,15 +16,76 @@ pub struct Offset {
 
 pub type Range = std::ops::Range<usize>;
 
+/// Source code of a Tokay program or module, which the offsets of its parselets refer to.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Source {
+    pub filename: Option<String>, // Source filename
+    pub code: String,             // Source code
+}
+
+impl Source {
+    /// Returns the line at the given row, without its line break.
+    pub fn line(&self, row: u32) -> Option<&str> {
+        self.code
+            .lines()
+            .nth(row.checked_sub(1)? as usize)
+            .map(|line| line.trim_end_matches('\r'))
+    }
+}
+
+/** Encoding of the input read by a Reader.
+
+Input is always decoded into chars, so that tokens like `Char` or `Match` work the same way
+regardless of the encoding. Invalid input is reported as an error in `Utf8` mode, all other
+encodings replace invalid sequences by U+FFFD.
+
+Input is read line by line, except in `Bytes` mode: Binary input usually has no line structure,
+so it is read in blocks, and long runs without line breaks don't have to be buffered entirely.
+*/
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Encoding {
+    #[default]
+    Utf8, // UTF-8, invalid sequences are reported as error
+    Utf8Lossy, // UTF-8, invalid sequences are replaced
+    Latin1,    // ISO-8859-1
+    Bytes, // Byte-level mode, every byte is read as a char from 0 to 255, in blocks instead of lines
+    Utf16Le, // UTF-16 little endian
+    Utf16Be, // UTF-16 big endian
+}
+
+impl std::str::FromStr for Encoding {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace("_", "-").as_str() {
+            "utf-8" | "utf8" => Ok(Self::Utf8),
+            "utf-8-lossy" | "utf8-lossy" => Ok(Self::Utf8Lossy),
+            "latin-1" | "latin1" | "iso-8859-1" => Ok(Self::Latin1),
+            "bytes" | "binary" => Ok(Self::Bytes),
+            "utf-16le" | "utf16le" => Ok(Self::Utf16Le),
+            "utf-16be" | "utf16be" => Ok(Self::Utf16Be),
+            _ => Err(format!(
+                "Unknown encoding '{}', use one of utf-8, utf-8-lossy, latin-1, bytes, utf-16le, utf-16be",
+                s
+            )),
+        }
+    }
+}
+
 // Abstraction of a buffered Reader with internal buffering, offset counting and clean-up.
 pub struct Reader {
-    pub filename: Option<String>, // Source filename
-    reader: Box<dyn BufRead>,     // Reader object to read from
-    buffer: String,               // Internal buffer
-    peeked: char,                 // Currently peeked char
-    offset: Offset,               // Current offset
-    start: Offset,                // Offset of last commit
-    pub eof: bool,                // EOF marker
+    pub filename: Option<String>,  // Source filename
+    reader: Box<dyn BufRead>,      // Reader object to read from
+    buffer: String,                // Internal buffer
+    peeked: char,                  // Currently peeked char
+    offset: Offset,                // Current offset
+    start: Offset,                 // Offset of last commit
+    pub eof: bool,                 // EOF marker
+    pub encoding: Encoding,        // Input encoding
+    pending: Vec<u8>,              // Undecoded bytes from last read
+    bytes: usize,                  // Total number of bytes read
+    error: RefCell<Option<Error>>, // Error occured while reading or capturing discarded input
+    line: Option<Range>,           // Offsets of the line last peeked
 }
 
 impl Reader {
@@ -45,24 +107,126 @@ impl Reader {
                 col: 1,
             },
             eof: false,
+            encoding: Encoding::default(),
+            pending: Vec::new(),
+            bytes: 0,
+            error: RefCell::new(None),
+            line: None,
         }
     }
 
     /// Internal function for reading a line.
     fn read_line(&mut self) -> Option<usize> {
-        let n = self
-            .reader
-            .read_line(&mut self.buffer)
-            // FIXME: Currently non-UTF-8 input is directly escalated as a panic.
-            // See issue https://github.com/tokay-lang/tokay/issues/169
-            .unwrap_or_else(|err| panic!("read_line failed: {}", err));
-
-        if n == 0 {
+        let mut bytes = std::mem::take(&mut self.pending);
+
+        let read = if self.encoding == Encoding::Bytes {
+            self.reader.fill_buf().map(|block| {
+                bytes.extend_from_slice(block);
+                block.len()
+            })
+        } else {
+            self.reader.read_until(b'\n', &mut bytes)
+        };
+
+        let mut n = match read {
+            Ok(n) => n,
+            Err(err) => return self.fail(format!("Unable to read input: {}", err)),
+        };
+
+        if self.encoding == Encoding::Bytes {
+            self.reader.consume(n);
+        }
+
+        // UTF-16 requires complete code units, but a newline may end on its first byte.
+        if matches!(self.encoding, Encoding::Utf16Le | Encoding::Utf16Be) && bytes.len() % 2 == 1 {
+            let mut byte = [0u8];
+            if let Ok(1) = self.reader.read(&mut byte) {
+                bytes.push(byte[0]);
+                n += 1;
+            }
+        }
+
+        if bytes.is_empty() {
             self.eof = true;
             return None;
         }
 
-        Some(n)
+        let len = self.buffer.len();
+        let offset = self.bytes;
+        self.bytes += n;
+
+        match self.encoding {
+            Encoding::Utf8 => match String::from_utf8(bytes) {
+                Ok(line) => self.buffer.push_str(&line),
+                Err(err) => {
+                    let valid = err.utf8_error().valid_up_to();
+                    let bytes = err.into_bytes();
+
+                    // Keep the valid part, so it can still be parsed
+                    self.buffer
+                        .push_str(std::str::from_utf8(&bytes[..valid]).unwrap());
+
+                    return self.fail(format!(
+                        "Invalid UTF-8 sequence in input at byte {}",
+                        offset + valid
+                    ));
+                }
+            },
+            Encoding::Utf8Lossy => self.buffer.push_str(&String::from_utf8_lossy(&bytes)),
+            Encoding::Latin1 | Encoding::Bytes => {
+                self.buffer.extend(bytes.iter().map(|byte| *byte as char))
+            }
+            Encoding::Utf16Le | Encoding::Utf16Be => {
+                let mut units: Vec<u16> = bytes
+                    .chunks(2)
+                    .map(|unit| match (unit, self.encoding) {
+                        ([lo, hi], Encoding::Utf16Le) => u16::from_le_bytes([*lo, *hi]),
+                        ([hi, lo], _) => u16::from_be_bytes([*hi, *lo]),
+                        _ => 0xfffd, // odd byte at end of input
+                    })
+                    .collect();
+
+                // Keep a trailing high surrogate until its low surrogate was read
+                if n > 0 && matches!(units.last(), Some(0xd800..=0xdbff)) {
+                    units.pop();
+                    self.pending = bytes[bytes.len() - 2..].to_vec();
+                }
+
+                self.buffer.extend(
+                    char::decode_utf16(units).map(|ch| ch.unwrap_or(char::REPLACEMENT_CHARACTER)),
+                );
+            }
+        }
+
+        Some(self.buffer.len() - len)
+    }
+
+    /// Internal function to stop reading due to an error, located at the end of the input read.
+    fn fail(&mut self, msg: String) -> Option<usize> {
+        let mut offset = self.offset;
+
+        for ch in self.buffer[self.pos()..].chars() {
+            offset.offset += ch.len_utf8();
+
+            if ch == '\n' {
+                offset.row += 1;
+                offset.col = 1;
+            } else {
+                offset.col += 1;
+            }
+        }
+
+        let mut error = Error::new(None, msg).with_kind(ErrorKind::Io);
+        error.input = Some(Box::new(Location::from_reader(self, offset)));
+
+        *self.error.get_mut() = Some(error);
+        self.eof = true;
+        None
+    }
+
+    /// Take an error which occured while reading the input.
+    pub fn take_error(&mut self) -> Option<Error> {
+        self.error.get_mut().take()
     }
 
     pub fn tell(&self) -> Offset {
@@ -74,7 +238,7 @@ impl Reader {
     }
 
     pub fn eof(&mut self) -> bool {
-        if self.buffer[self.offset.offset..].chars().next().is_some() {
+        if self.buffer[self.pos()..].chars().next().is_some() {
             false
         } else {
             if !self.eof {
@@ -91,8 +255,8 @@ impl Reader {
 
     /// Capture last length characters.
     pub fn capture_last(&self, mut length: usize) -> Range {
-        if length > self.offset.offset {
-            length = self.offset.offset;
+        if length > self.pos() {
+            length = self.pos();
         }
 
         self.offset.offset - length..self.offset.offset
@@ -110,19 +274,139 @@ impl Reader {
     }
 
     pub fn print(&self, start: usize) {
-        println!("{:?}", &self.buffer[start..self.offset.offset])
+        println!("{:?}", &self.buffer[start - self.start.offset..self.pos()])
     }
 
-    /// Get slice from range
+    /** Get slice from range.
+
+    Input before the last commit is gone. A range into it results in an empty slice, and an
+    error which is raised like errors occuring while reading. */
     pub fn get(&self, range: &Range) -> &str {
-        &self.buffer[range.start..range.end]
+        if range.start < self.start.offset {
+            self.error.borrow_mut().get_or_insert_with(|| {
+                Error::new(
+                    None,
+                    format!(
+                        "Input at offset {} was already discarded and can't be captured",
+                        range.start
+                    ),
+                )
+            });
+
+            return "";
+        }
+
+        &self.buffer[range.start - self.start.offset..range.end - self.start.offset]
+    }
+
+    /** Returns the buffered line of input containing the given offset, without its line break.
+
+    Returns None when the line's beginning was already discarded by a commit. */
+    pub fn line(&self, offset: &Offset) -> Option<&str> {
+        let pos = offset.offset.checked_sub(self.start.offset)?;
+        let buffer = self.buffer.get(..pos)?;
+
+        let start = match buffer.rfind('\n') {
+            Some(start) => start + 1,
+            None if self.start.col == 1 => 0,
+            None => return None,
+        };
+
+        let end = self.buffer[pos..]
+            .find('\n')
+            .map_or(self.buffer.len(), |end| pos + end);
+
+        Some(self.buffer[start..end].trim_end_matches('\r'))
+    }
+
+    /** Returns the current line of input including its line break, and the position of the
+    current offset within it, without consuming any input.
+
+    The line starts at the beginning of the buffer when its beginning was already discarded.
+    The line's offsets are remembered, so that peeking the same line again doesn't scan it. */
+    pub fn peek_line(&mut self) -> (&str, usize) {
+        let pos = self.pos();
+
+        let line = match &self.line {
+            Some(line) if line.contains(&self.offset.offset) => line.clone(),
+            _ => {
+                let start = self.buffer[..pos].rfind('\n').map_or(0, |start| start + 1);
+
+                let end = loop {
+                    if let Some(end) = self.buffer[pos..].find('\n') {
+                        break pos + end + 1;
+                    }
+
+                    if self.eof || self.read_line().is_none() {
+                        break self.buffer.len();
+                    }
+                };
+
+                let line = self.start.offset + start..self.start.offset + end;
+                self.line = Some(line.clone());
+                line
+            }
+        };
+
+        let start = line.start.saturating_sub(self.start.offset);
+        let end = line.end - self.start.offset;
+
+        (&self.buffer[start..end], pos - start)
+    }
+
+    /** Reads the entire remaining input and returns it as a String.
+
+    The returned input is consumed from the reader. */
+    pub fn read_to_string(&mut self) -> Result<String, Error> {
+        while self.read_line().is_some() {}
+
+        if let Some(error) = self.take_error() {
+            return Err(error);
+        }
+
+        self.line = None;
+        Ok(self.buffer.split_off(self.pos()))
     }
 
-    /// Commits current input buffer and removes cached content
+    /** Commits current input buffer and removes cached content.
+
+    Input before the current offset is discarded and can't be accessed anymore afterwards.
+    Offsets are still counted from the beginning of the input. The current line is kept up to
+    a limited length, so that it can still be shown in error messages.
+
+    Input is only removed from the buffer when it makes up more than half of it or 64 KiB, so
+    that moving the remaining input is amortized over several commits. */
     pub fn commit(&mut self) {
-        self.buffer.drain(0..self.offset.offset);
-        self.start = self.offset;
-        self.offset.offset = 0; // reset offset to 0
+        let pos = self.pos();
+        if pos <= 64 * 1024 && pos * 2 <= self.buffer.len() {
+            return;
+        }
+
+        let keep = self.buffer[..pos].rfind('\n').map_or(0, |start| start + 1);
+        let all = pos - keep > 1024 || (keep == 0 && self.start.col > 1);
+
+        let discard = if all { pos } else { keep };
+        if discard <= 64 * 1024 && discard * 2 <= self.buffer.len() {
+            return;
+        }
+
+        if all {
+            self.buffer.drain(0..pos);
+            self.start = self.offset;
+        } else if keep > 0 {
+            self.buffer.drain(0..keep);
+            self.start = Offset {
+                offset: self.start.offset + keep,
+                row: self.offset.row,
+                col: 1,
+            };
+        }
+    }
+
+    /// Current position inside the internal buffer
+    #[inline]
+    fn pos(&self) -> usize {
+        self.offset.offset - self.start.offset
     }
 
     /// Take one character accepted by callback
@@ -144,12 +428,12 @@ impl Reader {
     where
         F: Fn(char) -> bool + Copy,
     {
-        let start = self.offset.offset;
+        let start = self.pos();
 
         while self.once(accept).is_some() {}
 
-        if start < self.offset.offset {
-            Some(&self.buffer[start..self.offset.offset])
+        if start < self.pos() {
+            Some(&self.buffer[start..self.pos()])
         } else {
             None
         }
@@ -161,7 +445,7 @@ impl Iterator for Reader {
 
     fn next(&mut self) -> Option<Self::Item> {
         loop {
-            if let Some(ch) = self.buffer[self.offset.offset..].chars().next() {
+            if let Some(ch) = self.buffer[self.pos()..].chars().next() {
                 self.offset.offset += ch.len_utf8();
 
                 if ch == '\n' {
@@ -186,7 +470,7 @@ impl Iterator for Reader {
 impl PeekableIterator for Reader {
     fn peek(&mut self) -> Option<&Self::Item> {
         loop {
-            if let Some(ch) = self.buffer[self.offset.offset..].chars().next() {
+            if let Some(ch) = self.buffer[self.pos()..].chars().next() {
                 self.peeked = ch;
                 return Some(&self.peeked);
             }
@@ -1,8 +1,23 @@
 //! Unit tests
 use crate::utils::testcase;
-use crate::{eval, value};
+use crate::vm::Thread;
+use crate::{Program, Reader, eval, value};
 use tokay_macros;
 
+// Runs `func` with a new thread of `program`, reading from `input`
+fn with_thread<T>(
+    program: &Program,
+    input: impl AsRef<[u8]>,
+    func: impl FnOnce(&mut Thread) -> T,
+) -> T {
+    let mut reader = Reader::new(
+        None,
+        Box::new(std::io::Cursor::new(input.as_ref().to_vec())),
+    );
+
+    func(&mut Thread::new(program, vec![&mut reader]))
+}
+
 #[test]
 // Simple testcase for testcase
 fn test_case() {
@@ -83,4 +98,745 @@ fn examples() {
     );
 }
 
+#[test]
+// Testing kinds, spans and rendering of errors
+fn errors() {
+    use crate::{Compiler, ErrorKind};
+
+    let kind = |code: &str, input: &str| eval(code, input, None).unwrap_err().kind;
+
+    assert_eq!(kind("x = {", ""), ErrorKind::Syntax);
+    assert_eq!(kind("'break'\nbreak", ""), ErrorKind::Syntax);
+    assert_eq!(kind("1 + undefined", ""), ErrorKind::Name);
+    assert_eq!(kind("\"a\" - 1", ""), ErrorKind::Type);
+    assert_eq!(kind("f : @x { x }\nf()", ""), ErrorKind::Argument);
+    assert_eq!(kind("ord()", ""), ErrorKind::Argument);
+    assert_eq!(kind("'a' error(\"no b\")", "abc"), ErrorKind::User);
+    assert_eq!(kind("10 % 0", ""), ErrorKind::Type);
+
+    // Compile errors are located in the source
+    let error = Compiler::new()
+        .compile_from_str("x = 1\nprint(x + y2)")
+        .unwrap_err()
+        .remove(0);
+
+    assert_eq!(
+        error.to_string(),
+        "Line 2, column 11: Use of undefined name 'y2'"
+    );
+    assert_eq!(
+        format!("{:#}", error),
+        "Line 2, column 11: Use of undefined name 'y2'\n 2 | print(x + y2)\n   |           ^^"
+    );
+
+    // Errors raised by the program are located in the input, spanning collected input
+    let error = eval(
+        "''a''+ error(\"Too many\", collect=true)",
+        "bc\n\taaab",
+        None,
+    )
+    .unwrap_err();
+    assert_eq!(
+        format!("{:#}", error),
+        "Line 2, column 2: Too many: (\"a\", \"a\", \"a\")\n 2 | \taaab\n   | \t^^^\n  at program line 1, column 8"
+    );
+    assert_eq!(
+        error.offset().map(|offset| (offset.row, offset.col)),
+        Some((2, 2))
+    );
+
+    // Runtime errors are located in both source and input, with a backtrace
+    let error = eval("f : @x { x - 1 }\nWord f($1)", "12 abc", None).unwrap_err();
+    assert_eq!(error.offset().map(|offset| offset.col), Some(10));
+    assert_eq!(error.input.as_ref().map(|input| input.offset.col), Some(7));
+    assert_eq!(
+        error.backtrace,
+        vec![
+            ("f".to_string(), error.offset()),
+            ("__main__".to_string(), error.backtrace[1].1)
+        ]
+    );
+    assert_eq!(
+        format!("{:#}", error),
+        "Line 1, column 10: Method 'str_sub' not found\n 1 | f : @x { x - 1 }\n   |          ^\n  at input line 1, column 7\n  in f, line 1, column 10\n  in __main__, line 2, column 6"
+    );
+
+    // Runtime errors in a module are located in the module's source
+    let error = eval(
+        "use \"tests/modules/failing\"\nfailing.fail(\"abc\")",
+        "",
+        None,
+    )
+    .unwrap_err();
+    let location = error.location().unwrap();
+    assert!(
+        location
+            .filename
+            .as_ref()
+            .is_some_and(|filename| filename.ends_with("failing.tok"))
+    );
+    assert_eq!((location.offset.row, location.offset.col), (4, 5));
+    assert_eq!(location.snippet.as_deref(), Some("    x - 1"));
+
+    // Compile errors in a module are located in the module's source
+    let errors = Compiler::new()
+        .compile_from_str("\nuse \"tests/modules/undefined\"")
+        .unwrap_err();
+    let location = errors[0].location().unwrap();
+    assert!(
+        errors[0]
+            .message
+            .starts_with("In module 'tests/modules/undefined': Use of undefined name")
+    );
+    assert!(
+        location
+            .filename
+            .as_ref()
+            .is_some_and(|filename| filename.ends_with("undefined.tok"))
+    );
+    assert_eq!((location.offset.row, location.offset.col), (4, 5));
+
+    // The parser recovers from syntax errors, so that all of them are reported
+    let errors = Compiler::new()
+        .compile_from_str("x = 1 +\ny = 2 )\nz = 3 ]\nprint(x)")
+        .unwrap_err();
+
+    assert_eq!(
+        errors
+            .iter()
+            .map(|error| (error.kind, error.offset().map(|offset| offset.row)))
+            .collect::<Vec<_>>(),
+        vec![
+            (ErrorKind::Syntax, Some(1)),
+            (ErrorKind::Syntax, Some(2)),
+            (ErrorKind::Syntax, Some(3))
+        ]
+    );
+
+    // Errors recovered at runtime are collected by the thread, without affecting the result
+    let program = Compiler::new()
+        .compile_from_str("error(\"Oops\", recover=true)\n42")
+        .unwrap()
+        .unwrap();
+    with_thread(&program, "", |thread| {
+        assert_eq!(thread.run(), Ok(Some(value!(42))));
+        assert_eq!(
+            thread
+                .errors
+                .iter()
+                .map(|error| error.message.as_str())
+                .collect::<Vec<_>>(),
+            vec!["Oops"]
+        );
+    });
+}
+
+#[test]
+// Testing input encodings of the Reader
+fn reader_encodings() {
+    use crate::{Compiler, Encoding};
+
+    let program = Compiler::new().compile_from_str("Chars").unwrap().unwrap();
+
+    let run = |input: &[u8], encoding| {
+        with_thread(&program, input, |thread| {
+            thread.reader.encoding = encoding;
+            thread.run()
+        })
+    };
+
+    assert_eq!(
+        run(b"caf\xc3\xa9", Encoding::Utf8),
+        Ok(Some(value!("café")))
+    );
+    assert_eq!(
+        run(b"caf\xe9", Encoding::Utf8).map_err(|err| err.message),
+        Err("Invalid UTF-8 sequence in input at byte 3".to_string())
+    );
+    assert_eq!(
+        run(b"ok\ncaf\xe9", Encoding::Utf8).map_err(|err| format!("{:#}", err)),
+        Err(
+            "Line 2, column 4: Invalid UTF-8 sequence in input at byte 6\n 2 | caf\n   |    ^\n  at program line 1, column 1"
+                .to_string()
+        )
+    );
+    assert_eq!(
+        run(b"caf\xe9", Encoding::Utf8Lossy),
+        Ok(Some(value!("caf\u{fffd}")))
+    );
+    assert_eq!(run(b"caf\xe9", Encoding::Latin1), Ok(Some(value!("café"))));
+    assert_eq!(
+        run(b"\x00\xff", Encoding::Bytes),
+        Ok(Some(value!("\u{0}\u{ff}")))
+    );
+    assert_eq!(
+        run(b"h\x00\xe9\x00=\xd8\x00\xde", Encoding::Utf16Le),
+        Ok(Some(value!("hé😀")))
+    );
+    assert_eq!(
+        run(b"\x00h\x00\xe9\xd8=\xde\x00", Encoding::Utf16Be),
+        Ok(Some(value!("hé😀")))
+    );
+
+    // Errors are raised by the token reading the input, so that they can be caught
+    let program = Compiler::new()
+        .compile_from_str("try Chars catch e { \"caught \" + e.msg }")
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        with_thread(&program, b"caf\xe9", |thread| thread.run()),
+        Ok(Some(value!([
+            "caught Invalid UTF-8 sequence in input at byte 3",
+            "af"
+        ])))
+    );
+}
+
+#[test]
+// Testing that processed input is discarded by the main parselet
+fn reader_discard() {
+    use crate::Compiler;
+
+    let input = "ab cd\n".repeat(1000);
+
+    let run = |src: &str| {
+        let program = Compiler::new().compile_from_str(src).unwrap().unwrap();
+        with_thread(&program, &input, |thread| {
+            (thread.run(), thread.reader.start().offset)
+        })
+    };
+
+    // Input is discarded up to the end
+    assert_eq!(run("Word _ void"), (Ok(None), 6000));
+
+    // Capture 0 requires the entire input, also when accessed by a called parselet
+    assert_eq!(run("Word _ void\nend x = $0"), (Ok(None), 0));
+    assert_eq!(run("f : @{ x = $0 }\nWord _ void\nend f"), (Ok(None), 0));
+    assert_eq!(run("P : @{ Word _ $0 }\nP void"), (Ok(None), 0));
+
+    // Values called at runtime may access capture 0 as well
+    assert_eq!(
+        run("f : @n { x = $0 }\nbegin g = f\nWord _ void\nend g(1)"),
+        (Ok(None), 0)
+    );
+
+    // Ranges into discarded input raise an error instead of being truncated
+    let mut reader = Reader::new(None, Box::new(std::io::Cursor::new("abcdef\ng\nh")));
+    reader.by_ref().take(8).count();
+    let range = reader.capture_last(1);
+    reader.commit();
+
+    assert_eq!(reader.start().offset, 7);
+    assert_eq!(reader.get(&range), "g");
+    assert!(reader.take_error().is_none());
+    assert_eq!(reader.get(&(0..2)), "");
+    assert_eq!(
+        reader.take_error().map(|error| error.message),
+        Some("Input at offset 0 was already discarded and can't be captured".to_string())
+    );
+}
+
+#[test]
+// Testing memoization statistics and limits
+fn memo_stats() {
+    use crate::Compiler;
+
+    let run = |src: &str, input: &str, limit| {
+        let program = Compiler::new().compile_from_str(src).unwrap().unwrap();
+        with_thread(&program, input, |thread| {
+            thread.memo.limit = limit;
+            (thread.run(), thread.memo.stats())
+        })
+    };
+
+    // Factor is small enough to be inlined with the inline_parselets-feature, and isn't memoized
+    // then. The remaining entries of the left-recursive Term and Expr are required, so that the
+    // limit doesn't apply to them.
+    let expr = include_str!("../examples/expr.tok");
+
+    let (result, stats) = run(expr, "1+2*3+4*(5-1)", None);
+    assert_eq!(result, Ok(Some(value!(23))));
+    assert!(stats.hits > 0);
+    assert!(stats.entries < stats.peak);
+
+    let (result, limited) = run(expr, "1+2*3+4*(5-1)", Some(0));
+    assert_eq!(result, Ok(Some(value!(23))));
+
+    if cfg!(feature = "inline_parselets") {
+        assert_eq!(limited.peak, stats.peak);
+    } else {
+        assert!(limited.peak < stats.peak);
+    }
+
+    // Factor accepting whitespace is too large to be inlined, so its results are memoized
+    let expr = include_str!("../examples/expr_with_spaces.tok");
+    let input = "1 + 2 * 3 + 4 * (5 - 1)";
+
+    let (result, stats) = run(expr, input, None);
+    assert_eq!(result, Ok(Some(value!(23))));
+
+    let (result, limited) = run(expr, input, Some(0));
+    assert_eq!(result, Ok(Some(value!(23))));
+    assert!(limited.peak < stats.peak);
+
+    // When the limit is reached, entries with lower offsets make room for new ones
+    let (result, evicting) = run(expr, input, Some(4));
+    assert_eq!(result, Ok(Some(value!(23))));
+    assert!(evicting.peak < stats.peak);
+    assert!(evicting.hits > limited.hits);
+}
+
+#[test]
+// Scripted debugger session with stepping, breakpoints and inspection
+fn debugger() {
+    use crate::{Compiler, vm::DebugConsole, vm::Debugger};
+    use std::{cell::RefCell, io::Cursor, rc::Rc};
+
+    struct Output(Rc<RefCell<Vec<u8>>>);
+
+    impl std::io::Write for Output {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let src = "f : @x {\n    y = x * 2\n    y + 1\n}\na = 1\nb = f(a)\nb";
+    let program = Compiler::new().compile_from_str(src).unwrap().unwrap();
+
+    let run = |commands: &str, breakpoints: &[u32], step: bool| {
+        let output = Rc::new(RefCell::new(Vec::new()));
+        let mut console = DebugConsole::new(
+            Debugger::new(step),
+            Box::new(Cursor::new(commands.to_string())),
+            Box::new(Output(output.clone())),
+        );
+        console
+            .debugger
+            .breakpoints
+            .extend(breakpoints.iter().map(|row| (None, *row)));
+
+        let result = with_thread(&program, "", |thread| {
+            thread.debugger = Some(Box::new(console));
+            thread.run()
+        });
+        let output = String::from_utf8(output.borrow().clone()).unwrap();
+        (result, output)
+    };
+
+    // Step into f, inspect locals and finish
+    let (result, output) = run("s\ns\nlocals\nf\nc\n", &[], true);
+    assert_eq!(result, Ok(Some(value!(3))));
+    assert!(output.contains("__main__ at line 6"));
+    assert!(output.contains(".f at line 2"));
+    assert!(output.contains(" x = 1\n"));
+    assert!(output.contains(" y = void\n"));
+
+    // Step over f
+    let (_, output) = run("n\nn\nc\n", &[], true);
+    assert!(!output.contains(".f at"));
+    assert!(output.contains("__main__ at line 7"));
+
+    // Stop at breakpoint only
+    let (_, output) = run("c\n", &[3], false);
+    assert!(output.starts_with(".f at line 3"));
+    assert_eq!(output.matches("(tokay)").count(), 1);
+
+    // Abort program
+    let (result, _) = run("q\n", &[], true);
+    assert!(result.is_err());
+
+    // Breakpoints refer to a file, or the current source by default
+    assert_eq!(
+        Debugger::parse_breakpoint("3", Some("main.tok")),
+        Ok((Some("main.tok".to_string()), 3))
+    );
+    assert_eq!(
+        Debugger::parse_breakpoint("lib/module.tok:7", Some("main.tok")),
+        Ok((Some("lib/module.tok".to_string()), 7))
+    );
+    assert_eq!(Debugger::parse_breakpoint("3", None), Ok((None, 3)));
+    assert!(Debugger::parse_breakpoint("main.tok:x", None).is_err());
+}
+
+#[test]
+#[cfg(feature = "dap")]
+// Scripted Debug Adapter Protocol session
+fn dap() {
+    use crate::{dap, utils};
+    use serde_json::{Value as Json, json};
+    use std::io::{BufReader, Read, Write};
+    use std::sync::mpsc::{Receiver, Sender, channel};
+
+    // Byte stream between client and server
+    struct Pipe(Receiver<Vec<u8>>, Vec<u8>);
+
+    impl Read for Pipe {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            if self.1.is_empty() {
+                self.1 = self.0.recv().unwrap_or_default();
+            }
+
+            let len = buf.len().min(self.1.len());
+            buf[..len].copy_from_slice(&self.1[..len]);
+            self.1.drain(..len);
+            Ok(len)
+        }
+    }
+
+    struct Sink(Sender<Vec<u8>>);
+
+    impl Write for Sink {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            let _ = self.0.send(buf.to_vec());
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let path = std::env::temp_dir().join("tokay_dap_test.tok");
+    std::fs::write(
+        &path,
+        "f : @x {\n    y = x * 2\n    y + 1\n}\nprint(f(1))\n",
+    )
+    .unwrap();
+
+    let (requests, server_input) = channel();
+    let (server_output, responses) = channel();
+
+    let client = std::thread::spawn(move || {
+        let mut input = BufReader::new(Pipe(responses, Vec::new()));
+        let mut output = Sink(requests);
+        let mut seq = 0;
+
+        let mut request = |command: &str, arguments: Json| {
+            seq += 1;
+            utils::write_message(
+                &mut output,
+                &json!({"seq": seq, "type": "request", "command": command, "arguments": arguments}),
+            )
+            .unwrap();
+        };
+
+        // Receive messages until one matches
+        let mut until = |key: &str, value: &str| loop {
+            let message = utils::read_message(&mut input).unwrap().unwrap();
+            if message[key] == value {
+                return message;
+            }
+        };
+
+        request("initialize", json!({"adapterID": "tokay"}));
+        until("event", "initialized");
+        request("launch", json!({"program": path}));
+        request(
+            "setBreakpoints",
+            json!({"source": {"path": path}, "breakpoints": [{"line": 3}]}),
+        );
+        // Breakpoints of another source are kept apart
+        request(
+            "setBreakpoints",
+            json!({"source": {"path": "other.tok"}, "breakpoints": [{"line": 1}]}),
+        );
+        request("configurationDone", json!({}));
+
+        let stopped = until("event", "stopped");
+        assert_eq!(stopped["body"]["reason"], "breakpoint");
+
+        request("stackTrace", json!({"threadId": 1}));
+        let frames = until("command", "stackTrace")["body"]["stackFrames"].clone();
+        assert_eq!(frames[0]["name"], "f");
+        assert_eq!(frames[0]["line"], 3);
+        assert_eq!(frames[1]["name"], "__main__");
+
+        request("variables", json!({"variablesReference": 1}));
+        let variables = until("command", "variables")["body"]["variables"].clone();
+        assert_eq!(variables[0]["name"], "x");
+        assert_eq!(variables[0]["value"], "1");
+        assert_eq!(variables[1]["name"], "y");
+        assert_eq!(variables[1]["value"], "2");
+
+        request("stepOut", json!({"threadId": 1}));
+        let stopped = until("event", "stopped");
+        assert_eq!(stopped["body"]["reason"], "step");
+
+        request("continue", json!({"threadId": 1}));
+        let output = until("event", "output");
+        assert_eq!(output["body"]["output"], "3\n");
+        let exited = until("event", "exited");
+        assert_eq!(exited["body"]["exitCode"], 0);
+
+        request("disconnect", json!({}));
+        until("command", "disconnect");
+    });
+
+    dap::serve(
+        Box::new(BufReader::new(Pipe(server_input, Vec::new()))),
+        Box::new(Sink(server_output)),
+    )
+    .unwrap();
+
+    client.join().unwrap();
+}
+
+#[test]
+#[cfg(feature = "lsp")]
+// Scripted Language Server Protocol session
+fn lsp() {
+    use crate::{lsp, utils};
+    use serde_json::{Value as Json, json};
+    use std::io::Cursor;
+
+    let uri = "file:///test.tok";
+    let text =
+        "Pair : @key, value=1 {\n    x = key\n    Int x value\n}\nPair(Int)\nprint(\"😀\" + y)\n";
+
+    // Document importing a module with errors
+    let module_uri = format!("file://{}/tests/lsp.tok", env!("CARGO_MANIFEST_DIR"));
+    let module_text = "\nuse \"modules/broken\"\n";
+
+    let mut input = Vec::new();
+    let mut id = 0;
+    let mut request = |method: &str, params: Json| {
+        id += 1;
+        utils::write_message(
+            &mut input,
+            &json!({"jsonrpc": "2.0", "id": id, "method": method, "params": params}),
+        )
+        .unwrap();
+    };
+
+    let at = |line: u32, character: u32| json!({"textDocument": {"uri": uri}, "position": {"line": line, "character": character}});
+
+    request("initialize", json!({}));
+    request(
+        "textDocument/didOpen",
+        json!({"textDocument": {"uri": uri, "text": text}}),
+    );
+    request(
+        "textDocument/didOpen",
+        json!({"textDocument": {"uri": module_uri, "text": module_text}}),
+    );
+    request("textDocument/hover", at(4, 1));
+    request("textDocument/hover", at(4, 6));
+    request("textDocument/definition", at(2, 11));
+    request("textDocument/completion", at(2, 4));
+    request("shutdown", json!(null));
+    request("exit", json!(null));
+
+    let mut output = Vec::new();
+    lsp::serve(&mut Cursor::new(input), &mut output).unwrap();
+
+    let mut messages = Vec::new();
+    let mut output = Cursor::new(output);
+    while let Some(message) = utils::read_message(&mut output).unwrap() {
+        messages.push(message);
+    }
+
+    let response = |id: u32| {
+        messages
+            .iter()
+            .find(|message| message["id"] == id)
+            .unwrap()
+            .clone()
+    };
+
+    assert!(response(1)["result"]["capabilities"]["hoverProvider"] == true);
+
+    let diagnostics = |uri: &str| {
+        messages
+            .iter()
+            .find(|message| {
+                message["method"] == "textDocument/publishDiagnostics"
+                    && message["params"]["uri"] == uri
+            })
+            .unwrap()["params"]["diagnostics"]
+            .clone()
+    };
+
+    // Undefined name is reported, with its column in UTF-16 code units
+    let diagnostic = &diagnostics(uri)[0];
+    assert_eq!(
+        diagnostic["range"]["start"],
+        json!({"line": 5, "character": 13})
+    );
+    assert!(diagnostic["message"].as_str().unwrap().contains("y"));
+
+    // Errors of an imported module are reported at the import
+    let diagnostics = diagnostics(&module_uri);
+    assert_eq!(diagnostics.as_array().unwrap().len(), 1);
+    assert_eq!(diagnostics[0]["range"]["start"]["line"], 1);
+    assert!(
+        diagnostics[0]["message"]
+            .as_str()
+            .unwrap()
+            .contains("In module 'modules/broken'")
+    );
+
+    let hover = |id: u32| response(id)["result"]["contents"]["value"].clone();
+    assert!(hover(4).as_str().unwrap().contains("Pair : @key, value=1"));
+    assert!(hover(5).as_str().unwrap().contains("Int : @base=void"));
+
+    let definition = response(6)["result"]["range"].clone();
+    assert_eq!(definition["start"], json!({"line": 0, "character": 13}));
+
+    let completion = response(7)["result"].clone();
+    let labels: Vec<&str> = completion
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["label"].as_str().unwrap())
+        .collect();
+    assert!(labels.contains(&"key"));
+    assert!(labels.contains(&"x"));
+    assert!(labels.contains(&"Pair"));
+    assert!(labels.contains(&"print"));
+    assert!(labels.contains(&"Repeat"));
+    assert!(labels.contains(&"accept"));
+}
+
 tokay_macros::tokay_tests!("tests/*.tok");
+
+#[test]
+// Results of the main parselet handed to on_result one by one
+fn thread_on_result() {
+    use crate::Compiler;
+    use std::{cell::RefCell, rc::Rc};
+
+    let program = Compiler::new()
+        .compile_from_str("begin 0; Int; end 99")
+        .unwrap()
+        .unwrap();
+
+    let results = Rc::new(RefCell::new(Vec::new()));
+    let collect = results.clone();
+
+    with_thread(&program, "1 2 3", |thread| {
+        thread.on_result = Some(Box::new(move |value| collect.borrow_mut().push(value)));
+        assert_eq!(thread.run(), Ok(None));
+    });
+    assert_eq!(
+        *results.borrow(),
+        vec![value!(0), value!(1), value!(2), value!(3), value!(99)]
+    );
+}
+
+#[test]
+#[cfg(feature = "io")]
+// File I/O builtins, which must be permitted by the compiler or the thread
+fn file_io() {
+    use crate::Compiler;
+
+    let filename = std::env::temp_dir().join(format!("tokay-file-io-{}.txt", std::process::id()));
+    let filename = filename.to_str().unwrap().replace('\\', "/");
+
+    let src = format!(
+        r#"
+        f = open("{filename}", "w")
+        f.write("Hello\n")
+        f.close()
+
+        f = open("{filename}", "a")
+        f.write("World\r\n")
+        f.close()
+
+        lines = list()
+        for line in open("{filename}") lines.push(line)
+
+        (lines, open("{filename}").read())
+        "#
+    );
+
+    let program = Compiler::new().compile_from_str(&src).unwrap().unwrap();
+
+    let run = |allow_io: bool| {
+        with_thread(&program, "", |thread| {
+            thread.allow_io = allow_io;
+            thread.run()
+        })
+    };
+
+    // Not permitted by default
+    assert_eq!(
+        run(false).unwrap_err().message,
+        "open() file I/O is not permitted"
+    );
+
+    assert_eq!(
+        run(true),
+        Ok(Some(value!([["Hello", "World"], "Hello\nWorld\r\n"])))
+    );
+
+    // Permitted by the compiler
+    let mut compiler = Compiler::new();
+    compiler.allow_io = true;
+
+    let program = compiler.compile_from_str(&src).unwrap().unwrap();
+    assert!(program.run_from_str("").is_ok());
+
+    // Reading invalid UTF-8 raises an error
+    std::fs::write(&filename, b"Hello\n\xffWorld\n").unwrap();
+
+    let program = compiler
+        .compile_from_str(&format!(r#"for line in open("{filename}") line"#))
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        program.run_from_str("").unwrap_err().message,
+        format!(
+            "file_lines() failed on {:?}: stream did not contain valid UTF-8",
+            filename
+        )
+    );
+
+    std::fs::remove_file(filename).unwrap();
+}
+
+#[test]
+// Main parselet processing several inputs with per-file begin and end
+fn main_inputs() {
+    use crate::Compiler;
+    use std::io::Cursor;
+
+    let program = Compiler::new()
+        .compile_from_str(
+            "beginfile cnt = 0\n'skip' next_input()\nInt cnt += 1 void\nendfile (FILENAME, cnt)",
+        )
+        .unwrap()
+        .unwrap();
+
+    let mut readers: Vec<Reader> = [("a", "1 2 3"), ("b", "4 skip 5"), ("c", "6")]
+        .into_iter()
+        .map(|(filename, input)| {
+            Reader::new(
+                Some(filename.to_string()),
+                Box::new(Cursor::new(input.to_string())),
+            )
+        })
+        .collect();
+
+    let mut thread = Thread::new(&program, readers.iter_mut().collect());
+
+    assert_eq!(
+        thread.run(),
+        Ok(Some(value!([["a", 3], ["b", 1], ["c", 1]])))
+    );
+
+    // Per-file begin and end are only allowed in the main scope
+    let errors = Compiler::new()
+        .compile_from_str("F : @{ beginfile 1 }")
+        .unwrap_err();
+
+    assert_eq!(
+        errors[0].message,
+        "'beginfile' may only be used in the main scope"
+    );
+}
@@ -250,3 +250,57 @@ pub(crate) fn testcase(code: &str) {
         )
     }
 }
+
+/// Read a JSON message framed by a `Content-Length` header.
+#[cfg(any(feature = "dap", feature = "lsp"))]
+pub(crate) fn read_message(
+    input: &mut dyn std::io::BufRead,
+) -> std::io::Result<Option<serde_json::Value>> {
+    let mut length = None;
+
+    loop {
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = line.split_once(':')
+            && name.eq_ignore_ascii_case("Content-Length")
+        {
+            length = value.trim().parse::<usize>().ok();
+        }
+    }
+
+    let Some(length) = length else {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "Missing Content-Length header",
+        ));
+    };
+
+    let mut content = vec![0; length];
+    input.read_exact(&mut content)?;
+
+    Ok(Some(serde_json::from_slice(&content)?))
+}
+
+/// Write a JSON message framed by a `Content-Length` header.
+#[cfg(any(feature = "dap", feature = "lsp"))]
+pub(crate) fn write_message(
+    output: &mut dyn Write,
+    message: &serde_json::Value,
+) -> std::io::Result<()> {
+    let content = message.to_string();
+    write!(
+        output,
+        "Content-Length: {}\r\n\r\n{}",
+        content.len(),
+        content
+    )?;
+    output.flush()
+}
@@ -1,7 +1,7 @@
 //! Dictionary object
 use super::{BoxedObject, MethodIter, Object, RefValue, Str};
-use crate::Error;
 use crate::value;
+use crate::{Error, ErrorKind};
 use indexmap::IndexMap;
 use tokay_macros::tokay_method;
 extern crate self as tokay;
@@ -300,11 +300,10 @@ impl Dict {
         }
 
         if !key.is_hashable() {
-            return Err(Error::from(format!(
-                "{} unhashable type '{}'",
-                __function,
-                key.name()
-            )));
+            return Err(
+                Error::from(format!("{} unhashable type '{}'", __function, key.name()))
+                    .with_kind(ErrorKind::Type),
+            );
         }
 
         if upsert.is_true() {
@@ -334,6 +333,34 @@ impl Dict {
         }
     });
 
+    /** Checks if `key` is in `dict`.
+
+    This method is also invoked by the `in` and `not in` operators.
+    */
+    tokay_method!("dict_has : @dict, key", {
+        let dict = dict.borrow();
+
+        if let Some(dict) = dict.object::<Dict>() {
+            if !key.is_hashable() {
+                return Err(Error::from(format!(
+                    "{} unhashable type '{}'",
+                    __function,
+                    key.name()
+                ))
+                .with_kind(ErrorKind::Type));
+            }
+
+            Ok(value!(dict.get(&key).is_some()))
+        } else {
+            Err(Error::from(format!(
+                "{} only accepts '{}' as parameter, not '{}'",
+                __function,
+                "dict",
+                dict.name()
+            )))
+        }
+    });
+
     /** Insert or replace `value` under the given `key` in `dict`.
 
     When `value` is provided as void, the key is removed.
@@ -345,11 +372,10 @@ impl Dict {
     */
     tokay_method!("dict_set_item : @dict, key, value=void", {
         if !key.is_hashable() {
-            return Err(Error::from(format!(
-                "{} unhashable type '{}'",
-                __function,
-                key.name()
-            )));
+            return Err(
+                Error::from(format!("{} unhashable type '{}'", __function, key.name()))
+                    .with_kind(ErrorKind::Type),
+            );
         }
 
         let mut dict = dict.borrow_mut();
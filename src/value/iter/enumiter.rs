@@ -55,4 +55,10 @@ impl RefValueIter for EnumIter {
         Iter::iter_rev(vec![self.iter.clone()], None)?;
         Ok(())
     }
+
+    fn take_error(&mut self) -> Option<Error> {
+        let mut iter = self.iter.borrow_mut();
+        let iter = iter.object_mut::<Iter>().expect("Iter object expected");
+        iter.iter.take_error()
+    }
 }
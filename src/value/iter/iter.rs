@@ -34,6 +34,11 @@ pub trait RefValueIter: CloneBoxedRefValueIter {
     fn rev(&mut self) -> Result<(), Error> {
         Err(Error::from("This iterator cannot be reversed."))
     }
+
+    /// Takes the error which stopped the iteration early, if any.
+    fn take_error(&mut self) -> Option<Error> {
+        None
+    }
 }
 
 /// Iter implementing Object to be used as RefValue
@@ -66,11 +71,13 @@ impl Iter {
         let mut iter = iter.borrow_mut();
 
         if let Some(iter) = iter.object_mut::<Iter>() {
-            Ok(RefValue::from(
-                iter.iter
-                    .next(context)
-                    .unwrap_or_else(|| tokay::value!(void)),
-            ))
+            match iter.iter.next(context) {
+                Some(next) => Ok(next),
+                None => match iter.iter.take_error() {
+                    Some(error) => Err(error),
+                    None => Ok(tokay::value!(void)),
+                },
+            }
         } else {
             Err(Error::from(format!(
                 "{} only accepts '{}' as parameter, not '{}'",
@@ -143,6 +150,10 @@ impl Iter {
                 list.push(item);
             }
 
+            if let Some(error) = iter.iter.take_error() {
+                return Err(error);
+            }
+
             Ok(RefValue::from(list))
         } else {
             return Err(Error::from(format!(
@@ -1,5 +1,6 @@
 //! List object
-use super::{BoxedObject, Iter, Object, RefValue};
+use super::{BoxedObject, Iter, Object, RefValue, Slice};
+use crate::Error;
 use crate::value;
 use tokay_macros::tokay_method;
 extern crate self as tokay;
@@ -122,6 +123,8 @@ impl List {
 
     Otherwise, `default` is just returned when the specified `item` is not present.
 
+    When `index` is a `slice`, a new list containing the sliced items is returned.
+
     This method is also invoked when using the `dict` item syntax.
     */
     tokay_method!(
@@ -134,9 +137,22 @@ impl List {
 
             {
                 let list = list.borrow();
+                let list = list.object::<List>().unwrap();
+
+                // Slicing creates a new list
+                if let Some(slice) = index.borrow().object::<Slice>() {
+                    return Ok(RefValue::from(
+                        slice
+                            .indexes(list.len())
+                            .into_iter()
+                            .map(|index| list[index].clone())
+                            .collect::<InnerList>(),
+                    ));
+                }
+
                 let index = index.to_usize()?;
 
-                if let Some(value) = list.object::<List>().unwrap().get(index) {
+                if let Some(value) = list.get(index) {
                     return Ok(value.clone());
                 }
             }
@@ -160,6 +176,45 @@ impl List {
             list = Self::list(vec![list], None)?;
         }
 
+        // Slice assignment replaces the addressed items by the items of value
+        if let Some(slice) = item.borrow().object::<Slice>() {
+            let items = List::from(&value);
+
+            let mut list = list.borrow_mut();
+            let list = list.object_mut::<List>().unwrap();
+
+            let (start, stop, step) = slice.resolve(list.len());
+
+            if step == 1 {
+                let (start, stop) = (start as usize, stop.max(start) as usize);
+                list.splice(start..stop, items);
+            } else {
+                let mut indexes = slice.indexes(list.len());
+
+                if value.is_void() {
+                    // Remove items from the back, so that the remaining indexes stay valid
+                    indexes.sort_unstable();
+
+                    for index in indexes.into_iter().rev() {
+                        list.remove(index);
+                    }
+                } else if items.len() != indexes.len() {
+                    return Err(Error::from(format!(
+                        "{} cannot assign {} items to a slice of {} items",
+                        __function,
+                        items.len(),
+                        indexes.len()
+                    )));
+                } else {
+                    for (index, item) in indexes.into_iter().zip(items) {
+                        list[index] = item;
+                    }
+                }
+            }
+
+            return Ok(value);
+        }
+
         let mut list = list.borrow_mut();
         let list = list.object_mut::<List>().unwrap();
 
@@ -410,6 +465,22 @@ impl List {
         Ok(list)
     });
 
+    /** Checks if `item` is in `list`.
+
+    This method is also invoked by the `in` and `not in` operators.
+    */
+    tokay_method!("list_contains : @list, item", {
+        let list = list.borrow();
+
+        if let Some(list) = list.object::<List>() {
+            return Ok(value![
+                list.iter().any(|val| *val.borrow() == *item.borrow())
+            ]);
+        }
+
+        Ok(value![false])
+    });
+
     /** Find `item` in `list` and return its offset.
 
     In case `item` is not in the list, -1 is returned.
@@ -7,6 +7,7 @@ mod method;
 mod object;
 mod parselet;
 mod refvalue;
+pub mod slice;
 pub mod str;
 pub mod token;
 pub mod value;
@@ -20,7 +21,8 @@ pub use method::Method;
 pub use object::{BoxedObject, Object};
 pub(crate) use parselet::{Parselet, ParseletRef};
 pub use refvalue::RefValue;
-pub use token::Token;
+pub use slice::Slice;
+pub use token::{Regex, Token};
 pub use value::Value;
 
 /** Value construction macro
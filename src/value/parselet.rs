@@ -1,7 +1,8 @@
 //! Parselet object represents a callable, user-defined function.
 
 use super::{BoxedObject, Dict, Object, RefValue};
-use crate::error::Error;
+use crate::error::{Error, ErrorKind};
+use crate::reader::Source;
 use crate::vm::*;
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -25,11 +26,26 @@ pub struct Parselet {
     pub name: String,                   // Parselet's name from source (for debugging)
     pub(crate) consuming: Option<bool>, // Indicator for consuming & left-recursion
     pub(crate) severity: u8,            // Capture push severity
-    signature: Vec<(String, Option<usize>)>, // Argument signature with default arguments
+    #[cfg_attr(feature = "serde", serde(default = "default_memoize"))]
+    pub(crate) memoize: bool, // Memoize results of consuming parselet
+    pub(crate) signature: Vec<(String, Option<usize>)>, // Argument signature with default arguments
     pub(crate) locals: usize,           // Number of local variables present
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) variables: Vec<String>, // Names of local variables by address (for debugging)
     pub(crate) begin: Vec<Op>,          // Begin-operations
     pub(crate) end: Vec<Op>,            // End-operations
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) begin_file: Vec<Op>, // Begin-operations per input file (main only)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) end_file: Vec<Op>, // End-operations per input file (main only)
     pub(crate) body: Vec<Op>,           // Operations
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) source: Option<Rc<Source>>, // Source the parselet is defined in
+}
+
+#[cfg(feature = "serde")]
+fn default_memoize() -> bool {
+    true
 }
 
 impl Parselet {
@@ -38,6 +54,7 @@ impl Parselet {
         name: Option<String>,
         consuming: Option<bool>,
         severity: u8,
+        memoize: bool,
         signature: Vec<(String, Option<usize>)>,
         locals: usize,
         begin: Vec<Op>,
@@ -53,11 +70,16 @@ impl Parselet {
             name: name.unwrap_or(String::new()),
             consuming,
             severity,
+            memoize,
             signature,
             locals,
+            variables: Vec::new(),
             begin,
             end,
+            begin_file: Vec::new(),
+            end_file: Vec::new(),
             body,
+            source: None,
         };
 
         if ret.name.is_empty() {
@@ -67,6 +89,49 @@ impl Parselet {
         ret
     }
 
+    /** Key to memoize a call of a consuming parselet, if memoized.
+
+    The memo key contains the argument values, so that calls with different arguments are
+    memoized separately. */
+    #[inline(never)]
+    fn memo_key(
+        &self,
+        thread: &mut Thread,
+        args: &mut [Capture],
+        main: bool,
+    ) -> Result<Option<MemoKey>, Reject> {
+        if !(self.consuming == Some(true) || (self.consuming.is_some() && self.memoize)) {
+            return Ok(None);
+        }
+
+        let values: Option<Vec<RefValue>> = args[..if main { 0 } else { self.signature.len() }]
+            .iter_mut()
+            .map(|arg| {
+                let value = arg.extract(thread.reader);
+                (value.is_void() || value.is_hashable()).then_some(value)
+            })
+            .collect();
+
+        match values {
+            Some(values) => Ok(Some((
+                thread.reader.tell().offset,
+                self as *const Parselet as usize, // Unique parselet id from memory address
+                values,
+            ))),
+            // Left-recursive parselets rely on memoization
+            None if self.consuming == Some(true) => Err(Error::new(
+                None,
+                format!(
+                    "Left-recursive {}() must be called with hashable arguments",
+                    self.name
+                ),
+            )
+            .into()),
+            // Otherwise, calls with mutable arguments are not memoized
+            None => Ok(None),
+        }
+    }
+
     /** Run parselet on a given thread.
 
     The main-parameter defines if the parselet behaves like a main loop or
@@ -79,29 +144,12 @@ impl Parselet {
         main: bool,
         depth: usize,
     ) -> Result<Accept, Reject> {
-        // Get unique parselet id from memory address
-        let id = self as *const Parselet as usize;
-
-        // When parselet is consuming, try to read previous result from cache.
-        if self.consuming.is_some() {
-            let reader_start = thread.reader.tell();
-
-            // Check for a previously memoized result
-            // fixme: This doesn't recognize calls to the same parselet with same parameters,
-            //        which might lead in unwanted results. This must be checked! It might become
-            //        a problem when the Repeat<P>(min=0, max=void) generic parselet becomes available.
-            if let Some((reader_end, result)) = thread.memo.get(&(reader_start.offset, id)) {
-                thread.reader.reset(*reader_end);
-                return result.clone();
-            }
-        }
-
         let args_len = args.len();
 
         // Check for provided argument count bounds first
         // todo: Not executed when *args-catchall is implemented
         if args_len > self.signature.len() {
-            return Err(match self.signature.len() {
+            return argument_error(match self.signature.len() {
                 0 => format!(
                     "{}() doesn't accept any arguments ({} given)",
                     self.name, args_len
@@ -116,9 +164,7 @@ impl Parselet {
                     self.signature.len(),
                     args_len
                 ),
-            }
-            .into())
-            .into();
+            });
         }
 
         if main {
@@ -161,11 +207,10 @@ impl Parselet {
                         continue;
                     }
 
-                    return Error::new(
-                        None,
-                        format!("{}() expected argument '{}'", self.name, arg.0),
-                    )
-                    .into();
+                    return argument_error(format!(
+                        "{}() expected argument '{}'",
+                        self.name, arg.0
+                    ));
                 }
             }
 
@@ -173,7 +218,7 @@ impl Parselet {
             // todo: Not executed when **nargs-catchall is implemented
             if let Some(mut nargs) = nargs {
                 if let Some((name, _)) = nargs.pop() {
-                    return Err(match nargs.len() {
+                    return argument_error(match nargs.len() {
                         0 => format!(
                             "{}() doesn't accept named argument '{}'",
                             self.name,
@@ -184,21 +229,29 @@ impl Parselet {
                             self.name,
                             n + 1
                         ),
-                    }
-                    .into())
-                    .into();
+                    });
                 }
             }
         }
 
+        let reader_start = thread.reader.tell();
+
+        // When parselet is consuming, try to read previous result from cache.
+        let memo = self.memo_key(thread, &mut args, main)?;
+
+        // Check for a previously memoized result
+        if let Some((reader_end, result)) = memo.as_ref().and_then(|memo| thread.memo.get(memo)) {
+            thread.reader.reset(*reader_end);
+            return result.clone();
+        }
+
         // Create a new conrext
         let mut context = Context::new(thread, self, depth, args);
 
         //println!("remaining {:?}", nargs);
-        let reader_start = context.frame0().reader_start;
 
         // Perform left-recursive execution
-        let result = if let Some(true) = self.consuming {
+        let result = if let (Some(true), Some(memo)) = (self.consuming, memo.as_ref()) {
             /*
             println!(
                 "--- {} @ {} ---",
@@ -215,7 +268,7 @@ impl Parselet {
             context
                 .thread
                 .memo
-                .insert((reader_start.offset, id), (reader_end, result.clone()));
+                .insert(memo.clone(), (reader_end, result.clone()), true);
 
             loop {
                 let loop_result = context.run(main);
@@ -247,7 +300,7 @@ impl Parselet {
                 context
                     .thread
                     .memo
-                    .insert((reader_start.offset, id), (reader_end, result.clone()));
+                    .insert(memo.clone(), (reader_end, result.clone()), true);
 
                 // Reset reader & stack
                 context.thread.reader.reset(reader_start);
@@ -263,10 +316,11 @@ impl Parselet {
         } else {
             let result = context.run(main);
 
-            if self.consuming.is_some() {
+            if let Some(memo) = memo {
                 context.thread.memo.insert(
-                    (reader_start.offset, id),
+                    memo,
                     (context.thread.reader.tell(), result.clone()),
+                    false,
                 );
             }
 
@@ -297,6 +351,57 @@ impl Parselet {
 
         result
     }
+
+    /** Check if the parselet requires all the input it consumed so far.
+
+    This is the case when capture 0 is accessed, or `print()` is called without arguments,
+    which prints capture 0. The check includes all parselets called from this parselet, and all
+    parselets loaded as values, as these can be called at runtime, e.g. from a variable. Main
+    parselets not requiring their input allow to discard it after every iteration, so that input
+    is processed in bounded memory.
+    */
+    pub(crate) fn requires_input(&self, program: &Program) -> bool {
+        fn requires(
+            parselet: &Parselet,
+            program: &Program,
+            visited: &mut std::collections::HashSet<usize>,
+        ) -> bool {
+            if !visited.insert(parselet as *const Parselet as usize) {
+                return false;
+            }
+
+            parselet
+                .begin
+                .iter()
+                .chain(&parselet.begin_file)
+                .chain(&parselet.body)
+                .chain(&parselet.end_file)
+                .chain(&parselet.end)
+                .any(|op| {
+                    let addr = match op {
+                        Op::LoadFastCapture(0) | Op::LoadCapture => return true,
+                        // Values loaded from statics may be called later on
+                        Op::CallStatic(addr) | Op::LoadStatic(addr) => *addr,
+                        Op::CallStaticArg(call) | Op::CallStaticArgNamed(call) => call.0,
+                        _ => return false,
+                    };
+
+                    let value = program.statics[addr].borrow();
+
+                    if let Some(parselet) = value.object::<ParseletRef>() {
+                        requires(&parselet.0.borrow(), program, visited)
+                    } else if let Some(builtin) = value.object::<crate::builtin::BuiltinRef>() {
+                        matches!(op, Op::CallStatic(_) | Op::LoadStatic(_))
+                            && matches!(builtin.0.name, "print" | "eprint")
+                    } else {
+                        // Dynamic builtins may access any captures
+                        value.object::<super::dynbuiltin::DynBuiltinRef>().is_some()
+                    }
+                })
+        }
+
+        requires(self, program, &mut std::collections::HashSet::new())
+    }
 }
 
 impl From<Parselet> for RefValue {
@@ -403,3 +508,11 @@ impl<'de> serde::Deserialize<'de> for ParseletRef {
         Ok(ParseletRef(Rc::new(RefCell::new(value))))
     }
 }
+
+/// Rejects with an argument error.
+#[inline(never)]
+fn argument_error(message: String) -> Result<Accept, Reject> {
+    Error::new(None, message)
+        .with_kind(ErrorKind::Argument)
+        .into()
+}
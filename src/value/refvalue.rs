@@ -2,7 +2,7 @@ use super::{BoxedObject, Dict, Method, Object, Str, Token, Value};
 use crate::builtin::{Builtin, BuiltinRef};
 use crate::value;
 use crate::{Accept, Context, Error, Reject};
-use num::{ToPrimitive, Zero};
+use num::{Integer, Signed, ToPrimitive, Zero};
 use num_bigint::BigInt;
 use std::cell::RefCell;
 use std::hash::{Hash, Hasher};
@@ -33,6 +33,51 @@ impl RefValue {
         }));
     }
 
+    /** Loads the attribute `attr` from a value.
+
+    Attributes are resolved to methods of the value. For dicts, attributes which are not
+    methods fall back to the dict's keys, so that `node.children` retrieves `node["children"]`. */
+    pub fn load_attr(&self, attr: &str) -> Result<RefValue, Error> {
+        match self.create_method(attr) {
+            Ok(method) => Ok(method),
+            Err(err) => {
+                if let Some(dict) = self.borrow().object::<Dict>()
+                    && let Some(value) = dict.get_str(attr)
+                {
+                    return Ok(value.clone());
+                }
+
+                Err(err)
+            }
+        }
+    }
+
+    /** Stores `value` as the attribute `attr` of a value.
+
+    Only dicts allow for attribute assignment, where the attribute is stored as key. Attributes
+    which are methods can't be assigned, as `load_attr()` would never retrieve them. */
+    pub fn store_attr(&self, attr: &str, value: RefValue) -> Result<RefValue, Error> {
+        if !self.is("dict") {
+            return Err(Error::from(format!(
+                "Cannot assign attribute '{}' to '{}'",
+                attr,
+                self.name()
+            )));
+        }
+
+        if Builtin::get_method(self.name(), attr).is_ok() {
+            return Err(Error::from(format!(
+                "Cannot assign attribute '{}' to '{}', as it is a method",
+                attr,
+                self.name()
+            )));
+        }
+
+        Ok(self
+            .call_method("set_item", None, vec![RefValue::from(attr), value], None)?
+            .unwrap())
+    }
+
     /** Performs a direct method call on a value.
 
     This function is designed to invoke methods on values directly from Rust code. */
@@ -75,9 +120,10 @@ impl RefValue {
                     }
                 }
                 Value::Int(int) => {
-                    // int fast lane neg, iinc, idec
+                    // int fast lane neg, bitnot, iinc, idec
                     match op {
                         "neg" => return Ok(value!(-int.clone())),
+                        "bitnot" => return Ok(value!(!int.clone())),
                         "not" => return Ok(value!(int.is_zero())),
                         "iinc" => {
                             *int += 1;
@@ -103,12 +149,28 @@ impl RefValue {
                 "iinc" if name == "int" => Ok(self.binary_op(value!(1 as i64), "iadd")?),
                 // default fallback for inline dec is an inline sub by 1
                 "idec" if name == "int" => Ok(self.binary_op(value!(1 as i64), "isub")?),
+                // default fallback for bitnot works on the integer representation
+                "bitnot" if name == "int" => Ok(value!(!self.to_bigint()?)),
                 _ => Err(notfound),
             },
         }
     }
 
     pub fn binary_op(self, operand: RefValue, op: &str) -> Result<RefValue, String> {
+        // Membership is tested by the container, which is the operand
+        if let "in" | "notin" = op {
+            let method = if operand.is("dict") {
+                "has"
+            } else {
+                "contains"
+            };
+            let res = operand
+                .call_method(method, None, vec![self], None)?
+                .is_some_and(|res| res.is_true());
+
+            return Ok(value!(if op == "in" { res } else { !res }));
+        }
+
         let name = {
             // Operations starting with "i" are inline
             if op.starts_with("i") {
@@ -147,15 +209,35 @@ impl RefValue {
                         // Int inline fast-lane
                         (Value::Int(int), no_float) if !no_float.is("float") => match op {
                             "iadd" => {
-                                *int += that.to_i64()?;
+                                *int += that.to_bigint()?;
                                 return Ok(self.clone());
                             }
                             "imul" => {
-                                *int *= that.to_i64()?;
+                                *int *= that.to_bigint()?;
                                 return Ok(self.clone());
                             }
                             "isub" => {
-                                *int -= that.to_i64()?;
+                                *int -= that.to_bigint()?;
+                                return Ok(self.clone());
+                            }
+                            "ibitand" => {
+                                *int &= that.to_bigint()?;
+                                return Ok(self.clone());
+                            }
+                            "ibitor" => {
+                                *int |= that.to_bigint()?;
+                                return Ok(self.clone());
+                            }
+                            "ibitxor" => {
+                                *int ^= that.to_bigint()?;
+                                return Ok(self.clone());
+                            }
+                            "ishl" => {
+                                *int = shift_left(int, &that)?;
+                                return Ok(self.clone());
+                            }
+                            "ishr" => {
+                                *int >>= shift_count(&that)?;
                                 return Ok(self.clone());
                             }
                             _ => None,
@@ -192,9 +274,27 @@ impl RefValue {
                         }
                     }
 
+                    // Bitwise and shift operations aren't available on floats
+                    (Value::Float(_), _) | (_, Value::Float(_))
+                        if matches!(op, "bitand" | "bitor" | "bitxor" | "shl" | "shr") =>
+                    {
+                        Some("float")
+                    }
+
+                    // Integer division is performed on integers
                     (Value::Float(_), _) | (_, Value::Float(_)) if op != "divi" => match op {
                         "add" => return Ok(value!(this.to_f64()? + that.to_f64()?)),
                         "mul" => return Ok(value!(this.to_f64()? * that.to_f64()?)),
+                        "pow" => {
+                            let base = this.to_f64()?;
+                            let exponent = that.to_f64()?;
+
+                            if base == 0.0 && exponent < 0.0 {
+                                return Err(String::from("Division by zero"));
+                            }
+
+                            return Ok(value!(base.powf(exponent)));
+                        }
                         "sub" => return Ok(value!(this.to_f64()? - that.to_f64()?)),
                         "div" | "mod" => {
                             let dividend = this.to_f64()?;
@@ -221,6 +321,43 @@ impl RefValue {
                         "add" => return Ok(value!(this.to_bigint()? + that.to_bigint()?)),
                         "mul" => return Ok(value!(this.to_bigint()? * that.to_bigint()?)),
                         "sub" => return Ok(value!(this.to_bigint()? - that.to_bigint()?)),
+                        "bitand" => return Ok(value!(this.to_bigint()? & that.to_bigint()?)),
+                        "bitor" => return Ok(value!(this.to_bigint()? | that.to_bigint()?)),
+                        "bitxor" => return Ok(value!(this.to_bigint()? ^ that.to_bigint()?)),
+                        "shl" => return Ok(value!(shift_left(&this.to_bigint()?, that)?)),
+                        "shr" => return Ok(value!(this.to_bigint()? >> shift_count(that)?)),
+                        "pow" => {
+                            let base = this.to_bigint()?;
+                            let exponent = that.to_bigint()?;
+
+                            // A negative exponent results in a fraction
+                            if exponent.is_negative() {
+                                if base.is_zero() {
+                                    return Err(String::from("Division by zero"));
+                                }
+
+                                return Ok(value!(this.to_f64()?.powf(that.to_f64()?)));
+                            }
+
+                            // Bases of 0, 1 and -1 don't grow with any exponent
+                            if base.bits() <= 1 {
+                                if exponent.is_zero() || (base.is_negative() && exponent.is_even())
+                                {
+                                    return Ok(value!(1));
+                                }
+
+                                return Ok(value!(base));
+                            }
+
+                            match exponent.to_u64() {
+                                Some(exponent)
+                                    if base.bits().saturating_mul(exponent) <= MAX_INT_BITS =>
+                                {
+                                    return Ok(value!(base.pow(exponent as u32)));
+                                }
+                                _ => return Err(String::from("Exponent is too large")),
+                            }
+                        }
                         "div" | "divi" | "mod" => {
                             let dividend = this.to_bigint()?;
                             let divisor = that.to_bigint()?;
@@ -297,6 +434,33 @@ impl RefValue {
     }
 }
 
+/// Maximum number of bits of integers resulting from left shifts and powers.
+const MAX_INT_BITS: u64 = 1 << 24;
+
+/// Retrieve the number of bits to shift from an operand.
+fn shift_count(value: &Value) -> Result<usize, String> {
+    let count = value.to_bigint()?;
+
+    if count.is_negative() {
+        return Err(String::from("Negative shift count"));
+    }
+
+    count
+        .to_usize()
+        .ok_or_else(|| String::from("Shift count is too large"))
+}
+
+/// Shift an integer to the left by an operand, rejecting results exceeding `MAX_INT_BITS`.
+fn shift_left(int: &BigInt, value: &Value) -> Result<BigInt, String> {
+    let count = shift_count(value)?;
+
+    if int.bits().saturating_add(count as u64) > MAX_INT_BITS {
+        return Err(String::from("Shift count is too large"));
+    }
+
+    Ok(int << count)
+}
+
 impl Object for RefValue {
     fn id(&self) -> usize {
         self.borrow().id()
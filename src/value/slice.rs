@@ -0,0 +1,124 @@
+//! Slice object
+use super::{BoxedObject, Object, RefValue};
+use crate::Error;
+use num::ToPrimitive;
+use num_bigint::Sign;
+use tokay_macros::tokay_method;
+extern crate self as tokay;
+
+/** Slice object type
+
+A slice describes a range of items by `start`, `stop` and `step`, where each of them is optional.
+It is used by the subscript syntax `s[start:stop:step]`, e.g. `s[1:5]` or `l[::-1]`. */
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct Slice {
+    start: Option<i64>,
+    stop: Option<i64>,
+    step: Option<i64>,
+}
+
+impl Object for Slice {
+    fn name(&self) -> &'static str {
+        "slice"
+    }
+
+    fn repr(&self) -> String {
+        fn repr(value: Option<i64>) -> String {
+            value.map_or("void".to_string(), |value| value.to_string())
+        }
+
+        format!(
+            "slice({}, {}, {})",
+            repr(self.start),
+            repr(self.stop),
+            repr(self.step)
+        )
+    }
+
+    // Slices can't be used as dict keys, so that slicing a dict is rejected
+    fn is_hashable(&self) -> bool {
+        false
+    }
+}
+
+#[allow(unused_doc_comments)]
+impl Slice {
+    /** Creates a new `slice` from `start`, `stop` and `step`.
+
+    Any of the values can be void. This is also created by the `s[start:stop:step]` syntax.
+    */
+    tokay_method!("slice : @start=void, stop=void, step=void", {
+        // Values exceeding an i64 are clipped, as they are out of bounds anyway
+        fn index(value: RefValue) -> Result<Option<i64>, String> {
+            if value.is_void() {
+                return Ok(None);
+            }
+
+            let index = value.to_bigint()?;
+            Ok(Some(index.to_i64().unwrap_or(match index.sign() {
+                Sign::Minus => i64::MIN,
+                _ => i64::MAX,
+            })))
+        }
+
+        let slice = Self {
+            start: index(start)?,
+            stop: index(stop)?,
+            step: index(step)?,
+        };
+
+        if slice.step == Some(0) {
+            return Err(Error::from(format!("{} step cannot be zero", __function)));
+        }
+
+        Ok(RefValue::from(slice))
+    });
+
+    /** Resolve the slice into `(start, stop, step)` for a sequence of length `len`.
+
+    Negative `start` and `stop` values are counted from the sequence's end,
+    and values out of bounds are clipped to the sequence's boundaries. */
+    pub fn resolve(&self, len: usize) -> (i64, i64, i64) {
+        let len = len as i64;
+        let step = self.step.unwrap_or(1);
+
+        let (lower, upper) = if step < 0 { (-1, len - 1) } else { (0, len) };
+
+        let clip = |index: Option<i64>, default: i64| match index {
+            None => default,
+            Some(index) if index < 0 => (index + len).max(lower),
+            Some(index) => index.min(upper),
+        };
+
+        (
+            clip(self.start, if step < 0 { upper } else { lower }),
+            clip(self.stop, if step < 0 { lower } else { upper }),
+            step,
+        )
+    }
+
+    /// Resolve the slice into the indexes of the items it addresses in a sequence of length `len`.
+    pub fn indexes(&self, len: usize) -> Vec<usize> {
+        let (start, stop, step) = self.resolve(len);
+
+        let mut indexes = Vec::new();
+        let mut index = start;
+
+        while (step > 0 && index < stop) || (step < 0 && index > stop) {
+            indexes.push(index as usize);
+
+            match index.checked_add(step) {
+                Some(next) => index = next,
+                None => break,
+            }
+        }
+
+        indexes
+    }
+}
+
+impl From<Slice> for RefValue {
+    fn from(value: Slice) -> Self {
+        RefValue::from(Box::new(value) as BoxedObject)
+    }
+}
@@ -1,5 +1,5 @@
 //! String object
-use super::{BoxedObject, List, Object, RefValue};
+use super::{BoxedObject, List, Object, RefValue, Slice};
 use crate::value;
 use num::{ToPrimitive, Zero};
 use num_bigint::{BigInt, Sign};
@@ -106,6 +106,20 @@ impl Str {
 
         let string = s.borrow();
         let string = string.object::<Str>().unwrap();
+
+        // Slicing creates a new string
+        if let Some(slice) = item.borrow().object::<Slice>() {
+            let chars: Vec<char> = string.chars().collect();
+
+            return Ok(RefValue::from(
+                slice
+                    .indexes(chars.len())
+                    .into_iter()
+                    .map(|index| chars[index])
+                    .collect::<String>(),
+            ));
+        }
+
         let mut item = item.to_bigint()?;
 
         // In case the item index is negative, calculate from string's end
@@ -165,6 +179,23 @@ impl Str {
         })
     });
 
+    tokay_method!("str_contains : @s, sub", {
+        if !s.is("str") {
+            s = RefValue::from(s.to_string());
+        }
+
+        let string = s.borrow();
+        let sub = sub.borrow();
+
+        let string = string.object::<Str>().unwrap().as_str();
+
+        Ok(if let Some(sub) = sub.object::<Str>() {
+            value!(string.contains(sub.as_str()))
+        } else {
+            value!(string.contains(&sub.to_string()))
+        })
+    });
+
     tokay_method!("str_endswith : @s, postfix", {
         if !s.is("str") {
             s = RefValue::from(s.to_string());
@@ -1,9 +1,12 @@
 //! Token callables represented by Value::Token
 use super::{BoxedObject, Dict, Object, RefValue};
+use crate::reader::Reader;
 use crate::vm::*;
+use caseless::Caseless;
 use charclass::CharClass;
 use num_bigint::BigInt;
 use num_parse::*;
+use regex_automata::{Anchored, Input, meta, util::syntax};
 use tokay_macros::tokay_token;
 extern crate self as tokay;
 
@@ -12,12 +15,98 @@ extern crate self as tokay;
 #[derive(Debug, Clone, Hash, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Token {
-    Empty,            // Matches the empty word
-    EOF,              // Matches End of File
-    Char(CharClass),  // Matches one character from a character class
-    Chars(CharClass), // Matches multiple characters from a character class
-    Match(String),    // Match a string
-    Touch(String),    // Match a string with zero severity
+    Empty,               // Matches the empty word
+    EOF,                 // Matches End of File
+    Char(CharClass),     // Matches one character from a character class
+    Chars(CharClass),    // Matches multiple characters from a character class
+    Match(String),       // Match a string
+    Touch(String),       // Match a string with zero severity
+    MatchNoCase(String), // Match a string case-insensitive
+    TouchNoCase(String), // Match a string case-insensitive with zero severity
+    Regex(Regex),        // Match a regular expression
+}
+
+/** Regular expression used by `Token::Regex`.
+
+The expression must match at the current reader position within the current line, and `^` and
+`$` match at the beginning and end of a line. Regular expressions are compared and serialized by
+their pattern.
+*/
+#[derive(Debug, Clone)]
+pub struct Regex {
+    pattern: String,
+    regex: meta::Regex,
+}
+
+impl Regex {
+    pub fn new(pattern: &str) -> Result<Self, String> {
+        match meta::Regex::builder()
+            .syntax(syntax::Config::new().multi_line(true))
+            .build(pattern)
+        {
+            Ok(regex) => Ok(Self {
+                pattern: pattern.to_string(),
+                regex,
+            }),
+            Err(err) => {
+                // Syntax errors are reported with the pattern, only take the error's description
+                let err = match err.syntax_error() {
+                    Some(err) => err.to_string(),
+                    None => err.to_string(),
+                };
+                let err = err.lines().last().unwrap_or_default();
+
+                Err(format!(
+                    "Invalid regular expression /{}/: {}",
+                    pattern,
+                    err.strip_prefix("error: ").unwrap_or(err)
+                ))
+            }
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+}
+
+impl std::hash::Hash for Regex {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.pattern.hash(state);
+    }
+}
+
+impl PartialEq for Regex {
+    fn eq(&self, other: &Self) -> bool {
+        self.pattern == other.pattern
+    }
+}
+
+impl PartialOrd for Regex {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.pattern.partial_cmp(&other.pattern)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Regex {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.pattern.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Regex {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let pattern = String::deserialize(deserializer)?;
+        Regex::new(&pattern).map_err(serde::de::Error::custom)
+    }
 }
 
 impl Token {
@@ -71,52 +160,9 @@ impl Token {
             ident => builtin_ccl(ident),
         }
     }
-}
-
-impl Object for Token {
-    fn name(&self) -> &'static str {
-        "token"
-    }
-
-    fn repr(&self) -> String {
-        match self {
-            Token::Empty => "Empty".to_string(),
-            Token::EOF => "EOF".to_string(),
-            Token::Char(ccl) => format!("{:?}", ccl),
-            Token::Chars(ccl) => format!("{:?}+", ccl),
-            Token::Touch(s) => format!("'{}'", s),
-            Token::Match(s) => format!("''{}''", s),
-        }
-    }
-
-    fn is_callable(&self, without_arguments: bool) -> bool {
-        without_arguments // Tokens don't support arguments
-    }
-
-    fn is_consuming(&self) -> bool {
-        true // Tokens always consume!
-    }
-
-    fn is_nullable(&self) -> bool {
-        match self {
-            Token::Empty => true,
-            Token::EOF => false,
-            Token::Char(ccl) | Token::Chars(ccl) => ccl.len() == 0, //True shouldn't be possible here by definition!
-            Token::Match(s) | Token::Touch(s) => s.len() == 0, //True shouldn't be possible here by definition!
-        }
-    }
-
-    fn call(
-        &self,
-        context: Option<&mut Context>,
-        args: Vec<RefValue>,
-        nargs: Option<Dict>,
-    ) -> Result<Accept, Reject> {
-        assert!(context.is_some() && args.len() == 0 && nargs.is_none());
-
-        let context = context.unwrap();
-        let reader = &mut context.thread.reader;
 
+    /// Reads the token from the reader.
+    fn read(&self, reader: &mut Reader) -> Result<Accept, Reject> {
         match self {
             Token::Empty => Ok(Accept::Next),
             Token::EOF => {
@@ -189,6 +235,143 @@ impl Object for Token {
                     Err(Reject::Next)
                 }
             }
+            Token::MatchNoCase(string) | Token::TouchNoCase(string) => {
+                let start = reader.tell();
+
+                // Compare case-folded input against the case-folded string
+                let string: Vec<char> = string.chars().default_case_fold().collect();
+                let mut pos = 0;
+
+                while pos < string.len() {
+                    let folded: Vec<char> = match reader.peek() {
+                        Some(ch) => std::iter::once(*ch).default_case_fold().collect(),
+                        None => break,
+                    };
+
+                    if !string[pos..].starts_with(&folded) {
+                        break;
+                    }
+
+                    pos += folded.len();
+                    reader.next();
+                }
+
+                if pos == string.len() {
+                    Ok(Accept::Push(Capture::Range(
+                        reader.capture_from(&start),
+                        None,
+                        if matches!(self, Token::TouchNoCase(_)) {
+                            0
+                        } else {
+                            5
+                        },
+                    )))
+                } else {
+                    reader.reset(start);
+                    Err(Reject::Next)
+                }
+            }
+            Token::Regex(regex) => {
+                let start = reader.tell();
+
+                // Match within the current line, starting at the current position
+                let (line, pos) = reader.peek_line();
+
+                let input = Input::new(line)
+                    .span(pos..line.len())
+                    .anchored(Anchored::Yes);
+
+                let mut captures = regex.regex.create_captures();
+                regex.regex.search_captures(&input, &mut captures);
+
+                let (len, groups) = match captures.get_match() {
+                    Some(found) => (
+                        found.len(),
+                        // With groups, a list of the match and its groups is the result
+                        if captures.group_len() > 1 {
+                            Some(
+                                captures
+                                    .iter()
+                                    .map(|group| match group {
+                                        Some(group) => RefValue::from(&line[group.range()]),
+                                        None => crate::value!(void),
+                                    })
+                                    .collect::<Vec<RefValue>>(),
+                            )
+                        } else {
+                            None
+                        },
+                    ),
+                    None => return Err(Reject::Next),
+                };
+
+                while reader.tell().offset < start.offset + len {
+                    reader.next();
+                }
+
+                Ok(Accept::Push(match groups {
+                    Some(groups) => Capture::Value(RefValue::from(groups), None, 5),
+                    None => Capture::Range(reader.capture_from(&start), None, 5),
+                }))
+            }
+        }
+    }
+}
+
+impl Object for Token {
+    fn name(&self) -> &'static str {
+        "token"
+    }
+
+    fn repr(&self) -> String {
+        match self {
+            Token::Empty => "Empty".to_string(),
+            Token::EOF => "EOF".to_string(),
+            Token::Char(ccl) => format!("{:?}", ccl),
+            Token::Chars(ccl) => format!("{:?}+", ccl),
+            Token::Touch(s) => format!("'{}'", s),
+            Token::Match(s) => format!("''{}''", s),
+            Token::TouchNoCase(s) => format!("'{}'i", s),
+            Token::MatchNoCase(s) => format!("''{}''i", s),
+            Token::Regex(regex) => format!("/{}/", regex.as_str()),
+        }
+    }
+
+    fn is_callable(&self, without_arguments: bool) -> bool {
+        without_arguments // Tokens don't support arguments
+    }
+
+    fn is_consuming(&self) -> bool {
+        true // Tokens always consume!
+    }
+
+    fn is_nullable(&self) -> bool {
+        match self {
+            Token::Empty => true,
+            Token::EOF => false,
+            Token::Char(ccl) | Token::Chars(ccl) => ccl.len() == 0, //True shouldn't be possible here by definition!
+            Token::Match(s) | Token::Touch(s) | Token::MatchNoCase(s) | Token::TouchNoCase(s) => {
+                s.is_empty()
+            } //True shouldn't be possible here by definition!
+            Token::Regex(regex) => regex.regex.is_match(""),
+        }
+    }
+
+    fn call(
+        &self,
+        context: Option<&mut Context>,
+        args: Vec<RefValue>,
+        nargs: Option<Dict>,
+    ) -> Result<Accept, Reject> {
+        assert!(context.is_some() && args.len() == 0 && nargs.is_none());
+
+        let reader = &mut context.unwrap().thread.reader;
+        let ret = self.read(reader);
+
+        // Errors reading the input are raised by the token which read it
+        match reader.take_error() {
+            Some(error) => Err(Reject::Error(Box::new(error))),
+            None => ret,
         }
     }
 }
@@ -321,14 +504,17 @@ tokay_token!("Word : @min=1 max=void", {
 In Tokay syntax, a touch is normally defined with static string using single quotation marks `'touch'`.
 It parses exactly the string provided and pushes it with a whitespace severity.
 
-Using the `Touch`-token, a dynamic input string can be provided, so `Touch("touch")` is equal to `'touch'`
+Using the `Touch`-token, a dynamic input string can be provided, so `Touch("touch")` is equal to `'touch'`,
+and `Touch("touch", nocase=true)` is equal to the case-insensitive `'touch'i`.
 */
-tokay_token!("Touch : @s", {
+tokay_token!("Touch : @s nocase=false", {
     let s = s.to_string();
     if s.len() == 0 {
         Err(Reject::Next)
+    } else if nocase.is_true() {
+        Token::TouchNoCase(s).call(Some(context), Vec::new(), None)
     } else {
-        Token::Touch(s.to_string()).call(Some(context), Vec::new(), None)
+        Token::Touch(s).call(Some(context), Vec::new(), None)
     }
 });
 
@@ -337,13 +523,16 @@ tokay_token!("Touch : @s", {
 In Tokay syntax, a match is normally defined with static string using two single quotation marks `''match''`.
 It parses exactly the string provided and pushes it with a token severity.
 
-Using the `Match`-token, a dynamic input string can be provided, so `Match("match")` is equal to `''match''`
+Using the `Match`-token, a dynamic input string can be provided, so `Match("match")` is equal to `''match''`,
+and `Match("match", nocase=true)` is equal to the case-insensitive `''match''i`.
 */
-tokay_token!("Match : @s", {
+tokay_token!("Match : @s nocase=false", {
     let s = s.to_string();
     if s.len() == 0 {
         Err(Reject::Next)
+    } else if nocase.is_true() {
+        Token::MatchNoCase(s).call(Some(context), Vec::new(), None)
     } else {
-        Token::Match(s.to_string()).call(Some(context), Vec::new(), None)
+        Token::Match(s).call(Some(context), Vec::new(), None)
     }
 });
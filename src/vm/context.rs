@@ -1,5 +1,6 @@
 //! Contexts and stack frames for parselet calls.
 use super::*;
+use crate::error::{Error, Location};
 use crate::reader::Offset;
 use crate::value::{Dict, List, Object, Parselet, RefValue, Value};
 use std::iter::FromIterator;
@@ -30,6 +31,78 @@ pub struct Loop {
     pub end: usize,    // End address of loop
 }
 
+/** Representation of a try-frame */
+#[derive(Debug, Clone, Copy)]
+pub struct Try {
+    pub frames: usize,  // Number of frames at try start
+    pub loops: usize,   // Number of loops at try start
+    pub stack: usize,   // Stack size at try start
+    pub reader: Offset, // Reader position at try start
+    pub start: usize,   // Start address of try-block
+    pub catch: usize,   // Start address of catch-block
+}
+
+impl Try {
+    /// Pushes a new try frame at `ip`, with the catch-block at relative address `catch`.
+    #[inline(never)]
+    pub fn enter(tries: &mut Vec<Try>, context: &Context, ip: usize, catch: usize) {
+        // Discard try frames which were left without reaching their end
+        while tries.last().is_some_and(|current| current.start >= ip) {
+            tries.pop();
+        }
+
+        tries.push(Try {
+            frames: context.frames.len(),
+            loops: context.loops.len(),
+            stack: context.stack.len(),
+            reader: context.thread.reader.tell(),
+            start: ip,
+            catch: ip + catch,
+        });
+    }
+
+    /** Catches an error `state` by the innermost try frame the instruction pointer is in.
+
+    Everything done inside the try-block is discarded, and the error is pushed to be received
+    by the catch-block, which address is returned. */
+    #[inline(never)]
+    pub fn catch(
+        tries: &mut Vec<Try>,
+        context: &mut Context,
+        ip: usize,
+        state: &mut Result<Accept, Reject>,
+    ) -> Option<usize> {
+        while tries
+            .last()
+            .is_some_and(|current| !(current.start..current.catch).contains(&ip))
+        {
+            tries.pop();
+        }
+
+        let current = tries.pop()?;
+
+        let Err(Reject::Error(mut error)) = std::mem::replace(state, Ok(Accept::Next)) else {
+            unreachable!();
+        };
+
+        context.locate(&mut error);
+
+        while context.frames.len() > current.frames {
+            context.frame = context.frames.pop().unwrap();
+        }
+
+        context.loops.truncate(current.loops);
+        context.stack.truncate(current.stack);
+        context.thread.reader.reset(current.reader);
+
+        context
+            .stack
+            .push(Capture::Value(RefValue::from(*error), None, 10));
+
+        Some(current.catch)
+    }
+}
+
 /** Contexts represent stack frames for parselet calls.
 
 Within the context, most operations regarding capture storing and loading is performed. */
@@ -104,6 +177,23 @@ impl<'program, 'reader, 'thread, 'parselet> Context<'program, 'reader, 'thread,
         );
     }
 
+    /** Closes the frame of an inlined parselet, pushing its result with the given severity.
+
+    Like a parselet call, the last capture is the result. */
+    #[inline(never)]
+    pub(super) fn close_inlined(&mut self, severity: u8) -> Result<Accept, Reject> {
+        let capture = if self.stack.len() > self.frame.capture_start {
+            self.stack.pop().unwrap()
+        } else {
+            Capture::Empty
+        };
+
+        self.stack.truncate(self.frame.capture_start);
+        self.frame = self.frames.pop().unwrap();
+
+        Ok(Accept::Push(capture).into_push(severity))
+    }
+
     /// Shortcut for an Ok(Accept::Push) with the given value.
     /// To push a value immediatelly, use context.thread.stack.push().
     #[inline]
@@ -131,7 +221,7 @@ impl<'program, 'reader, 'thread, 'parselet> Context<'program, 'reader, 'thread,
     #[inline]
     pub fn load(&mut self, index: usize) -> Result<Accept, Reject> {
         let capture = &mut self.stack[index];
-        let value = capture.extract(&self.thread.reader);
+        let value = capture.extract(self.thread.reader);
         self.push(value)
     }
 
@@ -147,6 +237,24 @@ impl<'program, 'reader, 'thread, 'parselet> Context<'program, 'reader, 'thread,
     }
 
     /// Return top-level frame
+    /** Locates an error in the parselet's source and in the input, if not already done.
+
+    Parselets without a source, like those of the prelude, leave the error to be located where
+    they were called from. */
+    fn locate(&self, error: &mut Error) {
+        if let Some(source) = &self.parselet.source
+            && let Some(source_offset) = self.source_offset
+        {
+            error.patch_offset(source_offset);
+            error.locate(source);
+        }
+
+        if error.input.is_none() {
+            let reader = &self.thread.reader;
+            error.input = Some(Box::new(Location::from_reader(reader, reader.tell())));
+        }
+    }
+
     pub fn frame0(&self) -> &Frame {
         if self.frames.is_empty() {
             &self.frame
@@ -420,7 +528,7 @@ impl<'program, 'reader, 'thread, 'parselet> Context<'program, 'reader, 'thread,
 
         captures
             .into_iter()
-            .map(|mut capture| capture.extract(&self.thread.reader))
+            .map(|mut capture| capture.extract(self.thread.reader))
             .collect()
     }
 
@@ -449,9 +557,10 @@ impl<'program, 'reader, 'thread, 'parselet> Context<'program, 'reader, 'thread,
 
             // Patch context source position on error, if no other position already set
             Err(Reject::Error(ref mut err)) => {
-                if let Some(source_offset) = self.source_offset {
-                    err.patch_offset(source_offset);
-                }
+                self.locate(err);
+
+                err.backtrace
+                    .push((self.parselet.name.clone(), self.source_offset));
             }
 
             _ => {}
@@ -534,6 +643,31 @@ impl<'program, 'reader, 'thread, 'parselet> Context<'program, 'reader, 'thread,
         Ok(ret)
     }
 
+    /** Executes begin- or end-operations `ops` of a main parselet.
+
+    A result is handed to the thread; `Some` is returned when the main parselet must be left. */
+    fn execute_main(
+        &mut self,
+        name: &str,
+        ops: &[Op],
+        results: &mut List,
+    ) -> Option<Result<Accept, Reject>> {
+        match self.execute(name, ops) {
+            Ok(Accept::Next) | Err(Reject::Skip) | Ok(Accept::Push(Capture::Empty)) => None,
+            Ok(Accept::Push(mut capture)) => {
+                let res = capture.extract(self.thread.reader);
+                if !res.is_void() {
+                    self.thread.push_result(results, res);
+                }
+
+                None
+            }
+            Ok(Accept::Repeat) => None,
+            Ok(accept) => Some(Ok(accept.into_push(self.parselet.severity))),
+            other => Some(other),
+        }
+    }
+
     /** Run the current context as a main parselet.
 
     __main__-parselets are executed differently, as they handle unrecognized input as whitespace or gap,
@@ -544,34 +678,44 @@ impl<'program, 'reader, 'thread, 'parselet> Context<'program, 'reader, 'thread,
         // collected results
         let mut results = List::new();
 
+        // Input which was processed can be discarded, when it isn't required anymore
+        let discard = !self.parselet.requires_input(self.thread.program);
+
         // Begin
-        match self.execute("main begin", &self.parselet.begin) {
-            Ok(Accept::Next) | Err(Reject::Skip) | Ok(Accept::Push(Capture::Empty)) => {}
-            Ok(Accept::Push(mut capture)) => {
-                let res = capture.extract(&self.thread.reader);
-                if !res.is_void() {
-                    results.push(res);
-                }
-            }
-            Ok(Accept::Repeat) => {}
-            Ok(accept) => return Ok(accept.into_push(self.parselet.severity)),
-            other => return other,
-        };
+        if let Some(ret) = self.execute_main("main begin", &self.parselet.begin, &mut results) {
+            return ret;
+        }
 
         loop {
             self.reset(Some(self.thread.reader.tell()));
 
+            // Begin of input file
+            if let Some(ret) =
+                self.execute_main("main beginfile", &self.parselet.begin_file, &mut results)
+            {
+                return ret;
+            }
+
+            self.reset(Some(self.thread.reader.tell()));
+
             // Body
-            loop {
-                match self.execute("main body", &self.parselet.body) {
+            while !self.thread.skip_input {
+                let ret = self.execute("main body", &self.parselet.body);
+
+                // Stop on errors which occured while reading the input outside of tokens
+                if let Some(error) = self.thread.reader.take_error() {
+                    return Err(Reject::Error(Box::new(error)));
+                }
+
+                match ret {
                     Err(Reject::Next)
                     | Err(Reject::Skip)
                     | Ok(Accept::Next)
                     | Ok(Accept::Push(Capture::Empty)) => {}
                     Ok(Accept::Push(mut capture)) => {
-                        let res = capture.extract(&self.thread.reader);
+                        let res = capture.extract(self.thread.reader);
                         if !res.is_void() {
-                            results.push(res);
+                            self.thread.push_result(&mut results, res);
                         }
                     }
                     Ok(Accept::Repeat) => {}
@@ -579,23 +723,44 @@ impl<'program, 'reader, 'thread, 'parselet> Context<'program, 'reader, 'thread,
                     other => return other,
                 }
 
+                // Skip one character if nothing was consumed
                 if self.frame.reader_start == self.thread.reader.tell() {
-                    // Skip one character if nothing was consumed
                     self.thread.reader.next();
-
-                    // Drop all memoizations
-                    self.thread.memo.clear();
                 }
 
                 // Reset capture stack for loop repeat
                 self.reset(Some(self.thread.reader.tell()));
 
+                // Evict memoizations below the next match, nothing can backtrack there
+                self.thread.memo.evict(self.frame0().reader_start.offset);
+
+                // Discard processed input
+                if discard {
+                    self.thread.reader.commit();
+                }
+
                 // Break on EOF
                 if self.thread.reader.eof() {
                     break;
                 }
             }
 
+            self.thread.skip_input = false;
+
+            // Report errors which occured while reading the input
+            if let Some(error) = self.thread.reader.take_error() {
+                return Err(Reject::Error(Box::new(error)));
+            }
+
+            self.reset(Some(self.thread.reader.tell()));
+
+            // End of input file
+            if let Some(ret) =
+                self.execute_main("main endfile", &self.parselet.end_file, &mut results)
+            {
+                return ret;
+            }
+
             if self.thread.readers.is_empty() {
                 break;
             }
@@ -608,18 +773,9 @@ impl<'program, 'reader, 'thread, 'parselet> Context<'program, 'reader, 'thread,
         }
 
         // End
-        match self.execute("main end", &self.parselet.end) {
-            Ok(Accept::Next) | Err(Reject::Skip) | Ok(Accept::Push(Capture::Empty)) => {}
-            Ok(Accept::Push(mut capture)) => {
-                let res = capture.extract(&self.thread.reader);
-                if !res.is_void() {
-                    results.push(res);
-                }
-            }
-            Ok(Accept::Repeat) => {}
-            Ok(accept) => return Ok(accept.into_push(self.parselet.severity)),
-            other => return other,
-        };
+        if let Some(ret) = self.execute_main("main end", &self.parselet.end, &mut results) {
+            return ret;
+        }
 
         // results has higher priority than ret
         if !results.is_empty() {
@@ -0,0 +1,500 @@
+//! Step debugger for the Tokay VM.
+use super::*;
+use crate::reader::{Offset, Source};
+use crate::value::Parselet;
+use crate::{Error, Object, RefValue};
+use std::collections::BTreeSet;
+use std::io::{BufRead, Write};
+use std::path::Path;
+
+/// Breakpoint at a row of the source with the given filename.
+pub type Breakpoint = (Option<String>, u32);
+
+/// Stepping mode of the debugger
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Step {
+    Run,              // Run until a breakpoint is reached
+    Pause,            // Stop at the next source position
+    In,               // Stop at the next source line, including called parselets
+    Over(u32, usize), // Stop at the next source line at the given row and depth or above
+    Out(usize),       // Stop when the parselet at the given depth was left
+}
+
+/// Reason why the debugger stopped
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Reason {
+    Breakpoint,
+    Step,
+    Pause,
+}
+
+/** Debugger state, deciding where the VM stops.
+
+The debugger is hooked into a thread and checked on `Op::Offset` operations, which mark the
+source positions of the compiled program. It keeps track of the parselet call stack, as a VM
+context only knows about its own parselet.
+
+Breakpoints refer to the source of the parselet being executed, so that the program and its
+modules can be debugged. A breakpoint's filename also matches the end of a source's path, so
+that `module.tok` matches a module imported from `/path/to/module.tok`.
+*/
+#[derive(Debug)]
+pub struct Debugger {
+    pub breakpoints: BTreeSet<Breakpoint>, // Breakpoints by source filename and row
+    pub step: Step,                        // Current stepping mode
+    pub calls: Vec<(usize, String, Offset)>, // Parselet call stack by depth, name and position
+    last: Option<(u32, usize)>,            // Last visited row and depth
+}
+
+impl Debugger {
+    /// Creates a new debugger, optionally stopping at the first source line.
+    pub fn new(stop: bool) -> Self {
+        Self {
+            breakpoints: BTreeSet::new(),
+            step: if stop { Step::In } else { Step::Run },
+            calls: Vec::new(),
+            last: None,
+        }
+    }
+
+    /** Parses a breakpoint from `[FILE:]LINE`.
+
+    Without a filename, the breakpoint refers to the source with the given `filename`. */
+    pub fn parse_breakpoint(arg: &str, filename: Option<&str>) -> Result<Breakpoint, String> {
+        let (filename, row) = match arg.rsplit_once(':') {
+            Some((filename, row)) => (Some(filename), row),
+            None => (filename, arg),
+        };
+
+        match row.parse::<u32>() {
+            Ok(row) if row > 0 => Ok((filename.map(String::from), row)),
+            _ => Err(format!("Expecting [FILE:]LINE, got '{}'", arg)),
+        }
+    }
+
+    /// Check for a breakpoint at the row of a source.
+    pub fn is_breakpoint(&self, source: &Source, row: u32) -> bool {
+        self.breakpoints.iter().any(|(filename, at)| {
+            *at == row
+                && match (filename, &source.filename) {
+                    (None, None) => true,
+                    (Some(filename), Some(path)) => Path::new(path).ends_with(filename),
+                    _ => false,
+                }
+        })
+    }
+
+    /// Track the current position and check whether to stop there.
+    pub fn check(&mut self, context: &Context, offset: &Offset) -> Option<Reason> {
+        let depth = context.depth;
+        let position = (offset.row, depth);
+
+        while self.calls.last().is_some_and(|(call, ..)| *call >= depth) {
+            self.calls.pop();
+        }
+
+        self.calls
+            .push((depth, context.parselet.name.clone(), *offset));
+
+        let moved = self.last != Some(position);
+        self.last = Some(position);
+
+        let breakpoint = context
+            .parselet
+            .source
+            .as_ref()
+            .is_some_and(|source| self.is_breakpoint(source, offset.row));
+
+        let reason = if moved && breakpoint {
+            Reason::Breakpoint
+        } else {
+            match self.step {
+                Step::Pause => Reason::Pause,
+                Step::In if moved => Reason::Step,
+                Step::Over(row, over) if depth < over || (depth == over && offset.row != row) => {
+                    Reason::Step
+                }
+                Step::Out(out) if depth < out => Reason::Step,
+                _ => return None,
+            }
+        };
+
+        self.step = Step::Run;
+        Some(reason)
+    }
+}
+
+/// A source position where the VM stopped
+pub struct Position<'ops> {
+    pub offset: Offset, // Source position
+    pub reason: Reason, // Reason for stopping
+    ops: &'ops [Op],    // Block currently executed
+    ip: usize,          // Instruction pointer within ops
+}
+
+impl Position<'_> {
+    /// Dump the VM code of the current block, marking the instruction pointer.
+    pub fn code(&self) -> Vec<String> {
+        self.ops
+            .iter()
+            .enumerate()
+            .map(|(i, op)| format!("{}{:03} {:?}", if i == self.ip { ">" } else { " " }, i, op))
+            .collect()
+    }
+}
+
+/** Frontend of a debugger, interacting with the user when the VM stopped. */
+pub trait DebugFrontend {
+    /// Access the debugger state
+    fn debugger(&mut self) -> &mut Debugger;
+
+    /// Process pending requests while the program is running.
+    fn poll(&mut self) -> Result<(), Reject> {
+        Ok(())
+    }
+
+    /// Interact at a stopped position, until execution continues.
+    fn stop(&mut self, context: &mut Context, position: Position) -> Result<(), Reject>;
+}
+
+impl dyn DebugFrontend {
+    /// Hook called by the VM on every source position, giving control to the thread's debugger.
+    #[inline(never)]
+    pub(in crate::vm) fn hook(
+        context: &mut Context,
+        ops: &[Op],
+        ip: usize,
+        offset: &Offset,
+    ) -> Result<Accept, Reject> {
+        let mut debugger = context.thread.debugger.take().unwrap();
+        let ret = debugger.handle(context, ops, ip, offset);
+        context.thread.debugger = Some(debugger);
+        ret.and(Ok(Accept::Next))
+    }
+
+    // Checks whether to stop at a source position, and lets the frontend handle it.
+    fn handle(
+        &mut self,
+        context: &mut Context,
+        ops: &[Op],
+        ip: usize,
+        offset: &Offset,
+    ) -> Result<(), Reject> {
+        self.poll()?;
+
+        if let Some(reason) = self.debugger().check(context, offset) {
+            self.stop(
+                context,
+                Position {
+                    offset: *offset,
+                    reason,
+                    ops,
+                    ip,
+                },
+            )
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Format a capture for display.
+fn format_capture(context: &Context, capture: &Capture) -> String {
+    match capture {
+        Capture::Empty => "(empty)".to_string(),
+        Capture::Range(range, ..) => format!("{:?}", context.thread.reader.get(range)),
+        Capture::Value(value, ..) => value.repr(),
+    }
+}
+
+/// Named variables of a parselet, with their values.
+fn variables<'a>(
+    parselet: &'a Parselet,
+    values: impl Iterator<Item = RefValue> + 'a,
+) -> impl Iterator<Item = (String, RefValue)> + 'a {
+    values.enumerate().filter_map(|(i, value)| {
+        // Parselets loaded without variable names show all variables by address
+        if parselet.variables.is_empty() {
+            Some((format!("#{}", i), value))
+        } else {
+            let name = parselet.variables.get(i)?;
+            (!name.is_empty()).then(|| (name.clone(), value))
+        }
+    })
+}
+
+/// Local variables of the current parselet.
+pub fn locals(context: &Context) -> Vec<(String, RefValue)> {
+    let values = context.stack[..context.parselet.locals.min(context.stack.len())]
+        .iter()
+        .map(|capture| capture.clone().extract(context.thread.reader));
+
+    variables(context.parselet, values).collect()
+}
+
+/// Global variables, named by the main parselet.
+pub fn globals(context: &Context) -> Vec<(String, RefValue)> {
+    let main = context.thread.program.main();
+    let main = main.0.borrow();
+
+    variables(&main, context.thread.globals.iter().cloned()).collect()
+}
+
+/// Captures `$1..$n` of the current parselet, named by their alias if any.
+pub fn captures(context: &Context) -> Vec<(String, RefValue)> {
+    let start = context.frame0().capture_start.min(context.stack.len());
+
+    context.stack[start..]
+        .iter()
+        .enumerate()
+        .map(|(i, capture)| {
+            let name = match capture {
+                Capture::Range(_, Some(alias), _) | Capture::Value(_, Some(alias), _) => {
+                    alias.to_string()
+                }
+                _ => format!("${}", i + 1),
+            };
+
+            (name, capture.clone().extract(context.thread.reader))
+        })
+        .collect()
+}
+
+/// Describes a source position by its filename and line.
+fn describe((filename, row): &Breakpoint) -> String {
+    match filename {
+        Some(filename) => format!("'{}', line {}", filename, row),
+        None => format!("line {}", row),
+    }
+}
+
+/** Command-line debugger frontend.
+
+When stopped, commands are read from `input` and any output is written to `output`.
+*/
+pub struct DebugConsole {
+    pub debugger: Debugger,  // Debugger state
+    input: Box<dyn BufRead>, // Command input
+    output: Box<dyn Write>,  // Debugger output
+}
+
+impl DebugConsole {
+    /// Creates a new debug console reading commands from input and writing to output.
+    pub fn new(debugger: Debugger, input: Box<dyn BufRead>, output: Box<dyn Write>) -> Self {
+        Self {
+            debugger,
+            input,
+            output,
+        }
+    }
+
+    /// Process commands until execution continues.
+    fn prompt(
+        &mut self,
+        context: &mut Context,
+        position: &Position,
+    ) -> std::io::Result<Result<(), Reject>> {
+        let offset = &position.offset;
+        self.show_position(context, offset)?;
+
+        loop {
+            write!(self.output, "(tokay) ")?;
+            self.output.flush()?;
+
+            let mut line = String::new();
+            if self.input.read_line(&mut line)? == 0 {
+                // Continue without debugger when input is closed
+                self.debugger.breakpoints.clear();
+                return Ok(Ok(()));
+            }
+
+            let mut cmd = line.split_whitespace();
+
+            match cmd.next().unwrap_or("") {
+                "" => continue,
+                "c" | "continue" => return Ok(Ok(())),
+                "s" | "step" => {
+                    self.debugger.step = Step::In;
+                    return Ok(Ok(()));
+                }
+                "n" | "next" => {
+                    self.debugger.step = Step::Over(offset.row, context.depth);
+                    return Ok(Ok(()));
+                }
+                "f" | "finish" => {
+                    self.debugger.step = Step::Out(context.depth);
+                    return Ok(Ok(()));
+                }
+                "b" | "break" => match cmd.next().map(|arg| self.breakpoint(context, arg)) {
+                    Some(Ok(breakpoint)) => {
+                        writeln!(self.output, "Breakpoint at {}", describe(&breakpoint))?;
+                        self.debugger.breakpoints.insert(breakpoint);
+                    }
+                    Some(Err(err)) => writeln!(self.output, "{}", err)?,
+                    None => {
+                        for breakpoint in &self.debugger.breakpoints {
+                            writeln!(self.output, "Breakpoint at {}", describe(breakpoint))?;
+                        }
+                    }
+                },
+                "d" | "delete" => match cmd.next().map(|arg| self.breakpoint(context, arg)) {
+                    Some(Ok(breakpoint)) => {
+                        if !self.debugger.breakpoints.remove(&breakpoint) {
+                            writeln!(self.output, "No breakpoint at {}", describe(&breakpoint))?;
+                        }
+                    }
+                    Some(Err(err)) => writeln!(self.output, "{}", err)?,
+                    None => self.debugger.breakpoints.clear(),
+                },
+                "l" | "list" => self.show_position(context, offset)?,
+                "bt" | "backtrace" => {
+                    for (depth, name, offset) in self.debugger.calls.iter().rev() {
+                        writeln!(
+                            self.output,
+                            " {:03} {} at line {}, column {}",
+                            depth, name, offset.row, offset.col
+                        )?;
+                    }
+                }
+                "code" => {
+                    for line in position.code() {
+                        writeln!(self.output, "{}", line)?;
+                    }
+                }
+                "stack" => {
+                    for (i, capture) in context.stack.iter().enumerate() {
+                        writeln!(
+                            self.output,
+                            " {:03} {}",
+                            i,
+                            format_capture(context, capture)
+                        )?;
+                    }
+                }
+                "frames" => {
+                    for (i, frame) in context.frames.iter().enumerate() {
+                        writeln!(self.output, " {:03} {}", i, frame)?;
+                    }
+
+                    writeln!(
+                        self.output,
+                        " {:03} {}",
+                        context.frames.len(),
+                        context.frame
+                    )?;
+                }
+                "locals" => {
+                    for (name, value) in locals(context) {
+                        writeln!(self.output, " {} = {}", name, value.repr())?;
+                    }
+                }
+                "captures" => {
+                    for (name, value) in captures(context) {
+                        writeln!(self.output, " {} = {}", name, value.repr())?;
+                    }
+                }
+                "globals" => {
+                    for (name, value) in globals(context) {
+                        writeln!(self.output, " {} = {}", name, value.repr())?;
+                    }
+                }
+                "reader" => {
+                    let reader = context.thread.reader.tell();
+                    writeln!(
+                        self.output,
+                        "Input line {}, column {} (offset {}){}",
+                        reader.row,
+                        reader.col,
+                        reader.offset,
+                        if context.thread.reader.eof {
+                            ", end-of-file"
+                        } else {
+                            ""
+                        }
+                    )?;
+                }
+                "q" | "quit" => {
+                    return Ok(Err(Reject::from(Error::new(
+                        Some(*offset),
+                        "Program aborted by debugger".to_string(),
+                    ))));
+                }
+                "h" | "help" => {
+                    writeln!(
+                        self.output,
+                        concat!(
+                            "s, step          Step to next line, entering parselets\n",
+                            "n, next          Step to next line, stepping over parselets\n",
+                            "f, finish        Run until current parselet returns\n",
+                            "c, continue      Run until next breakpoint\n",
+                            "b, break [[FILE:]LINE]\n",
+                            "                 Set breakpoint at LINE, or list breakpoints\n",
+                            "d, delete [[FILE:]LINE]\n",
+                            "                 Delete breakpoint at LINE, or all breakpoints\n",
+                            "l, list          Show current position\n",
+                            "bt, backtrace    Show parselet call stack\n",
+                            "code             Show VM code of current block\n",
+                            "stack            Show capture stack\n",
+                            "frames           Show capture frames\n",
+                            "locals           Show local variables\n",
+                            "captures         Show captures of current parselet\n",
+                            "globals          Show global variables\n",
+                            "reader           Show input reader position\n",
+                            "q, quit          Abort program"
+                        )
+                    )?;
+                }
+                other => writeln!(
+                    self.output,
+                    "Unknown command '{}', type 'help' for help",
+                    other
+                )?,
+            }
+        }
+    }
+
+    /// Parse a breakpoint, which refers to the current source when no filename is given.
+    fn breakpoint(&self, context: &Context, arg: &str) -> Result<Breakpoint, String> {
+        let source = context.parselet.source.as_ref();
+        Debugger::parse_breakpoint(arg, source.and_then(|source| source.filename.as_deref()))
+    }
+
+    /// Print the current source position.
+    fn show_position(&mut self, context: &Context, offset: &Offset) -> std::io::Result<()> {
+        let source = context.parselet.source.as_ref();
+
+        writeln!(
+            self.output,
+            "{}{} at {}, column {}",
+            ".".repeat(context.depth),
+            context.parselet.name,
+            describe(&(
+                source.and_then(|source| source.filename.clone()),
+                offset.row
+            )),
+            offset.col
+        )?;
+
+        if let Some(line) = source.and_then(|source| source.line(offset.row)) {
+            writeln!(self.output, "{:>4} | {}", offset.row, line)?;
+            writeln!(
+                self.output,
+                "     | {}^",
+                " ".repeat(offset.col as usize - 1)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl DebugFrontend for DebugConsole {
+    fn debugger(&mut self) -> &mut Debugger {
+        &mut self.debugger
+    }
+
+    fn stop(&mut self, context: &mut Context, position: Position) -> Result<(), Reject> {
+        self.prompt(context, &position)
+            .map_err(|err| Reject::from(Error::new(None, format!("Debugger: {}", err))))?
+    }
+}
@@ -0,0 +1,153 @@
+//! Packrat memoization table of a thread
+use super::{Accept, Reject};
+use crate::reader::Offset;
+use crate::value::RefValue;
+use std::collections::{BTreeMap, HashMap};
+
+/// Key of the memoization table: reader offset, parselet id and argument values.
+pub type MemoKey = (usize, usize, Vec<RefValue>);
+
+// Memoized result, with the required flag
+type MemoEntry = ((Offset, Result<Accept, Reject>), bool);
+
+/// Statistics about the usage of the memoization table.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoStats {
+    pub hits: usize,      // Number of results served from the table
+    pub misses: usize,    // Number of lookups without a result
+    pub entries: usize,   // Number of entries currently held
+    pub peak: usize,      // Maximum number of entries held at once
+    pub evictions: usize, // Number of entries evicted
+}
+
+impl std::fmt::Display for MemoStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses, {} entries ({} at peak), {} evictions",
+            self.hits, self.misses, self.entries, self.peak, self.evictions
+        )
+    }
+}
+
+/** Memoization table holding the results of consuming parselets.
+
+Entries are held per reader offset. After every match of the main parselet, entries below the
+offset where its next match starts are evicted, as no parselet can backtrack there anymore. Within
+a single match, any parselet may still backtrack to its start, so entries are only evicted by
+an optional limit of the number of entries; When the limit is reached, the entries with the
+lowest offsets are evicted first, as they are least likely to be used again. Entries required to
+resolve left-recursions are never evicted this way.
+*/
+#[derive(Debug, Default)]
+pub struct Memo {
+    table: BTreeMap<usize, HashMap<MemoKey, MemoEntry>>,
+    len: usize,               // Number of entries
+    pub limit: Option<usize>, // Maximum number of entries
+    stats: MemoStats,         // Usage statistics
+}
+
+impl Memo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Look up a memoized result.
+    pub fn get(&mut self, key: &MemoKey) -> Option<&(Offset, Result<Accept, Reject>)> {
+        match self.table.get(&key.0).and_then(|entries| entries.get(key)) {
+            Some((entry, _)) => {
+                self.stats.hits += 1;
+                Some(entry)
+            }
+            None => {
+                self.stats.misses += 1;
+                None
+            }
+        }
+    }
+
+    /** Memoize a result.
+
+    When the table is full, entries with lower offsets are evicted to make room. The result is
+    dropped when this isn't possible, unless it is `required`. */
+    pub fn insert(
+        &mut self,
+        key: MemoKey,
+        value: (Offset, Result<Accept, Reject>),
+        required: bool,
+    ) {
+        let exists = self
+            .table
+            .get(&key.0)
+            .is_some_and(|entries| entries.contains_key(&key));
+
+        if !exists
+            && !required
+            && self.limit.is_some_and(|limit| self.len >= limit)
+            && !self.evict_lowest(key.0)
+        {
+            return;
+        }
+
+        if self
+            .table
+            .entry(key.0)
+            .or_default()
+            .insert(key, (value, required))
+            .is_none()
+        {
+            self.len += 1;
+            self.stats.peak = self.stats.peak.max(self.len);
+        }
+    }
+
+    // Evict the entry with the lowest offset below `offset` which isn't required.
+    fn evict_lowest(&mut self, offset: usize) -> bool {
+        let Some(key) = self.table.range(..offset).find_map(|(_, entries)| {
+            entries
+                .iter()
+                .find_map(|(key, (_, required))| (!required).then(|| key.clone()))
+        }) else {
+            return false;
+        };
+
+        if self
+            .table
+            .get_mut(&key.0)
+            .is_some_and(|entries| entries.remove(&key).is_some() && entries.is_empty())
+        {
+            self.table.remove(&key.0);
+        }
+
+        self.len -= 1;
+        self.stats.evictions += 1;
+        true
+    }
+
+    /// Evict all entries below the given offset, where the main parselet's next match starts.
+    pub fn evict(&mut self, offset: usize) {
+        let keep = self.table.split_off(&offset);
+        let evicted: usize = std::mem::replace(&mut self.table, keep)
+            .values()
+            .map(|entries| entries.len())
+            .sum();
+
+        self.len -= evicted;
+        self.stats.evictions += evicted;
+    }
+
+    /// Drop all entries.
+    pub fn clear(&mut self) {
+        self.stats.evictions += self.len;
+        self.table.clear();
+        self.len = 0;
+    }
+
+    /// Return usage statistics.
+    pub fn stats(&self) -> MemoStats {
+        MemoStats {
+            entries: self.len,
+            ..self.stats
+        }
+    }
+}
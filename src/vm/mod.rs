@@ -2,6 +2,8 @@
 mod accept;
 mod capture;
 mod context;
+mod debugger;
+mod memo;
 mod op;
 mod program;
 mod reject;
@@ -10,6 +12,8 @@ mod thread;
 pub use accept::*;
 pub use capture::*;
 pub use context::*;
+pub use debugger::*;
+pub use memo::*;
 pub(crate) use op::*;
 pub use program::*;
 pub use reject::*;
@@ -1,4 +1,5 @@
 use super::*;
+use crate::error::{Error, ErrorKind};
 use crate::reader::Offset;
 use crate::value;
 use crate::value::{Dict, List, Object, RefValue, Str, Value};
@@ -11,6 +12,7 @@ use std::rc::Rc;
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum UnaryOp {
+    BitNot,
     Dec,
     Inc,
     Neg,
@@ -20,6 +22,7 @@ pub(crate) enum UnaryOp {
 impl UnaryOp {
     pub fn to_str(&self) -> &'static str {
         match self {
+            Self::BitNot => "bitnot",
             Self::Dec => "idec",
             Self::Inc => "iinc",
             Self::Neg => "neg",
@@ -34,15 +37,25 @@ impl UnaryOp {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum BinaryOp {
     Add,
+    BitAnd,
+    BitOr,
+    BitXor,
     Div,
     Eq,
     Gt,
     GtEq,
+    In,
     InlineAdd,
+    InlineBitAnd,
+    InlineBitOr,
+    InlineBitXor,
     InlineDiv,
     InlineIntDiv,
     InlineMod,
     InlineMul,
+    InlinePow,
+    InlineShl,
+    InlineShr,
     InlineSub,
     IntDiv,
     Lt,
@@ -50,6 +63,10 @@ pub(crate) enum BinaryOp {
     Mod,
     Mul,
     Neq,
+    NotIn,
+    Pow,
+    Shl,
+    Shr,
     Sub,
 }
 
@@ -57,15 +74,25 @@ impl BinaryOp {
     pub fn to_str(&self) -> &'static str {
         match self {
             Self::Add => "add",
+            Self::BitAnd => "bitand",
+            Self::BitOr => "bitor",
+            Self::BitXor => "bitxor",
             Self::Div => "div",
             Self::Eq => "eq",
             Self::Gt => "gt",
             Self::GtEq => "gteq",
+            Self::In => "in",
             Self::InlineAdd => "iadd",
+            Self::InlineBitAnd => "ibitand",
+            Self::InlineBitOr => "ibitor",
+            Self::InlineBitXor => "ibitxor",
             Self::InlineDiv => "idiv",
             Self::InlineIntDiv => "idivi",
             Self::InlineMod => "imod",
             Self::InlineMul => "imul",
+            Self::InlinePow => "ipow",
+            Self::InlineShl => "ishl",
+            Self::InlineShr => "ishr",
             Self::InlineSub => "isub",
             Self::IntDiv => "divi",
             Self::Lt => "lt",
@@ -73,6 +100,10 @@ impl BinaryOp {
             Self::Mod => "mod",
             Self::Mul => "mul",
             Self::Neq => "neq",
+            Self::NotIn => "notin",
+            Self::Pow => "pow",
+            Self::Shl => "shl",
+            Self::Shr => "shr",
             Self::Sub => "sub",
         }
     }
@@ -102,6 +133,7 @@ pub(crate) enum Op {
     Collect,      // Collect stack values from current frame
     // InCollect,    // Same as collect, but degrate the parselet level (5) (fixme: This is temporary!)
     Fuse(usize), // Set frame fuse to relative forward address
+    Inlined(u8), // Close frame of an inlined parselet, pushing its result with severity
 
     // Loop frames
     Loop(usize), // Loop frame
@@ -109,6 +141,10 @@ pub(crate) enum Op {
     LoadBreak,   // Ok(Accept::Break) with value
     Continue,    // Ok(Accept::Continue)
 
+    // Try frames
+    Try(usize),   // Try frame with relative forward address of the catch-block
+    Catch(usize), // Close try frame and jump forward behind the catch-block
+
     // Conditional jumps
     ForwardIfTrue(usize),     // Jump forward when TOS is true
     ForwardIfFalse(usize),    // Jump forward when TOS is false
@@ -154,7 +190,7 @@ pub(crate) enum Op {
     LoadFastCapture(usize),      // Load capture by known index
     LoadCapture,                 // Load capture by evaluated index
     LoadItem { upsert: bool },   // Load item
-    LoadAttr,                    // Load attr
+    LoadAttr,                    // Load attribute
     StoreGlobal(usize),          // Store global variable
     StoreGlobalHold(usize),      // Store global variable and keep tos
     StoreFast(usize),            // Store local variable
@@ -165,6 +201,8 @@ pub(crate) enum Op {
     StoreCaptureHold,            // Store capture with evaluated index and keep tos
     StoreItem,                   // Store item
     StoreItemHold,               // Store item and push item reference to tos
+    StoreAttr,                   // Store attribute
+    StoreAttrHold,               // Store attribute and push attribute reference to tos
 
     MakeAlias,       // Make key-value-Capture from last two stack items
     MakeList(usize), // Make a List from specified amount of items on stack
@@ -201,272 +239,28 @@ impl Op {
 
         let mut ip = 0; // Instruction pointer
         let mut state = Ok(Accept::Next);
+        let mut tries: Vec<Try> = Vec::new(); // Try frames
 
         while ip < ops.len() {
             let op = &ops[ip];
 
             // Debug
-            if context.debug == 3 {
-                context.log(&format!("{:03}:{:?}", ip, op));
-            } else if context.debug > 3 {
-                if context.debug > 5 {
-                    // Skip any Nop-Operations
-                    if matches!(op, Op::Nop | Op::Offset(_)) {
-                        ip += 1;
-                        continue;
-                    }
-                }
-
-                // Dump entire code
-                context.log("--- Code ---");
-
-                fn dump(ops: &[Op], context: &Context, ip: usize) {
-                    for (i, op) in ops.iter().enumerate() {
-                        context.log(&format!(
-                            "{}{:03} {:?}",
-                            if i == ip { ">" } else { " " },
-                            i,
-                            op
-                        ));
-                    }
-                }
-
-                dump(ops, context, ip);
-
-                // Dump stack and frames
-                if context.debug > 4 {
-                    context.log("--- Reader ---");
-                    context.log(&format!(" offset={:?}", context.thread.reader.tell()));
-                    context.log(&format!(" eof={:?}", context.thread.reader.eof));
-
-                    context.log("--- Globals ---");
-                    for i in 0..context.thread.globals.len() {
-                        context.log(&format!(" {:03} {:?}", i, context.thread.globals[i]));
-                    }
-
-                    context.log("--- Stack ---");
-                    for i in 0..context.stack.len() {
-                        context.log(&format!(" {:03} {:?}", i, context.stack[i]));
-                    }
-
-                    context.log("--- Frames ---");
-                    for i in 0..context.frames.len() {
-                        context.log(&format!(" {:03} {}", i, context.frames[i]));
-                    }
-
-                    context.log(&format!(" {:03} {}", context.frames.len(), context.frame));
+            if context.debug >= 3 {
+                // Skip any Nop-Operations
+                if context.debug > 5 && matches!(op, Op::Nop | Op::Offset(_)) {
+                    ip += 1;
+                    continue;
                 }
 
-                // Step-by-step
-                if context.debug > 5 {
-                    let _ = io::stdin().read(&mut [0u8]).unwrap();
-                }
+                Self::trace(ops, context, ip);
             }
 
-            // Execute instruction
+            // Execute instruction; calls are performed here, any other instruction by execute()
             state = match op {
-                Op::Nop => Ok(Accept::Next),
-
-                Op::Offset(offset) => {
-                    context.source_offset = Some(**offset);
-                    Ok(Accept::Next)
-                }
-
-                // Frames
-                Op::Frame(fuse) => {
-                    context.frames.push(context.frame);
-                    context.frame = Frame {
-                        fuse: if *fuse > 0 { Some(ip + *fuse) } else { None },
-                        capture_start: context.stack.len(),
-                        reader_start: context.thread.reader.tell(),
-                    };
-
-                    Ok(Accept::Next)
-                }
-
-                /*
-                Op::Capture => {
-                    context.frame.capture_start = context.stack.len();
-                    Ok(Accept::Next)
-                }
-                */
-                Op::Extend => {
-                    context.frame.reader_start = context.thread.reader.tell();
-                    Ok(Accept::Next)
-                }
-
-                Op::Reset => {
-                    context.stack.truncate(context.frame.capture_start);
-                    context.thread.reader.reset(context.frame.reader_start);
-                    Ok(Accept::Next)
-                }
-
-                Op::ResetReader => {
-                    context.thread.reader.reset(context.frame.reader_start);
-                    Ok(Accept::Next)
-                }
-
-                Op::ResetCapture => {
-                    context.stack.truncate(context.frame.capture_start);
-                    Ok(Accept::Next)
-                }
-
-                Op::Close => {
-                    context.frame = context.frames.pop().unwrap();
-                    Ok(Accept::Next)
-                }
-
-                Op::Collect => Ok(Accept::Push(context.collect(
-                    context.frame.capture_start,
-                    false,
-                    true,
-                    context.thread.debug > 5,
-                ))),
-
-                /*
-                Op::InCollect => {
-                    let mut capture =
-                        context.collect(context.frame.capture_start, false, context.debug > 5);
-
-                    if capture.get_severity() > 5 {
-                        capture.set_severity(5);
-                    }
-
-                    Ok(Accept::Push(capture))
-                }
-                */
-                Op::Fuse(addr) => {
-                    context.frame.fuse = Some(ip + *addr);
-                    Ok(Accept::Next)
-                }
-
-                // Loops
-                Op::Loop(size) => {
-                    context.loops.push(Loop {
-                        frames: context.frames.len(),
-                        start: ip + 1,
-                        end: ip + *size,
-                    });
-                    Ok(Accept::Next)
-                }
-
-                Op::Break | Op::LoadBreak => {
-                    let current = context.loops.pop().unwrap();
-
-                    // Save value?
-                    let value = if matches!(op, Op::LoadBreak) {
-                        Some(context.pop())
-                    } else {
-                        None
-                    };
-
-                    // Discard all open frames inside current loop.
-                    while context.frames.len() > current.frames {
-                        context.frame = context.frames.pop().unwrap();
-                    }
-
-                    context.stack.truncate(context.frame.capture_start);
-
-                    // Jump behind loop
-                    ip = current.end;
-
-                    // Break will always leave a value, either defined or empty capture
-                    Ok(if let Some(value) = value {
-                        Accept::Push(Capture::Value(value, None, 10))
-                    } else {
-                        context.stack.push(Capture::Empty);
-                        Accept::Hold
-                    })
-                }
-
-                Op::Continue => {
-                    let current = context
-                        .loops
-                        .last()
-                        .expect("Op::Continue used outside of a loop frame");
-
-                    // Discard all open frames inside current loop.
-                    while context.frames.len() > current.frames {
-                        context.frame = context.frames.pop().unwrap();
-                    }
-
-                    context.stack.truncate(context.frame.capture_start);
-
-                    // Jump to loop start.
-                    ip = current.start;
-
-                    Ok(Accept::Hold)
-                }
-
-                // Conditional jumps
-                Op::ForwardIfTrue(goto) => {
-                    if context.pop().is_true() {
-                        ip += goto;
-                    } else {
-                        ip += 1;
-                    }
-
-                    Ok(Accept::Hold)
-                }
-
-                Op::ForwardIfFalse(goto) => {
-                    if !context.pop().is_true() {
-                        ip += goto;
-                    } else {
-                        ip += 1;
-                    }
-
-                    Ok(Accept::Hold)
-                }
-
-                Op::ForwardIfNotVoid(goto) => {
-                    if !context.pop().is_void() {
-                        ip += goto;
-                    } else {
-                        ip += 1;
-                    }
-
-                    Ok(Accept::Hold)
-                }
-
-                Op::ForwardIfConsumed(goto) => {
-                    if context.frame.reader_start != context.thread.reader.tell() {
-                        ip += goto;
-                        Ok(Accept::Hold)
-                    } else {
-                        Ok(Accept::Next)
-                    }
-                }
-
-                Op::Forward(goto) => {
-                    ip += goto;
-                    Ok(Accept::Hold)
-                }
-
-                /*
-                Op::Backward(goto) => {
-                    ip -= goto;
-                    Ok(Accept::Hold)
-                }
-                */
-                // Interrupts
-                Op::Push => Ok(Accept::Push(Capture::Empty)),
-                Op::LoadPush => {
-                    let value = context.pop();
-                    Ok(Accept::Push(Capture::Value(value, None, 15))) // high severity for override required here
-                }
-                Op::Accept => Ok(Accept::Return(Capture::Empty)),
-                Op::LoadAccept => Ok(Accept::Return(context.stack.pop().unwrap())),
-                Op::Repeat => Ok(Accept::Repeat),
-                Op::Next => Err(Reject::Next),
                 Op::Reject => {
                     state = Err(Reject::Next);
                     break;
                 }
-                Op::LoadExit => {
-                    std::process::exit(context.pop().to_i64()? as i32);
-                }
-                Op::Exit => std::process::exit(0),
 
                 // Calls
                 Op::CallOrCopy => {
@@ -540,323 +334,648 @@ impl Op {
                     }
                 }
 
-                // Variables and values
-                Op::LoadStatic(addr) => {
-                    let value = &context.thread.program.statics[*addr];
-                    context.push(value.borrow().clone().into())
-                }
-                Op::Push0 => context.push(value!(0i64)),
-                Op::Push1 => context.push(value!(1i64)),
-                Op::PushVoid => context.push(value!(void)),
-                Op::PushNull => context.push(value!(null)),
-                Op::PushTrue => context.push(value!(true)),
-                Op::PushFalse => context.push(value!(false)),
+                _ => op.execute(context, ops, &mut ip, &mut tries),
+            };
 
-                Op::LoadGlobal(addr) => context.push(context.thread.globals[*addr].clone()),
-                Op::LoadFast(addr) => context.load(*addr),
+            // Debug
+            if context.debug > 3 {
+                context.log(&format!("ip = {} state = {:?}", ip, state));
+            }
 
-                Op::LoadFastCapture(index) => {
-                    let mut capture = context.get_capture(*index).unwrap_or(Capture::Empty);
+            // Errors are caught by the innermost try frame the instruction pointer is in
+            if let Err(Reject::Error(_)) = state
+                && let Some(catch) = Try::catch(&mut tries, context, ip, &mut state)
+            {
+                ip = catch;
+                continue;
+            }
 
-                    capture.set_severity(10);
+            match state {
+                Ok(Accept::Hold) => state = Ok(Accept::Next),
+                Ok(Accept::Next) => ip += 1,
+                Ok(Accept::Push(capture)) if ip + 1 < ops.len() => {
                     context.stack.push(capture);
+                    state = Ok(Accept::Next);
+                    ip += 1;
+                }
+                Err(Reject::Next) if context.frames.len() > 0 => loop {
+                    context.stack.truncate(context.frame.capture_start);
+                    context.thread.reader.reset(context.frame.reader_start);
 
+                    if let Some(fuse) = context.frame.fuse {
+                        if fuse > ip {
+                            ip = fuse;
+                            break;
+                        }
+                    }
+
+                    if context.frames.len() == 0 {
+                        return Err(Reject::Next);
+                    }
+
+                    context.frame = context.frames.pop().unwrap();
+                },
+                _ => break,
+            }
+        }
+
+        // Clear all frames, except base frame
+        if !context.frames.is_empty() {
+            context.frames.truncate(1);
+            context.frame = context.frames.pop().unwrap();
+        }
+
+        if context.debug > 3 {
+            context.log(&format!("exit state = {:?}", state));
+        }
+
+        state
+    }
+
+    /// Logs the instruction at `ip`, and dumps the VM state on higher debug levels.
+    #[inline(never)]
+    fn trace(ops: &[Op], context: &Context, ip: usize) {
+        if context.debug == 3 {
+            context.log(&format!("{:03}:{:?}", ip, &ops[ip]));
+            return;
+        }
+
+        // Dump entire code
+        context.log("--- Code ---");
+
+        fn dump(ops: &[Op], context: &Context, ip: usize) {
+            for (i, op) in ops.iter().enumerate() {
+                context.log(&format!(
+                    "{}{:03} {:?}",
+                    if i == ip { ">" } else { " " },
+                    i,
+                    op
+                ));
+            }
+        }
+
+        dump(ops, context, ip);
+
+        // Dump stack and frames
+        if context.debug > 4 {
+            context.log("--- Reader ---");
+            context.log(&format!(" offset={:?}", context.thread.reader.tell()));
+            context.log(&format!(" eof={:?}", context.thread.reader.eof));
+
+            context.log("--- Globals ---");
+            for i in 0..context.thread.globals.len() {
+                context.log(&format!(" {:03} {:?}", i, context.thread.globals[i]));
+            }
+
+            context.log("--- Stack ---");
+            for i in 0..context.stack.len() {
+                context.log(&format!(" {:03} {:?}", i, context.stack[i]));
+            }
+
+            context.log("--- Frames ---");
+            for i in 0..context.frames.len() {
+                context.log(&format!(" {:03} {}", i, context.frames[i]));
+            }
+
+            context.log(&format!(" {:03} {}", context.frames.len(), context.frame));
+        }
+
+        // Step-by-step
+        if context.debug > 5 {
+            let _ = io::stdin().read(&mut [0u8]).unwrap();
+        }
+    }
+
+    /// Executes any instruction except calls, which may move the instruction pointer `ip`.
+    #[inline(never)]
+    fn execute(
+        &self,
+        context: &mut Context,
+        ops: &[Op],
+        ip: &mut usize,
+        tries: &mut Vec<Try>,
+    ) -> Result<Accept, Reject> {
+        match self {
+            Op::Nop => Ok(Accept::Next),
+
+            Op::Offset(offset) => {
+                context.source_offset = Some(**offset);
+
+                // Give control to the debugger, if any
+                if context.thread.debugger.is_some() {
+                    <dyn DebugFrontend>::hook(context, ops, *ip, offset)
+                } else {
                     Ok(Accept::Next)
                 }
+            }
 
-                Op::LoadCapture => {
-                    let index = context.pop();
-                    let index = index.borrow();
+            // Frames
+            Op::Frame(fuse) => {
+                context.frames.push(context.frame);
+                context.frame = Frame {
+                    fuse: if *fuse > 0 { Some(*ip + *fuse) } else { None },
+                    capture_start: context.stack.len(),
+                    reader_start: context.thread.reader.tell(),
+                };
 
-                    let mut capture = if let Some(alias) = index.object::<Str>() {
-                        context
-                            .get_capture_by_name(alias.as_str())
-                            .unwrap_or(Capture::Empty)
-                    } else {
-                        context
-                            .get_capture(index.to_usize()?)
-                            .unwrap_or(Capture::Empty)
-                    };
+                Ok(Accept::Next)
+            }
 
-                    capture.set_severity(10);
-                    context.stack.push(capture);
+            /*
+            Op::Capture => {
+                context.frame.capture_start = context.stack.len();
+                Ok(Accept::Next)
+            }
+            */
+            Op::Extend => {
+                context.frame.reader_start = context.thread.reader.tell();
+                Ok(Accept::Next)
+            }
 
-                    Ok(Accept::Next)
+            Op::Reset => {
+                context.stack.truncate(context.frame.capture_start);
+                context.thread.reader.reset(context.frame.reader_start);
+                Ok(Accept::Next)
+            }
+
+            Op::ResetReader => {
+                context.thread.reader.reset(context.frame.reader_start);
+                Ok(Accept::Next)
+            }
+
+            Op::ResetCapture => {
+                context.stack.truncate(context.frame.capture_start);
+                Ok(Accept::Next)
+            }
+
+            Op::Close => {
+                context.frame = context.frames.pop().unwrap();
+                Ok(Accept::Next)
+            }
+
+            Op::Collect => Ok(Accept::Push(context.collect(
+                context.frame.capture_start,
+                false,
+                true,
+                context.thread.debug > 5,
+            ))),
+
+            /*
+            Op::InCollect => {
+                let mut capture =
+                    context.collect(context.frame.capture_start, false, context.debug > 5);
+
+                if capture.get_severity() > 5 {
+                    capture.set_severity(5);
                 }
 
-                Op::LoadItem { upsert } => {
-                    let item = context.pop();
-                    let object = context.pop();
-                    let upsert = if *upsert {
-                        let mut dict = Dict::new();
-                        dict.insert(RefValue::from("upsert"), RefValue::from(true));
-                        Some(dict)
-                    } else {
-                        None
-                    };
+                Ok(Accept::Push(capture))
+            }
+            */
+            Op::Fuse(addr) => {
+                context.frame.fuse = Some(*ip + *addr);
+                Ok(Accept::Next)
+            }
 
-                    match object.call_method("get_item", Some(context), vec![item], upsert) {
-                        Ok(Some(value)) => context.push(value),
-                        Ok(None) => Ok(Accept::Next),
-                        Err(msg) => Err(Reject::from(msg)),
-                    }
+            Op::Inlined(severity) => context.close_inlined(*severity),
+
+            // Loops
+            Op::Loop(size) => {
+                context.loops.push(Loop {
+                    frames: context.frames.len(),
+                    start: *ip + 1,
+                    end: *ip + *size,
+                });
+                Ok(Accept::Next)
+            }
+
+            Op::Break | Op::LoadBreak => {
+                let current = context.loops.pop().unwrap();
+
+                // Save value?
+                let value = if matches!(self, Op::LoadBreak) {
+                    Some(context.pop())
+                } else {
+                    None
+                };
+
+                // Discard all open frames inside current loop.
+                while context.frames.len() > current.frames {
+                    context.frame = context.frames.pop().unwrap();
                 }
 
-                Op::LoadAttr => {
-                    let attr = context.pop();
-                    let attr = attr.borrow();
-                    let value = context.pop();
+                context.stack.truncate(context.frame.capture_start);
 
-                    match value.create_method(attr.object::<Str>().unwrap().as_str()) {
-                        Ok(value) => context.push(value),
-                        Err(err) => err.into(),
-                    }
+                // Jump behind loop
+                *ip = current.end;
+
+                // Break will always leave a value, either defined or empty capture
+                Ok(if let Some(value) = value {
+                    Accept::Push(Capture::Value(value, None, 10))
+                } else {
+                    context.stack.push(Capture::Empty);
+                    Accept::Hold
+                })
+            }
+
+            Op::Continue => {
+                let current = context
+                    .loops
+                    .last()
+                    .expect("Op::Continue used outside of a loop frame");
+
+                // Discard all open frames inside current loop.
+                while context.frames.len() > current.frames {
+                    context.frame = context.frames.pop().unwrap();
                 }
 
-                Op::StoreGlobal(addr) => {
-                    // todo: bounds checking?
-                    let value = context.pop().ref_or_copy();
-                    context.thread.globals[*addr] = value;
-                    Ok(Accept::Push(Capture::Empty))
+                context.stack.truncate(context.frame.capture_start);
+
+                // Jump to loop start.
+                *ip = current.start;
+
+                Ok(Accept::Hold)
+            }
+
+            // Try frames
+            Op::Try(catch) => {
+                Try::enter(tries, context, *ip, *catch);
+                Ok(Accept::Next)
+            }
+
+            Op::Catch(goto) => {
+                tries.pop();
+                *ip += goto;
+                Ok(Accept::Hold)
+            }
+
+            // Conditional jumps
+            Op::ForwardIfTrue(goto) => {
+                if context.pop().is_true() {
+                    *ip += goto;
+                } else {
+                    *ip += 1;
                 }
 
-                Op::StoreGlobalHold(addr) => {
-                    // todo: bounds checking?
-                    let value = context.peek().ref_or_copy();
-                    context.thread.globals[*addr] = value;
-                    Ok(Accept::Next)
+                Ok(Accept::Hold)
+            }
+
+            Op::ForwardIfFalse(goto) => {
+                if !context.pop().is_true() {
+                    *ip += goto;
+                } else {
+                    *ip += 1;
                 }
 
-                Op::StoreFast(addr) => {
-                    // todo: bounds checking?
-                    let value = context.pop().ref_or_copy();
-                    context.stack[*addr] = Capture::Value(value, None, 0);
-                    Ok(Accept::Push(Capture::Empty))
+                Ok(Accept::Hold)
+            }
+
+            Op::ForwardIfNotVoid(goto) => {
+                if !context.pop().is_void() {
+                    *ip += goto;
+                } else {
+                    *ip += 1;
                 }
 
-                Op::StoreFastHold(addr) => {
-                    // todo: bounds checking?
-                    let value = context.peek().ref_or_copy();
-                    context.stack[*addr] = Capture::Value(value, None, 0);
+                Ok(Accept::Hold)
+            }
+
+            Op::ForwardIfConsumed(goto) => {
+                if context.frame.reader_start != context.thread.reader.tell() {
+                    *ip += goto;
+                    Ok(Accept::Hold)
+                } else {
                     Ok(Accept::Next)
                 }
+            }
+
+            Op::Forward(goto) => {
+                *ip += goto;
+                Ok(Accept::Hold)
+            }
+
+            /*
+            Op::Backward(goto) => {
+                *ip -= goto;
+                Ok(Accept::Hold)
+            }
+            */
+            // Interrupts
+            Op::Push => Ok(Accept::Push(Capture::Empty)),
+            Op::LoadPush => {
+                let value = context.pop();
+                Ok(Accept::Push(Capture::Value(value, None, 15))) // high severity for override required here
+            }
+            Op::Accept => Ok(Accept::Return(Capture::Empty)),
+            Op::LoadAccept => Ok(Accept::Return(context.stack.pop().unwrap())),
+            Op::Repeat => Ok(Accept::Repeat),
+            Op::Next => Err(Reject::Next),
+            Op::LoadExit => {
+                std::process::exit(context.pop().to_i64()? as i32);
+            }
+            Op::Exit => std::process::exit(0),
 
-                Op::StoreFastCapture(index) => {
-                    let value = context.pop().ref_or_copy();
+            // Variables and values
+            Op::LoadStatic(addr) => {
+                let value = &context.thread.program.statics[*addr];
+                context.push(value.borrow().clone().into())
+            }
+            Op::Push0 => context.push(value!(0i64)),
+            Op::Push1 => context.push(value!(1i64)),
+            Op::PushVoid => context.push(value!(void)),
+            Op::PushNull => context.push(value!(null)),
+            Op::PushTrue => context.push(value!(true)),
+            Op::PushFalse => context.push(value!(false)),
 
-                    context.set_capture(*index, value);
-                    Ok(Accept::Push(Capture::Empty))
+            Op::LoadGlobal(addr) => context.push(context.thread.globals[*addr].clone()),
+            Op::LoadFast(addr) => context.load(*addr),
+
+            Op::LoadFastCapture(index) => {
+                let mut capture = context.get_capture(*index).unwrap_or(Capture::Empty);
+
+                capture.set_severity(10);
+                context.stack.push(capture);
+
+                Ok(Accept::Next)
+            }
+
+            Op::LoadCapture => {
+                let index = context.pop();
+                let index = index.borrow();
+
+                let mut capture = if let Some(alias) = index.object::<Str>() {
+                    context
+                        .get_capture_by_name(alias.as_str())
+                        .unwrap_or(Capture::Empty)
+                } else {
+                    context
+                        .get_capture(index.to_usize()?)
+                        .unwrap_or(Capture::Empty)
+                };
+
+                capture.set_severity(10);
+                context.stack.push(capture);
+
+                Ok(Accept::Next)
+            }
+
+            Op::LoadItem { upsert } => {
+                let item = context.pop();
+                let object = context.pop();
+                let upsert = if *upsert {
+                    let mut dict = Dict::new();
+                    dict.insert(RefValue::from("upsert"), RefValue::from(true));
+                    Some(dict)
+                } else {
+                    None
+                };
+
+                match object.call_method("get_item", Some(context), vec![item], upsert) {
+                    Ok(Some(value)) => context.push(value),
+                    Ok(None) => Ok(Accept::Next),
+                    Err(msg) => Err(Reject::from(msg)),
                 }
+            }
 
-                Op::StoreFastCaptureHold(index) => {
-                    let value = context.peek().ref_or_copy();
+            Op::LoadAttr => {
+                let attr = context.pop();
+                let attr = attr.borrow();
+                let value = context.pop();
 
-                    context.set_capture(*index, value);
-                    Ok(Accept::Next)
+                match value.load_attr(attr.object::<Str>().unwrap().as_str()) {
+                    Ok(value) => context.push(value),
+                    Err(err) => type_error(err),
                 }
+            }
 
-                Op::StoreCapture | Op::StoreCaptureHold => {
-                    let index = context.pop();
-                    let index = index.borrow();
+            Op::StoreGlobal(addr) => {
+                // todo: bounds checking?
+                let value = context.pop().ref_or_copy();
+                context.thread.globals[*addr] = value;
+                Ok(Accept::Push(Capture::Empty))
+            }
 
-                    if let Some(alias) = index.object::<Str>() {
-                        if matches!(op, Op::StoreCapture) {
-                            let value = context.pop().ref_or_copy();
-                            context.set_capture_by_name(alias, value);
-                            Ok(Accept::Push(Capture::Empty))
-                        } else {
-                            let value = context.peek().ref_or_copy();
-                            context.set_capture_by_name(alias, value);
-                            Ok(Accept::Next)
-                        }
+            Op::StoreGlobalHold(addr) => {
+                // todo: bounds checking?
+                let value = context.peek().ref_or_copy();
+                context.thread.globals[*addr] = value;
+                Ok(Accept::Next)
+            }
+
+            Op::StoreFast(addr) => {
+                // todo: bounds checking?
+                let value = context.pop().ref_or_copy();
+                context.stack[*addr] = Capture::Value(value, None, 0);
+                Ok(Accept::Push(Capture::Empty))
+            }
+
+            Op::StoreFastHold(addr) => {
+                // todo: bounds checking?
+                let value = context.peek().ref_or_copy();
+                context.stack[*addr] = Capture::Value(value, None, 0);
+                Ok(Accept::Next)
+            }
+
+            Op::StoreFastCapture(index) => {
+                let value = context.pop().ref_or_copy();
+
+                context.set_capture(*index, value);
+                Ok(Accept::Push(Capture::Empty))
+            }
+
+            Op::StoreFastCaptureHold(index) => {
+                let value = context.peek().ref_or_copy();
+
+                context.set_capture(*index, value);
+                Ok(Accept::Next)
+            }
+
+            Op::StoreCapture | Op::StoreCaptureHold => {
+                let index = context.pop();
+                let index = index.borrow();
+
+                if let Some(alias) = index.object::<Str>() {
+                    if matches!(self, Op::StoreCapture) {
+                        let value = context.pop().ref_or_copy();
+                        context.set_capture_by_name(alias, value);
+                        Ok(Accept::Push(Capture::Empty))
                     } else {
-                        if matches!(op, Op::StoreCapture) {
-                            let value = context.pop().ref_or_copy();
-                            context.set_capture(index.to_usize()?, value);
-                            Ok(Accept::Push(Capture::Empty))
-                        } else {
-                            let value = context.peek().ref_or_copy();
-                            context.set_capture(index.to_usize()?, value);
-                            Ok(Accept::Next)
-                        }
+                        let value = context.peek().ref_or_copy();
+                        context.set_capture_by_name(alias, value);
+                        Ok(Accept::Next)
+                    }
+                } else {
+                    if matches!(self, Op::StoreCapture) {
+                        let value = context.pop().ref_or_copy();
+                        context.set_capture(index.to_usize()?, value);
+                        Ok(Accept::Push(Capture::Empty))
+                    } else {
+                        let value = context.peek().ref_or_copy();
+                        context.set_capture(index.to_usize()?, value);
+                        Ok(Accept::Next)
                     }
                 }
+            }
 
-                Op::StoreItem | Op::StoreItemHold => {
-                    let item = context.pop();
-                    let object = context.pop();
-                    let value = context.pop();
+            Op::StoreItem | Op::StoreItemHold => {
+                let item = context.pop();
+                let object = context.pop();
+                let value = context.pop();
 
-                    match object.call_method("set_item", Some(context), vec![item, value], None) {
-                        Ok(value) => {
-                            let value = value.unwrap(); // setitem must always return a value!
+                match object.call_method("set_item", Some(context), vec![item, value], None) {
+                    Ok(value) => {
+                        let value = value.unwrap(); // setitem must always return a value!
 
-                            if matches!(op, Op::StoreItemHold) {
-                                context.push(value)
-                            } else {
-                                Ok(Accept::Next)
-                            }
+                        if matches!(self, Op::StoreItemHold) {
+                            context.push(value)
+                        } else {
+                            Ok(Accept::Next)
                         }
-                        Err(msg) => Err(Reject::from(msg)),
                     }
+                    Err(msg) => Err(Reject::from(msg)),
                 }
+            }
 
-                Op::MakeAlias => {
-                    let name = context.pop();
+            Op::StoreAttr => store_attr(context, false),
+            Op::StoreAttrHold => store_attr(context, true),
 
-                    match context.stack.last_mut().unwrap() {
-                        Capture::Range(_, alias, ..) | Capture::Value(_, alias, ..) => {
-                            *alias = Some(name);
-                        }
+            Op::MakeAlias => {
+                let name = context.pop();
 
-                        empty => {
-                            *empty = Capture::Value(value!(null), Some(name), 10);
-                        }
+                match context.stack.last_mut().unwrap() {
+                    Capture::Range(_, alias, ..) | Capture::Value(_, alias, ..) => {
+                        *alias = Some(name);
                     }
 
-                    Ok(Accept::Next)
+                    empty => {
+                        *empty = Capture::Value(value!(null), Some(name), 10);
+                    }
                 }
 
-                Op::MakeList(count) => {
-                    let mut list = List::with_capacity(*count);
+                Ok(Accept::Next)
+            }
 
-                    for mut value in context.stack.drain(context.stack.len() - *count..) {
-                        let value = value.extract(&mut context.thread.reader);
+            Op::MakeList(count) => {
+                let mut list = List::with_capacity(*count);
 
-                        if !value.is_void() {
-                            list.push(value);
-                        }
-                    }
+                for mut value in context.stack.drain(context.stack.len() - *count..) {
+                    let value = value.extract(&mut context.thread.reader);
 
-                    context.push(RefValue::from(list))
+                    if !value.is_void() {
+                        list.push(value);
+                    }
                 }
 
-                Op::MakeDict(count) => {
-                    let mut dict = Dict::new();
+                context.push(RefValue::from(list))
+            }
 
-                    {
-                        let start = context.stack.len() - *count * 2;
-                        let mut items = context.stack.drain(start..);
+            Op::MakeDict(count) => {
+                let mut dict = Dict::new();
 
-                        while let (Some(mut value), Some(mut key)) = (items.next(), items.next()) {
-                            let key = key.extract(&mut context.thread.reader);
-                            let value = value.extract(&mut context.thread.reader);
+                {
+                    let start = context.stack.len() - *count * 2;
+                    let mut items = context.stack.drain(start..);
 
-                            dict.insert(key, value);
-                        }
-                    }
+                    while let (Some(mut value), Some(mut key)) = (items.next(), items.next()) {
+                        let key = key.extract(&mut context.thread.reader);
+                        let value = value.extract(&mut context.thread.reader);
 
-                    context.push(RefValue::from(dict))
+                        dict.insert(key, value);
+                    }
                 }
 
-                // Operations
-                Op::Drop => {
-                    context.pop();
-                    Ok(Accept::Next)
-                }
+                context.push(RefValue::from(dict))
+            }
 
-                Op::Inv => {
-                    context.pop();
-                    Ok(Accept::Push(Capture::Empty))
-                }
+            // Operations
+            Op::Drop => {
+                context.pop();
+                Ok(Accept::Next)
+            }
 
-                Op::Sep => {
-                    let mut value = context.pop();
+            Op::Inv => {
+                context.pop();
+                Ok(Accept::Push(Capture::Empty))
+            }
 
-                    // fixme: Replace by https://doc.rust-lang.org/std/rc/struct.Rc.html#method.unwrap_or_clone ?
-                    if Rc::strong_count(&value) > 1 {
-                        value = RefValue::from({
-                            let inner = value.borrow();
-                            inner.clone()
-                        });
-                    }
+            Op::Sep => {
+                let mut value = context.pop();
 
-                    context.push(value)
+                // fixme: Replace by https://doc.rust-lang.org/std/rc/struct.Rc.html#method.unwrap_or_clone ?
+                if Rc::strong_count(&value) > 1 {
+                    value = RefValue::from({
+                        let inner = value.borrow();
+                        inner.clone()
+                    });
                 }
 
-                Op::Dup => {
-                    let value = context.peek();
-                    let value = value.borrow();
-                    context.push(value.clone().into())
-                }
+                context.push(value)
+            }
 
-                Op::Copy(index) => {
-                    assert!(*index > 0);
+            Op::Dup => {
+                let value = context.peek();
+                let value = value.borrow();
+                context.push(value.clone().into())
+            }
 
-                    let index = context.stack.len() - index;
-                    context.stack.push(context.stack[index].clone());
+            Op::Copy(index) => {
+                assert!(*index > 0);
 
-                    Ok(Accept::Next)
-                }
+                let index = context.stack.len() - index;
+                context.stack.push(context.stack[index].clone());
 
-                Op::Swap(index) => {
-                    assert!(*index > 1);
+                Ok(Accept::Next)
+            }
 
-                    let index = context.stack.len() - index;
-                    let tos = context.stack.pop().unwrap();
+            Op::Swap(index) => {
+                assert!(*index > 1);
 
-                    context.stack.push(context.stack[index].clone());
-                    context.stack[index] = tos;
+                let index = context.stack.len() - index;
+                let tos = context.stack.pop().unwrap();
 
-                    Ok(Accept::Next)
-                }
+                context.stack.push(context.stack[index].clone());
+                context.stack[index] = tos;
 
-                Op::UnaryOp(op) => {
-                    let value = context.pop();
-                    context.push(value.unary_op(op.to_str())?)
-                }
+                Ok(Accept::Next)
+            }
 
-                Op::BinaryOp(op) => {
-                    let last = context.pop();
-                    let first = context.pop();
-                    context.push(first.binary_op(last, op.to_str())?)
+            Op::UnaryOp(op) => {
+                let value = context.pop();
+                match value.unary_op(op.to_str()) {
+                    Ok(value) => context.push(value),
+                    Err(msg) => type_error(msg),
                 }
-            };
-
-            // Debug
-            if context.debug > 3 {
-                context.log(&format!("ip = {} state = {:?}", ip, state));
             }
 
-            match state {
-                Ok(Accept::Hold) => state = Ok(Accept::Next),
-                Ok(Accept::Next) => ip += 1,
-                Ok(Accept::Push(capture)) if ip + 1 < ops.len() => {
-                    context.stack.push(capture);
-                    state = Ok(Accept::Next);
-                    ip += 1;
+            Op::BinaryOp(op) => {
+                let last = context.pop();
+                let first = context.pop();
+                match first.binary_op(last, op.to_str()) {
+                    Ok(value) => context.push(value),
+                    Err(msg) => type_error(msg),
                 }
-                Err(Reject::Next) if context.frames.len() > 0 => loop {
-                    context.stack.truncate(context.frame.capture_start);
-                    context.thread.reader.reset(context.frame.reader_start);
-
-                    if let Some(fuse) = context.frame.fuse {
-                        if fuse > ip {
-                            ip = fuse;
-                            break;
-                        }
-                    }
-
-                    if context.frames.len() == 0 {
-                        return Err(Reject::Next);
-                    }
-
-                    context.frame = context.frames.pop().unwrap();
-                },
-                _ => break,
             }
-        }
-
-        // Clear all frames, except base frame
-        if !context.frames.is_empty() {
-            context.frames.truncate(1);
-            context.frame = context.frames.pop().unwrap();
-        }
 
-        if context.debug > 3 {
-            context.log(&format!("exit state = {:?}", state));
+            _ => unreachable!("{:?} is executed by Op::run()", self),
         }
+    }
+}
 
-        state
+/// Stores an attribute, and pushes its value when `hold` is set.
+fn store_attr(context: &mut Context, hold: bool) -> Result<Accept, Reject> {
+    let attr = context.pop();
+    let attr = attr.borrow();
+    let object = context.pop();
+    let value = context.pop();
+
+    match object.store_attr(attr.object::<Str>().unwrap().as_str(), value) {
+        Ok(value) if hold => context.push(value),
+        Ok(_) => Ok(Accept::Next),
+        Err(err) => type_error(err),
     }
 }
+
+/// Rejects with a type error.
+fn type_error(error: impl Into<Error>) -> Result<Accept, Reject> {
+    error.into().with_kind(ErrorKind::Type).into()
+}
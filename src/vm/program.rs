@@ -13,12 +13,17 @@ A program is the result of a successful compiler run. */
 #[cfg_attr(feature = "serde", serde(transparent))]
 pub struct Program {
     pub(crate) statics: Vec<RefValue>, // Static values referenced by this program
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub allow_io: bool, // Default of `Thread::allow_io` for threads running this program
 }
 
 impl Program {
     pub fn new(statics: Vec<RefValue>) -> Self {
         //println!("Program with {} statics in total", statics.len());
-        Self { statics }
+        Self {
+            statics,
+            allow_io: false,
+        }
     }
 
     /// Returns a reference to the program's main parselet.
@@ -40,8 +45,15 @@ impl Program {
         }
     }
 
+    /// Runs the program on a reader. The first error recovered during the run is returned, if any.
     pub fn run_from_reader(&self, mut reader: Reader) -> Result<Option<RefValue>, Error> {
-        Thread::new(self, vec![&mut reader]).run()
+        let mut thread = Thread::new(self, vec![&mut reader]);
+        let ret = thread.run();
+
+        match thread.errors.drain(..).next() {
+            Some(error) => Err(error),
+            None => ret,
+        }
     }
 
     pub fn run_from_str(&self, src: &'static str) -> Result<Option<RefValue>, Error> {
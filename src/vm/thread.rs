@@ -1,9 +1,8 @@
 //! Runtime thread withing a VM program.
 use super::*;
-use crate::reader::{Offset, Reader};
-use crate::value::RefValue;
+use crate::reader::Reader;
+use crate::value::{List, RefValue};
 use crate::{Error, Object};
-use std::collections::HashMap;
 
 /** Thread which is executing a VM program.
 
@@ -15,9 +14,15 @@ pub struct Thread<'program, 'reader> {
     pub reader: &'reader mut Reader,       // Current reader
     pub readers: Vec<&'reader mut Reader>, // List of readers
 
-    pub memo: HashMap<(usize, usize), (Offset, Result<Accept, Reject>)>, // parselet memoization table
-    pub globals: Vec<RefValue>,                                          // Global variables
-    pub debug: u8,                                                       // Debug level
+    pub memo: Memo,                                  // parselet memoization table
+    pub globals: Vec<RefValue>,                      // Global variables
+    pub debug: u8,                                   // Debug level
+    pub debugger: Option<Box<dyn DebugFrontend>>,    // Debugger
+    pub output: Option<Box<dyn std::io::Write>>,     // Output of print(), defaults to stdout
+    pub errors: Vec<Error>,                          // Recovered errors, recorded by error()
+    pub on_result: Option<Box<dyn FnMut(RefValue)>>, // Receives main's results one by one instead of collecting them
+    pub allow_io: bool, // Permit file I/O builtins like open(), defaults to `Program::allow_io`
+    pub skip_input: bool, // Skip the rest of the current reader, set by next_input()
 }
 
 impl<'program, 'reader> Thread<'program, 'reader> {
@@ -28,8 +33,14 @@ impl<'program, 'reader> Thread<'program, 'reader> {
             program,
             reader: readers.remove(0), // first reader becomes current reader
             readers,                   // other readers are kept for later use
-            memo: HashMap::new(),
+            memo: Memo::new(),
             globals: Vec::new(),
+            debugger: None,
+            output: None,
+            errors: Vec::new(),
+            on_result: None,
+            allow_io: program.allow_io,
+            skip_input: false,
             debug: if let Ok(level) = std::env::var("TOKAY_DEBUG") {
                 level.parse::<u8>().unwrap_or_default()
             } else {
@@ -38,6 +49,19 @@ impl<'program, 'reader> Thread<'program, 'reader> {
         }
     }
 
+    /// Hands a result of the main parselet to `on_result`, or collects it into `results`.
+    pub(crate) fn push_result(&mut self, results: &mut List, value: RefValue) {
+        if let Some(on_result) = self.on_result.as_mut() {
+            on_result(value)
+        } else {
+            results.push(value)
+        }
+    }
+
+    /** Runs the program's main parselet.
+
+    Errors recovered during the run don't affect the result, they are collected in `errors`,
+    which should be inspected by the caller. */
     pub fn run(&mut self) -> Result<Option<RefValue>, Error> {
         match self
             .program